@@ -28,6 +28,11 @@ FFICode deadline_monitor_builder_add_deadline(
     const DeadlineTag* deadline_tag,
     uint32_t min_ms,
     uint32_t max_ms);
+FFICode deadline_monitor_builder_add_concurrent_deadline(FFIHandle deadline_monitor_builder_handle,
+                                                         const DeadlineTag* deadline_tag,
+                                                         uint32_t min_ms,
+                                                         uint32_t max_ms,
+                                                         size_t max_instances);
 FFICode deadline_monitor_get_deadline(
     FFIHandle deadline_monitor_handle,
     const DeadlineTag* deadline_tag,
@@ -68,6 +73,20 @@ DeadlineMonitorBuilder DeadlineMonitorBuilder::add_deadline(const DeadlineTag& d
     return std::move(*this);
 }
 
+DeadlineMonitorBuilder DeadlineMonitorBuilder::add_concurrent_deadline(const DeadlineTag& deadline_tag,
+                                                                       const TimeRange& range,
+                                                                       size_t max_instances) &&
+{
+    auto handle = monitor_builder_handler_.as_rust_handle();
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(handle.has_value());
+
+    auto result{deadline_monitor_builder_add_concurrent_deadline(
+        handle.value(), &deadline_tag, range.min_ms(), range.max_ms(), max_instances)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
 DeadlineMonitor::DeadlineMonitor(FFIHandle handle) : monitor_handle_(handle, &deadline_monitor_destroy)
 {
 }
@@ -16,6 +16,7 @@
 #include "score/mw/health/common.h"
 #include "score/mw/health/tag.h"
 #include <score/expected.hpp>
+#include <cstddef>
 #include <functional>
 #include <optional>
 
@@ -50,6 +51,12 @@ class DeadlineMonitorBuilder final : public internal::RustDroppable<DeadlineMoni
     /// Adds a deadline with the given tag and duration range to the monitor.
     DeadlineMonitorBuilder add_deadline(const DeadlineTag& deadline_tag, const TimeRange& range) &&;
 
+    /// Adds a deadline with the given tag and duration range to the monitor.
+    /// Up to `max_instances` instances of the deadline can be obtained and run concurrently.
+    DeadlineMonitorBuilder add_concurrent_deadline(const DeadlineTag& deadline_tag,
+                                                   const TimeRange& range,
+                                                   size_t max_instances) &&;
+
   protected:
     std::optional<internal::FFIHandle> _drop_by_rust_impl()
     {
@@ -23,7 +23,19 @@ using namespace score::mw::health::logic;
 
 // Functions below must match functions defined in `crate::ffi`.
 
+struct CycleStatisticsFFI
+{
+    uint64_t cycles;
+    uint64_t min_evaluation_time_ns;
+    uint64_t max_evaluation_time_ns;
+    uint64_t avg_evaluation_time_ns;
+    uint64_t max_jitter_ns;
+    uint64_t overruns;
+    uint64_t missed_cycles;
+};
+
 FFICode health_monitor_builder_create(FFIHandle* health_monitor_builder_handle_out);
+FFICode health_monitor_builder_from_config(const char* config_path, FFIHandle* health_monitor_builder_handle_out);
 FFICode health_monitor_builder_destroy(FFIHandle health_monitor_builder_handle);
 FFICode health_monitor_builder_build(
     FFIHandle health_monitor_builder_handle,
@@ -43,6 +55,22 @@ FFICode health_monitor_builder_add_logic_monitor(
     FFIHandle health_monitor_builder_handle,
     const MonitorTag* monitor_tag,
     FFIHandle logic_monitor_builder_handle);
+FFICode health_monitor_builder_with_error_handler(
+    FFIHandle health_monitor_builder_handle,
+    void (*error_handler)(const MonitorTag* monitor_tag, uint8_t monitor_kind, uint8_t error_code, void* context),
+    void* context,
+    void (*context_destroy)(void* context));
+FFICode health_monitor_builder_with_cycle_overrun_limit(FFIHandle health_monitor_builder_handle,
+                                                        uint32_t cycle_overrun_limit);
+FFICode health_monitor_builder_with_failure_tolerance(FFIHandle health_monitor_builder_handle,
+                                                      const MonitorTag* monitor_tag,
+                                                      uint32_t failures,
+                                                      uint32_t window,
+                                                      uint32_t recovery_cycles);
+FFICode health_monitor_builder_with_worker_stall_timeout(FFIHandle health_monitor_builder_handle,
+                                                         uint64_t stall_timeout_ms);
+FFICode health_monitor_builder_with_time_resolution(FFIHandle health_monitor_builder_handle,
+                                                    uint64_t time_resolution_us);
 FFICode health_monitor_get_deadline_monitor(
     FFIHandle health_monitor_handle,
     const MonitorTag* monitor_tag,
@@ -56,6 +84,12 @@ FFICode health_monitor_get_logic_monitor(
     const MonitorTag* monitor_tag,
     FFIHandle* logic_monitor_handle_out);
 FFICode health_monitor_start(FFIHandle health_monitor_handle);
+FFICode health_monitor_stop(FFIHandle health_monitor_handle);
+FFICode health_monitor_pause(FFIHandle health_monitor_handle);
+FFICode health_monitor_resume(FFIHandle health_monitor_handle);
+FFICode health_monitor_rearm(FFIHandle health_monitor_handle);
+FFICode health_monitor_cycle_statistics(FFIHandle health_monitor_handle, CycleStatisticsFFI* cycle_statistics_out);
+FFICode health_monitor_check_worker(FFIHandle health_monitor_handle, bool* worker_alive_out);
 FFICode health_monitor_destroy(FFIHandle health_monitor_handle);
 }
 
@@ -67,6 +101,17 @@ FFIHandle health_monitor_builder_create_wrapper()
     return handle;
 }
 
+void error_handler_wrapper(const MonitorTag* monitor_tag, uint8_t monitor_kind, uint8_t error_code, void* context)
+{
+    auto* error_handler{static_cast<ErrorHandler*>(context)};
+    (*error_handler)(*monitor_tag, static_cast<MonitorKind>(monitor_kind), error_code);
+}
+
+void error_handler_context_destroy(void* context)
+{
+    delete static_cast<ErrorHandler*>(context);
+}
+
 }  // namespace
 
 // C++ wrapper for Rust library - the API implementation obeys the Rust API semantics and it's invariants
@@ -79,6 +124,23 @@ HealthMonitorBuilder::HealthMonitorBuilder()
 {
 }
 
+HealthMonitorBuilder::HealthMonitorBuilder(FFIHandle handle)
+    : health_monitor_builder_handle_{handle, &health_monitor_builder_destroy}
+{
+}
+
+score::cpp::expected<HealthMonitorBuilder, Error> HealthMonitorBuilder::from_config(const std::string& config_path)
+{
+    FFIHandle handle{nullptr};
+    auto result{health_monitor_builder_from_config(config_path.c_str(), &handle)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return score::cpp::expected<HealthMonitorBuilder, Error>(HealthMonitorBuilder{handle});
+}
+
 HealthMonitorBuilder HealthMonitorBuilder::add_deadline_monitor(
     const MonitorTag& monitor_tag,
     DeadlineMonitorBuilder&& monitor) &&
@@ -146,6 +208,76 @@ HealthMonitorBuilder HealthMonitorBuilder::thread_parameters(score::mw::health::
     return std::move(*this);
 }
 
+HealthMonitorBuilder HealthMonitorBuilder::with_error_handler(ErrorHandler&& error_handler) &&
+{
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(health_monitor_builder_handle_.as_rust_handle().has_value());
+
+    // Ownership of the handler is passed to the Rust side, it is destroyed using `error_handler_context_destroy`.
+    auto* context{new ErrorHandler{std::move(error_handler)}};
+    auto result{health_monitor_builder_with_error_handler(health_monitor_builder_handle_.as_rust_handle().value(),
+                                                          &error_handler_wrapper,
+                                                          context,
+                                                          &error_handler_context_destroy)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+HealthMonitorBuilder HealthMonitorBuilder::with_cycle_overrun_limit(uint32_t limit) &&
+{
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(health_monitor_builder_handle_.as_rust_handle().has_value());
+
+    // Limit is validated on build.
+    auto result{
+        health_monitor_builder_with_cycle_overrun_limit(health_monitor_builder_handle_.as_rust_handle().value(), limit)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+HealthMonitorBuilder HealthMonitorBuilder::with_failure_tolerance(const MonitorTag& monitor_tag,
+                                                                  uint32_t failures,
+                                                                  uint32_t window,
+                                                                  uint32_t recovery_cycles) &&
+{
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(health_monitor_builder_handle_.as_rust_handle().has_value());
+
+    // Tolerance is validated on build.
+    auto result{health_monitor_builder_with_failure_tolerance(
+        health_monitor_builder_handle_.as_rust_handle().value(), &monitor_tag, failures, window, recovery_cycles)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+HealthMonitorBuilder HealthMonitorBuilder::with_worker_stall_timeout(std::chrono::milliseconds stall_timeout) &&
+{
+    auto count{stall_timeout.count()};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT_MESSAGE(count >= 0, "stall timeout must be positive");
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(health_monitor_builder_handle_.as_rust_handle().has_value());
+
+    // Timeout is validated on build.
+    auto result{health_monitor_builder_with_worker_stall_timeout(
+        health_monitor_builder_handle_.as_rust_handle().value(), static_cast<uint64_t>(count))};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+HealthMonitorBuilder HealthMonitorBuilder::with_time_resolution(std::chrono::microseconds resolution) &&
+{
+    auto count{resolution.count()};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT_MESSAGE(count >= 0, "time resolution must be positive");
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(health_monitor_builder_handle_.as_rust_handle().has_value());
+
+    // Resolution is validated on build.
+    auto result{health_monitor_builder_with_time_resolution(health_monitor_builder_handle_.as_rust_handle().value(),
+                                                            static_cast<uint64_t>(count))};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
 score::cpp::expected<HealthMonitor, Error> HealthMonitorBuilder::build() &&
 {
     auto health_monitor_builder_handle = health_monitor_builder_handle_.drop_by_rust();
@@ -240,6 +372,75 @@ void HealthMonitor::start()
     SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
 }
 
+score::cpp::expected_blank<Error> HealthMonitor::stop()
+{
+    auto result{health_monitor_stop(health_monitor_)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected_blank<Error> HealthMonitor::pause()
+{
+    auto result{health_monitor_pause(health_monitor_)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected_blank<Error> HealthMonitor::resume()
+{
+    auto result{health_monitor_resume(health_monitor_)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+score::cpp::expected_blank<Error> HealthMonitor::rearm()
+{
+    auto result{health_monitor_rearm(health_monitor_)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return {};
+}
+
+CycleStatistics HealthMonitor::cycle_statistics() const
+{
+    CycleStatisticsFFI statistics{};
+    auto result{health_monitor_cycle_statistics(health_monitor_, &statistics)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return CycleStatistics{
+        statistics.cycles,
+        std::chrono::nanoseconds{statistics.min_evaluation_time_ns},
+        std::chrono::nanoseconds{statistics.max_evaluation_time_ns},
+        std::chrono::nanoseconds{statistics.avg_evaluation_time_ns},
+        std::chrono::nanoseconds{statistics.max_jitter_ns},
+        statistics.overruns,
+        statistics.missed_cycles,
+    };
+}
+
+bool HealthMonitor::check_worker() const
+{
+    bool worker_alive{false};
+    auto result{health_monitor_check_worker(health_monitor_, &worker_alive)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+    return worker_alive;
+}
+
 HealthMonitor::~HealthMonitor()
 {
     if (health_monitor_ != nullptr)
@@ -19,12 +19,47 @@
 #include "score/mw/health/logic_monitor.h"
 #include "score/mw/health/tag.h"
 #include "score/mw/health/thread.h"
+#include <chrono>
+#include <functional>
+#include <string>
 
 namespace score::mw::health
 {
 
 class HealthMonitor;
 
+/// Kind of a monitor reporting an error.
+enum class MonitorKind : uint8_t
+{
+    Deadline,
+    Heartbeat,
+    Logic,
+    Custom
+};
+
+/// Handler called on each error reported by a monitor.
+/// Receives tag of the failing monitor, monitor kind and error code specific to the monitor kind.
+using ErrorHandler = std::function<void(const MonitorTag&, MonitorKind, uint8_t)>;
+
+/// Timing statistics of the monitoring cycles.
+struct CycleStatistics
+{
+    /// Number of recorded cycles.
+    uint64_t cycles;
+    /// Shortest evaluation time.
+    std::chrono::nanoseconds min_evaluation_time;
+    /// Longest evaluation time.
+    std::chrono::nanoseconds max_evaluation_time;
+    /// Average evaluation time.
+    std::chrono::nanoseconds avg_evaluation_time;
+    /// Largest delay of a cycle start in relation to its schedule.
+    std::chrono::nanoseconds max_jitter;
+    /// Number of cycles not finished before the next cycle was scheduled.
+    uint64_t overruns;
+    /// Number of cycles skipped due to overruns.
+    uint64_t missed_cycles;
+};
+
 ///
 /// Builder for HealthMonitor instances.
 ///
@@ -34,6 +69,10 @@ class HealthMonitorBuilder final
     /// Create a new `HealthMonitorBuilder`.
     HealthMonitorBuilder();
 
+    /// Create a new `HealthMonitorBuilder` from the JSON configuration file.
+    /// Returns `Error::NotFound` if the file does not exist and `Error::InvalidArgument` if configuration is invalid.
+    static score::cpp::expected<HealthMonitorBuilder, Error> from_config(const std::string& config_path);
+
     ~HealthMonitorBuilder() = default;
     HealthMonitorBuilder(const HealthMonitorBuilder&) = delete;
     HealthMonitorBuilder& operator=(const HealthMonitorBuilder&) = delete;
@@ -65,10 +104,37 @@ class HealthMonitorBuilder final
     /// Sets the monitoring thread parameters.
     HealthMonitorBuilder thread_parameters(score::mw::health::ThreadParameters&& thread_parameters) &&;
 
+    /// Sets the handler called on each error reported by a monitor.
+    /// Handler is called from the monitoring thread and shall not block.
+    HealthMonitorBuilder with_error_handler(ErrorHandler&& error_handler) &&;
+
+    /// Sets the number of consecutive monitoring cycle overruns considered a failure.
+    /// By default, overruns are only reported. Limit must be greater than zero.
+    HealthMonitorBuilder with_cycle_overrun_limit(uint32_t limit) &&;
+
+    /// Sets the failure tolerance of the monitor with given tag.
+    /// Monitor is considered failed after `failures` failed evaluation cycles out of the last `window` cycles,
+    /// and healthy again after `recovery_cycles` consecutive cycles without errors.
+    /// By default, first failed evaluation cycle is considered a failure. Tolerance is validated on build.
+    HealthMonitorBuilder with_failure_tolerance(const MonitorTag& monitor_tag,
+                                                uint32_t failures,
+                                                uint32_t window,
+                                                uint32_t recovery_cycles) &&;
+
+    /// Sets the time without monitoring cycle after which the monitoring thread is considered stalled.
+    /// By default, five internal processing cycles. Timeout must be longer than the internal processing cycle.
+    HealthMonitorBuilder with_worker_stall_timeout(std::chrono::milliseconds stall_timeout) &&;
+
+    /// Sets the resolution of the clock shared by deadline and heartbeat monitors.
+    /// By default, one millisecond. Resolution must be between one microsecond and one millisecond.
+    HealthMonitorBuilder with_time_resolution(std::chrono::microseconds resolution) &&;
+
     /// Build a new `HealthMonitor` instance based on provided parameters.
     score::cpp::expected<HealthMonitor, Error> build() &&;
 
   private:
+    explicit HealthMonitorBuilder(internal::FFIHandle handle);
+
     internal::DroppableFFIHandle health_monitor_builder_handle_;
 
     std::optional<uint64_t> supervisor_api_cycle_ms_;
@@ -93,6 +159,28 @@ class HealthMonitor final
 
     void start();
 
+    /// Stop monitoring and join the monitoring thread.
+    /// Monitoring cannot be restarted afterwards.
+    score::cpp::expected_blank<Error> stop();
+
+    /// Pause monitoring, monitors are not evaluated until resumed.
+    score::cpp::expected_blank<Error> pause();
+
+    /// Resume paused monitoring, errors observed during the pause are discarded.
+    score::cpp::expected_blank<Error> resume();
+
+    /// Re-arm monitors after a recoverable failure.
+    score::cpp::expected_blank<Error> rearm();
+
+    /// Timing statistics of the monitoring cycles.
+    CycleStatistics cycle_statistics() const;
+
+    /// Check whether the monitoring thread completed a cycle within the stall timeout.
+    /// Shall be called periodically from a thread other than the monitoring thread.
+    /// On stall, status is changed to failed and the failure is reported to the supervisor once the thread resumes.
+    /// Returns `true` if monitoring is not started or already stopped.
+    bool check_worker() const;
+
   private:
     // Allow only the builder to create HealthMonitor instances.
     friend class HealthMonitorBuilder;
@@ -23,6 +23,10 @@ FFICode heartbeat_monitor_builder_create(
     uint32_t range_min_ms,
     uint32_t range_max_ms,
     FFIHandle* heartbeat_monitor_builder_handle_out);
+FFICode heartbeat_monitor_builder_create_with_indications(uint32_t reporting_cycle_ms,
+                                                          uint16_t min_indications,
+                                                          uint16_t max_indications,
+                                                          FFIHandle* heartbeat_monitor_builder_handle_out);
 FFICode heartbeat_monitor_builder_destroy(FFIHandle heartbeat_monitor_builder_handle);
 FFICode heartbeat_monitor_destroy(FFIHandle heartbeat_monitor_builder_handle);
 FFICode heartbeat_monitor_heartbeat(FFIHandle heartbeat_monitor_builder_handle);
@@ -47,6 +51,24 @@ HeartbeatMonitorBuilder::HeartbeatMonitorBuilder(const TimeRange& range)
 {
 }
 
+HeartbeatMonitorBuilder::HeartbeatMonitorBuilder(FFIHandle monitor_builder_handle)
+    : monitor_builder_handle_{monitor_builder_handle, &heartbeat_monitor_builder_destroy}
+{
+}
+
+HeartbeatMonitorBuilder HeartbeatMonitorBuilder::with_indications(std::chrono::milliseconds reporting_cycle,
+                                                                  uint16_t min_indications,
+                                                                  uint16_t max_indications)
+{
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(min_indications <= max_indications);
+
+    FFIHandle handle{nullptr};
+    auto result{heartbeat_monitor_builder_create_with_indications(
+        static_cast<uint32_t>(reporting_cycle.count()), min_indications, max_indications, &handle)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+    return HeartbeatMonitorBuilder{handle};
+}
+
 HeartbeatMonitor::HeartbeatMonitor(FFIHandle monitor_handle)
     : monitor_handle_{monitor_handle, &heartbeat_monitor_destroy}
 {
@@ -37,6 +37,15 @@ class HeartbeatMonitorBuilder final : public internal::RustDroppable<HeartbeatMo
     /// - `range` - time range between heartbeats.
     HeartbeatMonitorBuilder(const TimeRange& range);
 
+    /// Create a new `HeartbeatMonitorBuilder` counting heartbeats in fixed reporting cycles.
+    ///
+    /// - `reporting_cycle` - duration of a reporting cycle.
+    /// - `min_indications` - minimum number of heartbeats in a reporting cycle.
+    /// - `max_indications` - maximum number of heartbeats in a reporting cycle.
+    static HeartbeatMonitorBuilder with_indications(std::chrono::milliseconds reporting_cycle,
+                                                    uint16_t min_indications,
+                                                    uint16_t max_indications);
+
     HeartbeatMonitorBuilder(const HeartbeatMonitorBuilder&) = delete;
     HeartbeatMonitorBuilder& operator=(const HeartbeatMonitorBuilder&) = delete;
 
@@ -50,6 +59,8 @@ class HeartbeatMonitorBuilder final : public internal::RustDroppable<HeartbeatMo
     }
 
   private:
+    explicit HeartbeatMonitorBuilder(internal::FFIHandle monitor_builder_handle);
+
     internal::DroppableFFIHandle monitor_builder_handle_;
 
     // Allow to hide drop_by_rust implementation
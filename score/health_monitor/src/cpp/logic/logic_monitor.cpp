@@ -20,6 +20,17 @@ using namespace score::mw::health;
 using namespace score::mw::health::internal;
 using namespace score::mw::health::logic;
 
+struct TransitionRecordFFI
+{
+    StateTag source;
+    StateTag target;
+    EventTag event;
+    bool has_event;
+    uint64_t age_ns;
+    bool reset;
+    uint8_t error_code;
+};
+
 FFICode logic_monitor_builder_create(const StateTag* initial_state, FFIHandle* logic_monitor_builder_handle_out);
 FFICode logic_monitor_builder_destroy(FFIHandle logic_monitor_builder_handle);
 FFICode logic_monitor_builder_add_state(
@@ -27,9 +38,29 @@ FFICode logic_monitor_builder_add_state(
     const StateTag* state,
     const StateTag* allowed_states,
     size_t num_allowed_states);
+FFICode logic_monitor_builder_add_timed_state(FFIHandle logic_monitor_builder_handle,
+                                              const StateTag* state,
+                                              const StateTag* allowed_states,
+                                              size_t num_allowed_states,
+                                              uint32_t min_ms,
+                                              uint32_t max_ms);
+FFICode logic_monitor_builder_add_event(FFIHandle logic_monitor_builder_handle,
+                                        const StateTag* state,
+                                        const EventTag* event,
+                                        const StateTag* target_state);
+FFICode logic_monitor_builder_with_reset_policy(FFIHandle logic_monitor_builder_handle, uint8_t reset_policy);
+FFICode logic_monitor_builder_with_history_capacity(FFIHandle logic_monitor_builder_handle, size_t capacity);
 FFICode logic_monitor_destroy(FFIHandle logic_monitor_handle);
 FFICode logic_monitor_transition(FFIHandle logic_monitor_handle, const StateTag* target_state);
+FFICode logic_monitor_event(FFIHandle logic_monitor_handle, const EventTag* event, StateTag* state_out);
 FFICode logic_monitor_state(FFIHandle logic_monitor_handle, StateTag* state_out);
+FFICode logic_monitor_reset_to(FFIHandle logic_monitor_handle, const StateTag* state);
+FFICode logic_monitor_invalid_transition(FFIHandle logic_monitor_handle, StateTag* source_out, StateTag* target_out);
+FFICode logic_monitor_unexpected_event(FFIHandle logic_monitor_handle, StateTag* state_out, EventTag* event_out);
+FFICode logic_monitor_history(FFIHandle logic_monitor_handle,
+                              TransitionRecordFFI* records_out,
+                              size_t capacity,
+                              size_t* num_records_out);
 }
 
 FFIHandle logic_monitor_builder_create_wrapper(const StateTag& initial_state)
@@ -62,6 +93,60 @@ LogicMonitorBuilder LogicMonitorBuilder::add_state(
     return std::move(*this);
 }
 
+LogicMonitorBuilder LogicMonitorBuilder::add_timed_state(const StateTag& state,
+                                                         const std::vector<StateTag>& allowed_states,
+                                                         const TimeRange& dwell) &&
+{
+    auto monitor_builder_handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_builder_handle.has_value());
+
+    auto result{logic_monitor_builder_add_timed_state(monitor_builder_handle.value(),
+                                                      &state,
+                                                      allowed_states.data(),
+                                                      allowed_states.size(),
+                                                      dwell.min_ms(),
+                                                      dwell.max_ms())};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+LogicMonitorBuilder LogicMonitorBuilder::add_event(const StateTag& state,
+                                                   const EventTag& event,
+                                                   const StateTag& target) &&
+{
+    auto monitor_builder_handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_builder_handle.has_value());
+
+    auto result{logic_monitor_builder_add_event(monitor_builder_handle.value(), &state, &event, &target)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+LogicMonitorBuilder LogicMonitorBuilder::with_reset_policy(ResetPolicy reset_policy) &&
+{
+    auto monitor_builder_handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_builder_handle.has_value());
+
+    auto result{
+        logic_monitor_builder_with_reset_policy(monitor_builder_handle.value(), static_cast<uint8_t>(reset_policy))};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
+LogicMonitorBuilder LogicMonitorBuilder::with_history_capacity(size_t capacity) &&
+{
+    auto monitor_builder_handle{monitor_builder_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_builder_handle.has_value());
+
+    auto result{logic_monitor_builder_with_history_capacity(monitor_builder_handle.value(), capacity)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    return std::move(*this);
+}
+
 LogicMonitor::LogicMonitor(FFIHandle monitor_handle) : monitor_handle_{monitor_handle, &logic_monitor_destroy}
 {
 }
@@ -80,6 +165,21 @@ score::cpp::expected<StateTag, Error> LogicMonitor::transition(const StateTag& s
     return score::cpp::expected<StateTag, Error>(state);
 }
 
+score::cpp::expected<StateTag, Error> LogicMonitor::event(const EventTag& event)
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+
+    StateTag state_tag{""};
+    auto result{logic_monitor_event(monitor_handle.value(), &event, &state_tag)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return score::cpp::expected<StateTag, Error>(state_tag);
+}
+
 score::cpp::expected<StateTag, Error> LogicMonitor::state()
 {
     auto monitor_handle{monitor_handle_.as_rust_handle()};
@@ -95,4 +195,84 @@ score::cpp::expected<StateTag, Error> LogicMonitor::state()
     return score::cpp::expected<StateTag, Error>(state_tag);
 }
 
+score::cpp::expected<StateTag, Error> LogicMonitor::reset_to(const StateTag& state)
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+
+    auto result{logic_monitor_reset_to(monitor_handle.value(), &state)};
+    if (result != kSuccess)
+    {
+        return score::cpp::unexpected(static_cast<Error>(result));
+    }
+
+    return score::cpp::expected<StateTag, Error>(state);
+}
+
+std::vector<TransitionRecord> LogicMonitor::history()
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+
+    // Query number of records first.
+    size_t num_records{0};
+    auto result{logic_monitor_history(monitor_handle.value(), nullptr, 0, &num_records)};
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+
+    std::vector<TransitionRecordFFI> records(num_records,
+                                             TransitionRecordFFI{StateTag{""}, StateTag{""}, EventTag{""}, false, 0, false, 0});
+    result = logic_monitor_history(monitor_handle.value(), records.data(), records.size(), &num_records);
+    SCORE_LANGUAGE_FUTURECPP_ASSERT(result == kSuccess);
+    // History never shrinks - if it has grown in the meantime, the most recent records are provided.
+
+    std::vector<TransitionRecord> history;
+    history.reserve(records.size());
+    for (const auto& record : records)
+    {
+        history.push_back(TransitionRecord{
+            record.source,
+            record.target,
+            record.has_event ? std::optional<EventTag>{record.event} : std::nullopt,
+            std::chrono::nanoseconds{record.age_ns},
+            record.reset,
+            record.error_code,
+        });
+    }
+    return history;
+}
+
+std::optional<std::pair<StateTag, StateTag>> LogicMonitor::invalid_transition()
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+
+    StateTag source{""};
+    StateTag target{""};
+    auto result{logic_monitor_invalid_transition(monitor_handle.value(), &source, &target)};
+    if (result != kSuccess)
+    {
+        SCORE_LANGUAGE_FUTURECPP_ASSERT(result == static_cast<FFICode>(Error::NotFound));
+        return std::nullopt;
+    }
+
+    return std::make_pair(source, target);
+}
+
+std::optional<std::pair<StateTag, EventTag>> LogicMonitor::unexpected_event()
+{
+    auto monitor_handle{monitor_handle_.as_rust_handle()};
+    SCORE_LANGUAGE_FUTURECPP_PRECONDITION(monitor_handle.has_value());
+
+    StateTag state{""};
+    EventTag event{""};
+    auto result{logic_monitor_unexpected_event(monitor_handle.value(), &state, &event)};
+    if (result != kSuccess)
+    {
+        SCORE_LANGUAGE_FUTURECPP_ASSERT(result == static_cast<FFICode>(Error::NotFound));
+        return std::nullopt;
+    }
+
+    return std::make_pair(state, event);
+}
+
 }  // namespace score::mw::health::logic
@@ -16,6 +16,9 @@
 #include "score/mw/health/common.h"
 #include "score/mw/health/tag.h"
 #include <score/expected.hpp>
+#include <chrono>
+#include <optional>
+#include <utility>
 #include <vector>
 
 namespace score::mw::health
@@ -28,6 +31,35 @@ class HealthMonitorBuilder;
 namespace score::mw::health::logic
 {
 
+/// States to which `LogicMonitor::reset_to` is allowed.
+enum class ResetPolicy : uint8_t
+{
+    /// Reset is not allowed.
+    Disabled,
+    /// Reset is allowed only to the initial state.
+    InitialState,
+    /// Reset is allowed to any defined state.
+    AnyState
+};
+
+/// Record of a requested state transition.
+struct TransitionRecord
+{
+    /// State at the time of the request.
+    StateTag source;
+    /// Requested target state.
+    /// For an unexpected event, the state in which it was reported.
+    StateTag target;
+    /// Event which triggered the request, refer to `LogicMonitor::event`.
+    std::optional<EventTag> event;
+    /// Time elapsed since the request.
+    std::chrono::nanoseconds age;
+    /// Transition was forced using `LogicMonitor::reset_to`.
+    bool reset;
+    /// Zero on success, otherwise logic evaluation error code.
+    uint8_t error_code;
+};
+
 class LogicMonitorBuilder final : public internal::RustDroppable<LogicMonitorBuilder>
 {
   public:
@@ -46,6 +78,26 @@ class LogicMonitorBuilder final : public internal::RustDroppable<LogicMonitorBui
     /// If state already exists - it is overwritten.
     LogicMonitorBuilder add_state(const StateTag& state, const std::vector<StateTag>& allowed_states) &&;
 
+    /// Add time-bounded state along with allowed transitions.
+    /// If state already exists - it is overwritten.
+    /// Leaving the state before `dwell.min` or holding it longer than `dwell.max` is reported as an error.
+    LogicMonitorBuilder add_timed_state(const StateTag& state,
+                                        const std::vector<StateTag>& allowed_states,
+                                        const TimeRange& dwell) &&;
+
+    /// Add transition triggered by an event, refer to `LogicMonitor::event`.
+    /// If event already exists for the state - it is overwritten.
+    /// `target` must be an allowed target of `state`.
+    LogicMonitorBuilder add_event(const StateTag& state, const EventTag& event, const StateTag& target) &&;
+
+    /// Set states to which `LogicMonitor::reset_to` is allowed.
+    /// By default, reset is disabled.
+    LogicMonitorBuilder with_reset_policy(ResetPolicy reset_policy) &&;
+
+    /// Set number of transitions kept in the history.
+    /// By default, 16 last transitions are kept. Zero disables the history.
+    LogicMonitorBuilder with_history_capacity(size_t capacity) &&;
+
   protected:
     std::optional<internal::FFIHandle> _drop_by_rust_impl()
     {
@@ -78,6 +130,26 @@ class LogicMonitor final
     /// Current monitor state.
     score::cpp::expected<StateTag, Error> state();
 
+    /// Report an event, performing transition defined with `LogicMonitorBuilder::add_event`.
+    /// Event not expected in the current state is an invalid transition.
+    /// On success, current state is returned.
+    score::cpp::expected<StateTag, Error> event(const EventTag& event);
+
+    /// Force the monitor into a given state, discarding its error status.
+    /// Reset must be allowed by the reset policy of the monitor.
+    /// On success, current state is returned.
+    score::cpp::expected<StateTag, Error> reset_to(const StateTag& state);
+
+    /// Last transitions, starting from the oldest one.
+    /// Rejected transitions and resets are also included.
+    std::vector<TransitionRecord> history();
+
+    /// Source and target state of the last invalid transition, until the error is discarded.
+    std::optional<std::pair<StateTag, StateTag>> invalid_transition();
+
+    /// State in which the last unexpected event was reported and the event, until the error is discarded.
+    std::optional<std::pair<StateTag, EventTag>> unexpected_event();
+
   private:
     explicit LogicMonitor(internal::FFIHandle monitor_handle);
 
@@ -70,6 +70,13 @@ class StateTag : public Tag<StateTag>
     using Tag::Tag;
 };
 
+/// Event tag.
+class EventTag : public Tag<EventTag>
+{
+  public:
+    using Tag::Tag;
+};
+
 }  // namespace score::mw::health
 
 #endif  // SCORE_HM_TAG_H
@@ -43,6 +43,15 @@ TEST_F(DeadlineMonitorBuilderFixture, AddDeadline_Succeeds)
     auto deadline_monitor_builder{DeadlineMonitorBuilder{}.add_deadline(deadline_tag, range)};
 }
 
+TEST_F(DeadlineMonitorBuilderFixture, AddConcurrentDeadline_Succeeds)
+{
+    RecordProperty("Description", "Deadline with multiple instances successfully added.");
+    using namespace std::chrono_literals;
+    DeadlineTag deadline_tag{"deadline"};
+    TimeRange range{50ms, 150ms};
+    auto deadline_monitor_builder{DeadlineMonitorBuilder{}.add_concurrent_deadline(deadline_tag, range, 4)};
+}
+
 class DeadlineMonitorFixture : public ::testing::Test
 {
   protected:
@@ -59,7 +68,9 @@ class DeadlineMonitorFixture : public ::testing::Test
         MonitorTag deadline_monitor_tag{"deadline_monitor"};
         DeadlineTag deadline_tag{"deadline"};
         TimeRange range{50ms, 150ms};
-        auto deadline_monitor_builder{DeadlineMonitorBuilder{}.add_deadline(deadline_tag, range)};
+        DeadlineTag concurrent_deadline_tag{"concurrent_deadline"};
+        auto deadline_monitor_builder{DeadlineMonitorBuilder{}.add_deadline(deadline_tag, range).add_concurrent_deadline(
+            concurrent_deadline_tag, range, 2)};
 
         // Build HMON, including deadline monitor.
         auto hmon_build_result{HealthMonitorBuilder{}
@@ -83,6 +94,19 @@ TEST_F(DeadlineMonitorFixture, GetDeadline_Succeeds)
     ASSERT_TRUE(get_deadline_result.has_value());
 }
 
+TEST_F(DeadlineMonitorFixture, GetDeadline_ConcurrentInstances)
+{
+    RecordProperty("Description", "Deadline instances obtained until the pool is exhausted.");
+    // Get deadline instances.
+    auto get_deadline_result1{deadline_monitor_->get_deadline(DeadlineTag{"concurrent_deadline"})};
+    ASSERT_TRUE(get_deadline_result1.has_value());
+    auto get_deadline_result2{deadline_monitor_->get_deadline(DeadlineTag{"concurrent_deadline"})};
+    ASSERT_TRUE(get_deadline_result2.has_value());
+    auto get_deadline_result3{deadline_monitor_->get_deadline(DeadlineTag{"concurrent_deadline"})};
+    ASSERT_FALSE(get_deadline_result3.has_value());
+    ASSERT_EQ(get_deadline_result3.error(), Error::AlreadyExists);
+}
+
 TEST_F(DeadlineMonitorFixture, GetDeadline_Unknown)
 {
     RecordProperty("Description", "Deadline failed to be obtained due to unknown tag.");
@@ -16,6 +16,9 @@
 #include "score/mw/health/heartbeat_monitor.h"
 #include "score/mw/health/logic_monitor.h"
 #include <gtest/gtest.h>
+#include <cstdio>
+#include <fstream>
+#include <thread>
 
 using namespace score::mw::health;
 using namespace score::mw::health::deadline;
@@ -71,6 +74,19 @@ TEST_F(HealthMonitorBuilderFixture, Build_Succeeds)
     ASSERT_TRUE(result.has_value());
 }
 
+TEST_F(HealthMonitorBuilderFixture, Build_WithErrorHandler_Succeeds)
+{
+    RecordProperty("Description", "Successfully build monitor with an error handler.");
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto result{HealthMonitorBuilder{}
+                    .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                    .with_error_handler([](const MonitorTag&, MonitorKind, uint8_t) {})
+                    .build()};
+    ASSERT_TRUE(result.has_value());
+}
+
 TEST_F(HealthMonitorBuilderFixture, Build_InvalidCycles)
 {
     RecordProperty(
@@ -90,6 +106,145 @@ TEST_F(HealthMonitorBuilderFixture, Build_NoMonitors)
     ASSERT_EQ(result.error(), Error::WrongState);
 }
 
+TEST_F(HealthMonitorBuilderFixture, Build_ZeroCycleOverrunLimit)
+{
+    RecordProperty("Description", "Failed to build a health monitor with zero cycle overrun limit.");
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto result{HealthMonitorBuilder{}
+                    .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                    .with_cycle_overrun_limit(0)
+                    .build()};
+    ASSERT_FALSE(result.has_value());
+    ASSERT_EQ(result.error(), Error::InvalidArgument);
+}
+
+TEST_F(HealthMonitorBuilderFixture, Build_WithFailureTolerance)
+{
+    RecordProperty("Description", "Build a health monitor with failure tolerance of a monitor.");
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto result{HealthMonitorBuilder{}
+                    .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                    .with_failure_tolerance(deadline_monitor_tag, 2, 5, 3)
+                    .build()};
+    ASSERT_TRUE(result.has_value());
+}
+
+TEST_F(HealthMonitorBuilderFixture, Build_InvalidFailureTolerance)
+{
+    RecordProperty("Description", "Failed to build a health monitor with invalid failure tolerance.");
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    MonitorTag unknown_monitor_tag{"unknown_monitor"};
+
+    auto result_window{HealthMonitorBuilder{}
+                           .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder{})
+                           .with_failure_tolerance(deadline_monitor_tag, 5, 3, 1)
+                           .build()};
+    ASSERT_FALSE(result_window.has_value());
+    ASSERT_EQ(result_window.error(), Error::InvalidArgument);
+
+    auto result_unknown{HealthMonitorBuilder{}
+                            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder{})
+                            .with_failure_tolerance(unknown_monitor_tag, 2, 5, 3)
+                            .build()};
+    ASSERT_FALSE(result_unknown.has_value());
+    ASSERT_EQ(result_unknown.error(), Error::InvalidArgument);
+}
+
+TEST_F(HealthMonitorBuilderFixture, Build_InvalidWorkerStallTimeout)
+{
+    RecordProperty("Description", "Failed to build a health monitor with stall timeout not longer than cycle.");
+    using namespace std::chrono_literals;
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto result{HealthMonitorBuilder{}
+                    .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                    .with_internal_processing_cycle(100ms)
+                    .with_worker_stall_timeout(100ms)
+                    .build()};
+    ASSERT_FALSE(result.has_value());
+    ASSERT_EQ(result.error(), Error::InvalidArgument);
+}
+
+TEST_F(HealthMonitorBuilderFixture, Build_SubMillisecondTimeResolution)
+{
+    RecordProperty("Description", "Build a health monitor with sub-millisecond time resolution.");
+    using namespace std::chrono_literals;
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto result{HealthMonitorBuilder{}
+                    .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                    .with_time_resolution(10us)
+                    .build()};
+    ASSERT_TRUE(result.has_value());
+}
+
+TEST_F(HealthMonitorBuilderFixture, Build_InvalidTimeResolution)
+{
+    RecordProperty("Description", "Failed to build a health monitor with time resolution coarser than a millisecond.");
+    using namespace std::chrono_literals;
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto result{HealthMonitorBuilder{}
+                    .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                    .with_time_resolution(2ms)
+                    .build()};
+    ASSERT_FALSE(result.has_value());
+    ASSERT_EQ(result.error(), Error::InvalidArgument);
+}
+
+TEST_F(HealthMonitorBuilderFixture, FromConfig_Succeeds)
+{
+    RecordProperty("Description", "Successfully build monitor from the configuration file.");
+    const std::string config_path{::testing::TempDir() + "health_monitor_config.json"};
+    {
+        std::ofstream config_file{config_path};
+        config_file << R"({
+            "internal_processing_cycle_ms": 50,
+            "deadline_monitors": [
+                { "tag": "deadline_monitor", "deadlines": [{ "tag": "deadline", "min_ms": 0, "max_ms": 100 }] }
+            ]
+        })";
+    }
+
+    auto builder{HealthMonitorBuilder::from_config(config_path)};
+    std::remove(config_path.c_str());
+    ASSERT_TRUE(builder.has_value());
+
+    auto health_monitor{std::move(builder).value().build()};
+    ASSERT_TRUE(health_monitor.has_value());
+    ASSERT_TRUE(health_monitor.value().get_deadline_monitor(MonitorTag{"deadline_monitor"}).has_value());
+}
+
+TEST_F(HealthMonitorBuilderFixture, FromConfig_NotFound)
+{
+    RecordProperty("Description", "Failed to load nonexistent configuration file.");
+    auto result{HealthMonitorBuilder::from_config("/nonexistent/health_monitor_config.json")};
+    ASSERT_FALSE(result.has_value());
+    ASSERT_EQ(result.error(), Error::NotFound);
+}
+
+TEST_F(HealthMonitorBuilderFixture, FromConfig_Invalid)
+{
+    RecordProperty("Description", "Failed to load invalid configuration file.");
+    const std::string config_path{::testing::TempDir() + "health_monitor_invalid_config.json"};
+    {
+        std::ofstream config_file{config_path};
+        config_file << R"({ "deadline_monitors": [{ "tag": "deadline_monitor", "unknown": 1 }] })";
+    }
+
+    auto result{HealthMonitorBuilder::from_config(config_path)};
+    std::remove(config_path.c_str());
+    ASSERT_FALSE(result.has_value());
+    ASSERT_EQ(result.error(), Error::InvalidArgument);
+}
+
 TEST(HealthMonitor, GetDeadlineMonitor_Available)
 {
     RecordProperty("Description", "Successfully obtained deadline monitor.");
@@ -257,3 +412,88 @@ TEST(HealthMonitor, Start_MonitorsNotTaken)
     // `SIGABRT` is expected.
     ASSERT_DEATH({ health_monitor.start(); }, "");
 }
+
+TEST(HealthMonitor, StopPauseResumeRearm)
+{
+    RecordProperty("Description", "Pause, resume and stop of a started health monitor.");
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto health_monitor{HealthMonitorBuilder{}
+                            .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                            .build()
+                            .value()};
+
+    health_monitor.get_deadline_monitor(deadline_monitor_tag);
+
+    // Not started yet.
+    ASSERT_EQ(health_monitor.pause().error(), Error::WrongState);
+
+    health_monitor.start();
+    ASSERT_TRUE(health_monitor.pause().has_value());
+    ASSERT_TRUE(health_monitor.resume().has_value());
+    // Re-arm is only allowed after failure.
+    ASSERT_EQ(health_monitor.rearm().error(), Error::WrongState);
+    ASSERT_TRUE(health_monitor.stop().has_value());
+    ASSERT_EQ(health_monitor.stop().error(), Error::WrongState);
+}
+
+TEST(HealthMonitor, CycleStatistics)
+{
+    RecordProperty("Description", "Cycle statistics are collected by a started health monitor.");
+    using namespace std::chrono_literals;
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto health_monitor{HealthMonitorBuilder{}
+                            .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                            .with_internal_processing_cycle(10ms)
+                            .with_supervisor_api_cycle(50ms)
+                            .with_cycle_overrun_limit(3)
+                            .build()
+                            .value()};
+
+    health_monitor.get_deadline_monitor(deadline_monitor_tag);
+
+    // Not started yet.
+    ASSERT_EQ(health_monitor.cycle_statistics().cycles, 0U);
+
+    health_monitor.start();
+    std::this_thread::sleep_for(55ms);
+    ASSERT_TRUE(health_monitor.stop().has_value());
+
+    auto statistics{health_monitor.cycle_statistics()};
+    ASSERT_GT(statistics.cycles, 0U);
+    ASSERT_LE(statistics.min_evaluation_time, statistics.max_evaluation_time);
+}
+
+TEST(HealthMonitor, CheckWorker)
+{
+    RecordProperty("Description", "Monitoring thread of a started health monitor is checked from another thread.");
+    using namespace std::chrono_literals;
+    MonitorTag deadline_monitor_tag{"deadline_monitor"};
+    DeadlineMonitorBuilder deadline_monitor_builder;
+
+    auto health_monitor{HealthMonitorBuilder{}
+                            .add_deadline_monitor(deadline_monitor_tag, std::move(deadline_monitor_builder))
+                            .with_internal_processing_cycle(10ms)
+                            .with_supervisor_api_cycle(50ms)
+                            .with_worker_stall_timeout(500ms)
+                            .build()
+                            .value()};
+
+    health_monitor.get_deadline_monitor(deadline_monitor_tag);
+
+    // Not started yet.
+    ASSERT_TRUE(health_monitor.check_worker());
+
+    health_monitor.start();
+    bool worker_alive{false};
+    std::thread checker{[&health_monitor, &worker_alive]() {
+        std::this_thread::sleep_for(30ms);
+        worker_alive = health_monitor.check_worker();
+    }};
+    checker.join();
+    ASSERT_TRUE(worker_alive);
+    ASSERT_TRUE(health_monitor.stop().has_value());
+}
@@ -29,6 +29,42 @@ TEST(HeartbeatMonitorBuilder, New_Succeeds)
     HeartbeatMonitorBuilder heartbeat_monitor_builder{range};
 }
 
+TEST(HeartbeatMonitorBuilder, WithIndications_Succeeds)
+{
+    RecordProperty("TestType", "interface-test");
+    RecordProperty("DerivationTechnique", "explorative-testing");
+    RecordProperty("Description", "Object counting heartbeats in reporting cycles successfully constructed.");
+
+    using namespace std::chrono_literals;
+    auto heartbeat_monitor_builder{HeartbeatMonitorBuilder::with_indications(1000ms, 2, 4)};
+}
+
+TEST(HeartbeatMonitor, HeartbeatWithIndications_Succeeds)
+{
+    RecordProperty("TestType", "interface-test");
+    RecordProperty("DerivationTechnique", "explorative-testing");
+    RecordProperty("Description", "Heartbeat counted in reporting cycles successfully used.");
+
+    // Build HMON, including heartbeat monitor.
+    using namespace std::chrono_literals;
+    MonitorTag heartbeat_monitor_tag{"heartbeat_monitor"};
+    auto hmon_build_result{
+        HealthMonitorBuilder{}
+            .add_heartbeat_monitor(heartbeat_monitor_tag, HeartbeatMonitorBuilder::with_indications(1000ms, 2, 4))
+            .build()};
+    ASSERT_TRUE(hmon_build_result.has_value());
+    auto hmon{std::move(hmon_build_result.value())};
+
+    // Get heartbeat monitor.
+    auto get_heartbeat_monitor_result{hmon.get_heartbeat_monitor(heartbeat_monitor_tag)};
+    ASSERT_TRUE(get_heartbeat_monitor_result.has_value());
+    auto heartbeat_monitor{std::move(get_heartbeat_monitor_result.value())};
+
+    // Check heartbeats are not failing.
+    heartbeat_monitor.heartbeat();
+    heartbeat_monitor.heartbeat();
+}
+
 TEST(HeartbeatMonitor, Heartbeat_Succeeds)
 {
     RecordProperty("TestType", "interface-test");
@@ -43,6 +43,45 @@ TEST_F(LogicMonitorBuilderFixture, AddState_Succeeds)
     auto logic_monitor_builder{LogicMonitorBuilder{state1}.add_state(state1, {state2})};
 }
 
+TEST_F(LogicMonitorBuilderFixture, AddTimedState_Succeeds)
+{
+    RecordProperty("Description", "Time-bounded state successfully added.");
+    using namespace std::chrono_literals;
+    StateTag state1{"state1"};
+    StateTag state2{"state2"};
+    auto logic_monitor_builder{LogicMonitorBuilder{state1}.add_timed_state(state1, {state2}, TimeRange{100ms, 200ms})};
+}
+
+TEST_F(LogicMonitorBuilderFixture, AddEvent_Succeeds)
+{
+    RecordProperty("Description", "Event successfully added.");
+    StateTag state1{"state1"};
+    StateTag state2{"state2"};
+    auto logic_monitor_builder{
+        LogicMonitorBuilder{state1}.add_state(state1, {state2}).add_event(state1, EventTag{"event"}, state2)};
+}
+
+TEST(LogicMonitor, Transition_LeftTooEarly)
+{
+    RecordProperty("Description", "Monitor failed to transition from a state left before its dwell time.");
+    using namespace std::chrono_literals;
+    MonitorTag logic_monitor_tag{"logic_monitor"};
+    StateTag state1{"state1"};
+    StateTag state2{"state2"};
+    auto logic_monitor_builder{
+        LogicMonitorBuilder{state1}.add_timed_state(state1, {state2}, TimeRange{10000ms, 20000ms}).add_state(state2, {})};
+
+    auto hmon{HealthMonitorBuilder{}
+                  .add_logic_monitor(logic_monitor_tag, std::move(logic_monitor_builder))
+                  .build()
+                  .value()};
+    auto logic_monitor{hmon.get_logic_monitor(logic_monitor_tag).value()};
+
+    auto transition_result{logic_monitor.transition(state2)};
+    ASSERT_FALSE(transition_result.has_value());
+    ASSERT_EQ(transition_result.error(), Error::Failed);
+}
+
 class LogicMonitorFixture : public ::testing::Test
 {
   protected:
@@ -126,3 +165,172 @@ TEST_F(LogicMonitorFixture, State_Invalid)
     ASSERT_FALSE(state_result.has_value());
     ASSERT_EQ(state_result.error(), Error::Failed);
 }
+
+TEST_F(LogicMonitorFixture, ResetTo_Disabled)
+{
+    RecordProperty("Description", "Failed to reset monitor with reset disabled.");
+    auto reset_result{logic_monitor_->reset_to(state1_)};
+    ASSERT_FALSE(reset_result.has_value());
+    ASSERT_EQ(reset_result.error(), Error::WrongState);
+}
+
+TEST_F(LogicMonitorFixture, InvalidTransition_Reported)
+{
+    RecordProperty("Description", "Source and target of an invalid transition successfully obtained.");
+    ASSERT_FALSE(logic_monitor_->invalid_transition().has_value());
+
+    // State transition.
+    StateTag unknown{"unknown"};
+    logic_monitor_->transition(unknown);
+
+    auto invalid_transition{logic_monitor_->invalid_transition()};
+    ASSERT_TRUE(invalid_transition.has_value());
+    ASSERT_EQ(invalid_transition->first, state1_);
+    ASSERT_EQ(invalid_transition->second, unknown);
+}
+
+TEST_F(LogicMonitorFixture, History_Succeeds)
+{
+    RecordProperty("Description", "Transition history successfully obtained.");
+    ASSERT_TRUE(logic_monitor_->history().empty());
+
+    // State transitions.
+    logic_monitor_->transition(state2_);
+    logic_monitor_->transition(state1_);
+
+    auto history{logic_monitor_->history()};
+    ASSERT_EQ(history.size(), 2);
+    ASSERT_EQ(history[0].source, state1_);
+    ASSERT_EQ(history[0].target, state2_);
+    ASSERT_EQ(history[0].error_code, 0);
+    ASSERT_FALSE(history[0].event.has_value());
+    ASSERT_FALSE(history[0].reset);
+    ASSERT_EQ(history[1].source, state2_);
+    ASSERT_EQ(history[1].target, state1_);
+    ASSERT_NE(history[1].error_code, 0);
+    ASSERT_GE(history[0].age, history[1].age);
+}
+
+class ResettableLogicMonitorFixture : public ::testing::Test
+{
+  protected:
+    std::optional<LogicMonitor> logic_monitor_;
+    StateTag state1_{"state1"};
+    StateTag state2_{"state2"};
+
+    void SetUp() override
+    {
+        RecordProperty("TestType", "interface-test");
+        RecordProperty("DerivationTechnique", "explorative-testing");
+
+        MonitorTag logic_monitor_tag{"logic_monitor"};
+        auto logic_monitor_builder{LogicMonitorBuilder{state1_}
+                                       .add_state(state1_, {state2_})
+                                       .add_state(state2_, {})
+                                       .with_reset_policy(ResetPolicy::InitialState)
+                                       .with_history_capacity(1)};
+
+        auto hmon_build_result{
+            HealthMonitorBuilder{}.add_logic_monitor(logic_monitor_tag, std::move(logic_monitor_builder)).build()};
+        ASSERT_TRUE(hmon_build_result.has_value());
+        auto hmon{std::move(hmon_build_result.value())};
+
+        auto get_logic_monitor_result{hmon.get_logic_monitor(logic_monitor_tag)};
+        ASSERT_TRUE(get_logic_monitor_result.has_value());
+        logic_monitor_ = std::move(get_logic_monitor_result.value());
+    }
+};
+
+TEST_F(ResettableLogicMonitorFixture, ResetTo_Succeeds)
+{
+    RecordProperty("Description", "Monitor successfully reset from an invalid state.");
+    // State transition into invalid state.
+    logic_monitor_->transition(StateTag{"unknown"});
+    ASSERT_FALSE(logic_monitor_->state().has_value());
+
+    // Reset.
+    auto reset_result{logic_monitor_->reset_to(state1_)};
+    ASSERT_TRUE(reset_result.has_value());
+    ASSERT_EQ(logic_monitor_->state().value(), state1_);
+    ASSERT_FALSE(logic_monitor_->invalid_transition().has_value());
+
+    // Only the reset is kept in the history.
+    auto history{logic_monitor_->history()};
+    ASSERT_EQ(history.size(), 1);
+    ASSERT_TRUE(history[0].reset);
+    ASSERT_EQ(history[0].target, state1_);
+}
+
+TEST_F(ResettableLogicMonitorFixture, ResetTo_NotInitialState)
+{
+    RecordProperty("Description", "Failed to reset monitor to a state other than initial.");
+    auto reset_result{logic_monitor_->reset_to(state2_)};
+    ASSERT_FALSE(reset_result.has_value());
+    ASSERT_EQ(reset_result.error(), Error::InvalidArgument);
+}
+
+class EventLogicMonitorFixture : public ::testing::Test
+{
+  protected:
+    std::optional<LogicMonitor> logic_monitor_;
+    StateTag off_{"off"};
+    StateTag on_{"on"};
+    EventTag start_{"start"};
+    EventTag stop_{"stop"};
+
+    void SetUp() override
+    {
+        RecordProperty("TestType", "interface-test");
+        RecordProperty("DerivationTechnique", "explorative-testing");
+
+        MonitorTag logic_monitor_tag{"logic_monitor"};
+        auto logic_monitor_builder{LogicMonitorBuilder{off_}
+                                       .add_state(off_, {on_})
+                                       .add_state(on_, {off_})
+                                       .add_event(off_, start_, on_)
+                                       .add_event(on_, stop_, off_)};
+
+        auto hmon_build_result{
+            HealthMonitorBuilder{}.add_logic_monitor(logic_monitor_tag, std::move(logic_monitor_builder)).build()};
+        ASSERT_TRUE(hmon_build_result.has_value());
+        auto hmon{std::move(hmon_build_result.value())};
+
+        auto get_logic_monitor_result{hmon.get_logic_monitor(logic_monitor_tag)};
+        ASSERT_TRUE(get_logic_monitor_result.has_value());
+        logic_monitor_ = std::move(get_logic_monitor_result.value());
+    }
+};
+
+TEST_F(EventLogicMonitorFixture, Event_Succeeds)
+{
+    RecordProperty("Description", "Monitor successfully transitioned on expected events.");
+    auto event_result{logic_monitor_->event(start_)};
+    ASSERT_TRUE(event_result.has_value());
+    ASSERT_EQ(event_result.value(), on_);
+
+    event_result = logic_monitor_->event(stop_);
+    ASSERT_TRUE(event_result.has_value());
+    ASSERT_EQ(event_result.value(), off_);
+    ASSERT_FALSE(logic_monitor_->unexpected_event().has_value());
+
+    auto history{logic_monitor_->history()};
+    ASSERT_EQ(history.size(), 2);
+    ASSERT_EQ(history[0].event, start_);
+    ASSERT_EQ(history[0].target, on_);
+    ASSERT_EQ(history[1].event, stop_);
+    ASSERT_EQ(history[1].target, off_);
+}
+
+TEST_F(EventLogicMonitorFixture, Event_Unexpected)
+{
+    RecordProperty("Description", "Unexpected event successfully reported.");
+    auto event_result{logic_monitor_->event(stop_)};
+    ASSERT_FALSE(event_result.has_value());
+    ASSERT_EQ(event_result.error(), Error::Failed);
+
+    auto unexpected_event{logic_monitor_->unexpected_event()};
+    ASSERT_TRUE(unexpected_event.has_value());
+    ASSERT_EQ(unexpected_event->first, off_);
+    ASSERT_EQ(unexpected_event->second, stop_);
+    ASSERT_FALSE(logic_monitor_->invalid_transition().has_value());
+}
@@ -0,0 +1,322 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Default clock resolution.
+pub(crate) const DEFAULT_CLOCK_RESOLUTION: Duration = Duration::from_millis(1);
+/// Finest accepted clock resolution.
+pub(crate) const MIN_CLOCK_RESOLUTION: Duration = Duration::from_micros(1);
+/// Coarsest accepted clock resolution.
+pub(crate) const MAX_CLOCK_RESOLUTION: Duration = Duration::from_millis(1);
+
+/// Source of monotonic time used by the [`crate::HealthMonitor`].
+///
+/// [`SystemClock`] is used by default.
+/// [`VirtualClock`] allows for deterministic tests without sleeping.
+pub trait TimeSource: Send + Sync {
+    /// Current time point.
+    fn now(&self) -> Instant;
+}
+
+/// Time source based on [`Instant::now`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl TimeSource for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Manually advanced time source.
+///
+/// Time is frozen at the creation time point and only moves forward with [`VirtualClock::advance`].
+/// Clones share the same time.
+///
+/// # Note
+///
+/// Monitoring thread still sleeps in real time between cycles.
+/// [`crate::HealthMonitorDriver`] is recommended for fully deterministic tests.
+#[derive(Clone, Debug)]
+pub struct VirtualClock {
+    state: Arc<VirtualClockState>,
+}
+
+#[derive(Debug)]
+struct VirtualClockState {
+    /// Time point at which the clock was created.
+    starting_point: Instant,
+    /// Time elapsed since `starting_point` in nanoseconds.
+    elapsed_ns: AtomicU64,
+    /// Max time elapsed since `starting_point` representable as [`Instant`], in nanoseconds.
+    max_elapsed_ns: u64,
+}
+
+/// Max number of nanoseconds which can be added to `starting_point`.
+fn max_elapsed_ns(starting_point: Instant) -> u64 {
+    let representable = |elapsed_ns| starting_point.checked_add(Duration::from_nanos(elapsed_ns)).is_some();
+    if representable(u64::MAX) {
+        return u64::MAX;
+    }
+
+    // Binary search, `low` is always representable and `high` is not.
+    let (mut low, mut high) = (0, u64::MAX);
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if representable(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+impl VirtualClock {
+    /// Create a new [`VirtualClock`] frozen at the current time point.
+    pub fn new() -> Self {
+        let starting_point = Instant::now();
+        Self {
+            state: Arc::new(VirtualClockState {
+                starting_point,
+                elapsed_ns: AtomicU64::new(0),
+                max_elapsed_ns: max_elapsed_ns(starting_point),
+            }),
+        }
+    }
+
+    /// Move the time forward by `duration`.
+    /// Time saturates at the latest representable time point.
+    pub fn advance(&self, duration: Duration) {
+        let duration_ns = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        let _ = self
+            .state
+            .elapsed_ns
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |elapsed_ns| {
+                Some(elapsed_ns.saturating_add(duration_ns).min(self.state.max_elapsed_ns))
+            });
+    }
+
+    /// Time elapsed since the clock was created.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.state.elapsed_ns.load(Ordering::Acquire))
+    }
+}
+
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSource for VirtualClock {
+    fn now(&self) -> Instant {
+        // Elapsed time is limited in `advance`, addition does not overflow.
+        self.state
+            .starting_point
+            .checked_add(self.elapsed())
+            .expect("virtual time point out of range")
+    }
+}
+
+/// Monotonic clock shared by all monitors of a [`crate::HealthMonitor`].
+///
+/// Time points are represented as 64-bit numbers of ticks since the clock starting point.
+/// Length of a tick is defined by the clock resolution.
+/// Conversions saturate instead of overflowing.
+/// With the finest accepted resolution ([`MIN_CLOCK_RESOLUTION`]) ticks do not overflow within any realistic uptime.
+#[derive(Clone)]
+pub(crate) struct Clock {
+    /// Source of time points.
+    time_source: Arc<dyn TimeSource>,
+    /// Reference time point, tick zero.
+    starting_point: Instant,
+    /// Length of a tick in nanoseconds.
+    resolution_ns: u64,
+}
+
+impl Clock {
+    /// Create a new [`Clock`] starting at the current time point of `time_source`.
+    ///
+    /// - `resolution` - length of a tick, rounded to whole nanoseconds, at least one nanosecond.
+    /// - `time_source` - source of time points.
+    pub(crate) fn new(resolution: Duration, time_source: Arc<dyn TimeSource>) -> Self {
+        let resolution_ns = u64::try_from(resolution.as_nanos()).unwrap_or(u64::MAX).max(1);
+        Self {
+            starting_point: time_source.now(),
+            time_source,
+            resolution_ns,
+        }
+    }
+
+    /// Current time point.
+    pub(crate) fn instant(&self) -> Instant {
+        self.time_source.now()
+    }
+
+    /// Current time point in ticks.
+    pub(crate) fn now(&self) -> u64 {
+        self.ticks_at(self.instant())
+    }
+
+    /// Time point in ticks.
+    /// Time points earlier than the clock starting point are zero.
+    pub(crate) fn ticks_at(&self, time_point: Instant) -> u64 {
+        self.ticks(time_point.saturating_duration_since(self.starting_point))
+    }
+
+    /// Time point of provided number of ticks.
+    /// Ticks provided by this clock are always convertible, otherwise the current time point is returned.
+    pub(crate) fn instant_at(&self, ticks: u64) -> Instant {
+        self.starting_point
+            .checked_add(self.duration(ticks))
+            .unwrap_or_else(|| self.instant())
+    }
+
+    /// Duration in ticks, rounded down.
+    pub(crate) fn ticks(&self, duration: Duration) -> u64 {
+        let ticks = duration.as_nanos() / u128::from(self.resolution_ns);
+        u64::try_from(ticks).unwrap_or(u64::MAX)
+    }
+
+    /// Duration of provided number of ticks.
+    pub(crate) fn duration(&self, ticks: u64) -> Duration {
+        let nanos = u128::from(ticks) * u128::from(self.resolution_ns);
+        let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+        Duration::new(secs, (nanos % 1_000_000_000) as u32)
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new(DEFAULT_CLOCK_RESOLUTION, Arc::new(SystemClock))
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::clock::{max_elapsed_ns, Clock, TimeSource, VirtualClock, DEFAULT_CLOCK_RESOLUTION};
+    use core::time::Duration;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    fn clock_with_resolution(resolution: Duration) -> Clock {
+        Clock::new(resolution, Arc::new(VirtualClock::new()))
+    }
+
+    const HUNDRED_DAYS: Duration = Duration::from_secs(100 * 24 * 60 * 60);
+
+    #[test]
+    fn clock_default_resolution() {
+        let clock = Clock::default();
+        assert_eq!(clock.duration(1), DEFAULT_CLOCK_RESOLUTION);
+        assert_eq!(clock.ticks(Duration::from_micros(1999)), 1);
+    }
+
+    #[test]
+    fn clock_sub_millisecond_resolution() {
+        let clock = clock_with_resolution(Duration::from_micros(10));
+        assert_eq!(clock.ticks(Duration::from_millis(1)), 100);
+        assert_eq!(clock.duration(150), Duration::from_micros(1500));
+    }
+
+    #[test]
+    fn clock_zero_resolution_is_one_nanosecond() {
+        let clock = clock_with_resolution(Duration::ZERO);
+        assert_eq!(clock.ticks(Duration::from_nanos(5)), 5);
+    }
+
+    #[test]
+    fn clock_time_point_before_starting_point() {
+        let earlier = Instant::now();
+        let clock = Clock::default();
+        assert_eq!(clock.ticks_at(earlier), 0);
+    }
+
+    #[test]
+    fn clock_long_uptime() {
+        // Millisecond ticks exceed 32 bits after ~49.7 days.
+        let clock = Clock::default();
+        let later = Instant::now().checked_add(HUNDRED_DAYS).unwrap();
+        let ticks = clock.ticks_at(later);
+        assert!(ticks >= HUNDRED_DAYS.as_millis() as u64);
+        assert!(ticks > u64::from(u32::MAX));
+
+        // Nanosecond ticks are also sufficient.
+        let clock = clock_with_resolution(Duration::from_nanos(1));
+        assert_eq!(clock.ticks(HUNDRED_DAYS), HUNDRED_DAYS.as_nanos() as u64);
+        assert_eq!(clock.duration(clock.ticks(HUNDRED_DAYS)), HUNDRED_DAYS);
+    }
+
+    #[test]
+    fn virtual_clock_advance() {
+        let time_source = VirtualClock::new();
+        let starting_point = time_source.now();
+        let clone = time_source.clone();
+
+        clone.advance(Duration::from_millis(15));
+        assert_eq!(time_source.elapsed(), Duration::from_millis(15));
+        assert_eq!(time_source.now(), starting_point + Duration::from_millis(15));
+
+        time_source.advance(Duration::MAX);
+        assert_eq!(clone.elapsed(), Duration::from_nanos(u64::MAX));
+    }
+
+    #[test]
+    fn virtual_clock_advance_saturates() {
+        let time_source = VirtualClock::new();
+        let starting_point = time_source.now();
+
+        time_source.advance(Duration::MAX);
+        let saturated = time_source.now();
+        assert!(saturated > starting_point);
+
+        // Time never moves backwards.
+        time_source.advance(Duration::from_secs(1));
+        assert_eq!(time_source.now(), saturated);
+    }
+
+    #[test]
+    fn virtual_clock_max_elapsed() {
+        let starting_point = Instant::now();
+        let max = max_elapsed_ns(starting_point);
+        assert!(starting_point.checked_add(Duration::from_nanos(max)).is_some());
+        if max < u64::MAX {
+            assert!(starting_point.checked_add(Duration::from_nanos(max + 1)).is_none());
+        }
+    }
+
+    #[test]
+    fn clock_with_virtual_time_source() {
+        let time_source = VirtualClock::new();
+        let clock = Clock::new(Duration::from_micros(1), Arc::new(time_source.clone()));
+        assert_eq!(clock.now(), 0);
+
+        time_source.advance(Duration::from_micros(1500));
+        assert_eq!(clock.now(), 1500);
+        assert_eq!(clock.instant(), time_source.now());
+    }
+
+    #[test]
+    fn clock_conversions_saturate() {
+        let clock = clock_with_resolution(Duration::from_nanos(1));
+        assert_eq!(clock.ticks(Duration::MAX), u64::MAX);
+
+        let clock = clock_with_resolution(Duration::from_secs(1));
+        assert_eq!(clock.duration(u64::MAX), Duration::from_secs(u64::MAX));
+    }
+}
@@ -11,10 +11,12 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+use crate::custom::CustomEvaluationError;
 use crate::deadline::DeadlineEvaluationError;
 use crate::heartbeat::HeartbeatEvaluationError;
 use crate::log::ScoreDebug;
 use crate::logic::LogicEvaluationError;
+use crate::status::FailureOrigin;
 use crate::tag::MonitorTag;
 use core::cell::Cell;
 use core::hash::Hash;
@@ -80,14 +82,60 @@ pub(crate) trait Monitor {
     fn get_eval_handle(&self) -> MonitorEvalHandle;
 }
 
+/// Monitor kinds.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum MonitorKind {
+    /// Deadline monitor.
+    Deadline,
+    /// Heartbeat monitor.
+    Heartbeat,
+    /// Logic monitor.
+    Logic,
+    /// Custom monitor.
+    Custom,
+}
+
+impl From<MonitorKind> for u8 {
+    fn from(value: MonitorKind) -> Self {
+        value as u8
+    }
+}
+
 /// Errors that can occur during monitor evaluation.
 /// Contains failing monitor type.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
-#[allow(dead_code)]
-pub(crate) enum MonitorEvaluationError {
+pub enum MonitorEvaluationError {
+    /// Error reported by a deadline monitor.
     Deadline(DeadlineEvaluationError),
+    /// Error reported by a heartbeat monitor.
     Heartbeat(HeartbeatEvaluationError),
+    /// Error reported by a logic monitor.
     Logic(LogicEvaluationError),
+    /// Error reported by a custom monitor.
+    Custom(CustomEvaluationError),
+}
+
+impl MonitorEvaluationError {
+    /// Kind of the monitor that reported the error.
+    pub fn kind(&self) -> MonitorKind {
+        match self {
+            MonitorEvaluationError::Deadline(_) => MonitorKind::Deadline,
+            MonitorEvaluationError::Heartbeat(_) => MonitorKind::Heartbeat,
+            MonitorEvaluationError::Logic(_) => MonitorKind::Logic,
+            MonitorEvaluationError::Custom(_) => MonitorKind::Custom,
+        }
+    }
+
+    /// Error code of the concrete error, unique within the monitor kind.
+    pub(crate) fn code(&self) -> u8 {
+        match *self {
+            MonitorEvaluationError::Deadline(error) => error.into(),
+            MonitorEvaluationError::Heartbeat(error) => error.into(),
+            MonitorEvaluationError::Logic(error) => error.into(),
+            MonitorEvaluationError::Custom(error) => error.into(),
+        }
+    }
 }
 
 impl From<DeadlineEvaluationError> for MonitorEvaluationError {
@@ -108,13 +156,25 @@ impl From<LogicEvaluationError> for MonitorEvaluationError {
     }
 }
 
+impl From<CustomEvaluationError> for MonitorEvaluationError {
+    fn from(value: CustomEvaluationError) -> Self {
+        MonitorEvaluationError::Custom(value)
+    }
+}
+
+/// Error handling callback used by [`MonitorEvaluator::evaluate`].
+pub(crate) type OnErrorFn<'a> = dyn FnMut(&MonitorTag, MonitorEvaluationError, Option<FailureOrigin>) + 'a;
+
 /// Trait for evaluating monitors and reporting errors to be used by HealthMonitor.
 pub(crate) trait MonitorEvaluator {
     /// Run monitor evaluation.
     ///
     /// - `hmon_starting_point` - starting point of all monitors.
-    /// - `on_error` - error handling, containing tag of failing object and error code.
-    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError));
+    /// - `on_error` - error handling, containing tag of failing monitor, error code and failing object within the monitor.
+    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut OnErrorFn<'_>);
+
+    /// Discard errors observed so far and restart monitoring from the current time point.
+    fn rearm(&self);
 }
 
 /// Handle to a monitor evaluator, allowing for dynamic dispatch.
@@ -129,45 +189,31 @@ impl MonitorEvalHandle {
 }
 
 impl MonitorEvaluator for MonitorEvalHandle {
-    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError)) {
+    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut OnErrorFn<'_>) {
         self.inner.evaluate(hmon_starting_point, on_error)
     }
-}
 
-/// Get offset between two time points.
-/// [`None`] is returned if `later_time_point` is actually earlier than `earlier_time_point`.
-pub(crate) fn time_offset<T>(later_time_point: Instant, earlier_time_point: Instant) -> Option<T>
-where
-    T: TryFrom<u128>,
-    <T as TryFrom<u128>>::Error: core::fmt::Debug,
-{
-    let duration_since = later_time_point.checked_duration_since(earlier_time_point)?;
-    Some(duration_to_int(duration_since))
-}
-
-/// Get duration as an integer containing milliseconds.
-pub(crate) fn duration_to_int<T>(duration: Duration) -> T
-where
-    T: TryFrom<u128>,
-    <T as TryFrom<u128>>::Error: core::fmt::Debug,
-{
-    let millis = duration.as_millis();
-    T::try_from(millis).expect("Duration is too big for the integer of this type")
+    fn rearm(&self) {
+        self.inner.rearm()
+    }
 }
 
 /// Marker for disabling [`Sync`].
 pub(crate) type PhantomUnsync = PhantomData<Cell<()>>;
 
 #[cfg(not(loom))]
-pub use core::sync::atomic::{AtomicU64, Ordering};
+pub use core::sync::atomic::{fence, AtomicPtr, AtomicU64, Ordering};
+#[cfg(loom)]
+pub use loom::sync::atomic::{fence, AtomicPtr, AtomicU64, Ordering};
 #[cfg(loom)]
-pub use loom::sync::atomic::{AtomicU64, Ordering};
+pub use loom::sync::{Mutex, MutexGuard};
+#[cfg(not(loom))]
+pub use std::sync::{Mutex, MutexGuard};
 
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::{duration_to_int, time_offset, TimeRange};
+    use crate::common::TimeRange;
     use core::time::Duration;
-    use std::time::Instant;
 
     #[test]
     fn time_range_new_valid() {
@@ -202,45 +248,4 @@ mod tests {
         let tolerance = TimeRange::new(Duration::from_millis(200), Duration::from_millis(300));
         let _ = TimeRange::from_interval(interval, tolerance);
     }
-
-    #[test]
-    fn time_offset_valid() {
-        let monitor_starting_point = Instant::now();
-        let hmon_starting_point = Instant::now();
-        let result = time_offset::<u32>(hmon_starting_point, monitor_starting_point);
-        // Allow small offset.
-        assert!(result.is_some_and(|o| o < 10));
-    }
-
-    #[test]
-    fn time_offset_wrong_order() {
-        let hmon_starting_point = Instant::now();
-        let monitor_starting_point = Instant::now();
-        let result = time_offset::<u32>(hmon_starting_point, monitor_starting_point);
-        assert!(result.is_none());
-    }
-
-    #[test]
-    #[should_panic(expected = "Duration is too big for the integer of this type")]
-    fn time_offset_diff_too_large() {
-        const HUNDRED_DAYS_AS_SECS: u64 = 100 * 24 * 60 * 60;
-        let monitor_starting_point = Instant::now();
-        let hmon_starting_point = Instant::now()
-            .checked_add(Duration::from_secs(HUNDRED_DAYS_AS_SECS))
-            .unwrap();
-        let _ = time_offset::<u32>(hmon_starting_point, monitor_starting_point);
-    }
-
-    #[test]
-    fn duration_to_int_valid() {
-        let result: u32 = duration_to_int(Duration::from_millis(1234));
-        assert_eq!(result, 1234);
-    }
-
-    #[test]
-    #[should_panic(expected = "Duration is too big for the integer of this type")]
-    fn duration_to_int_too_large() {
-        const HUNDRED_DAYS_AS_SECS: u64 = 100 * 24 * 60 * 60;
-        let _result: u32 = duration_to_int(Duration::from_secs(HUNDRED_DAYS_AS_SECS));
-    }
 }
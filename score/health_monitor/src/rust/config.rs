@@ -0,0 +1,1034 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Health monitor configuration file support.
+//! Refer to [`HealthMonitorBuilder::from_config`] for the format description.
+
+use crate::common::TimeRange;
+use crate::deadline::DeadlineMonitorBuilder;
+use crate::health_monitor::{
+    HealthMonitorBuilder, HealthMonitorError, DEFAULT_INTERNAL_PROCESSING_CYCLE, DEFAULT_SUPERVISOR_API_CYCLE,
+};
+use crate::heartbeat::HeartbeatMonitorBuilder;
+use crate::logic::LogicMonitorBuilder;
+use crate::tag::{DeadlineTag, MonitorTag, StateTag};
+use crate::tolerance::FailureTolerance;
+use core::fmt;
+use core::time::Duration;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::path::Path;
+use thread::{SchedulerParameters, SchedulerPolicy, ThreadParameters};
+
+/// Configuration loading errors.
+/// Paths are provided in `field[index].field` form, e.g., `deadline_monitors[0].deadlines[1].max_ms`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// Configuration file cannot be read.
+    Io(std::io::ErrorKind),
+    /// Configuration is not a valid JSON document.
+    Syntax {
+        /// Line of the error, starting from 1.
+        line: usize,
+        /// Column of the error, starting from 1.
+        column: usize,
+    },
+    /// Required field is missing.
+    MissingField {
+        /// Path to the missing field.
+        path: String,
+    },
+    /// Field is not known.
+    UnknownField {
+        /// Path to the unknown field.
+        path: String,
+    },
+    /// Field has an unexpected type.
+    InvalidType {
+        /// Path to the field.
+        path: String,
+        /// Expected type.
+        expected: &'static str,
+    },
+    /// Field value is invalid.
+    InvalidValue {
+        /// Path to the field.
+        path: String,
+        /// Reason why the value is invalid.
+        reason: &'static str,
+    },
+    /// Tag is already defined.
+    DuplicateTag {
+        /// Path to the tag.
+        path: String,
+    },
+    /// Values are rejected by the builder.
+    Rejected {
+        /// Path to the rejected object.
+        path: String,
+        /// Error reported by the builder.
+        error: HealthMonitorError,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(kind) => write!(f, "cannot read configuration file: {kind}"),
+            ConfigError::Syntax { line, column } => write!(f, "invalid JSON at line {line}, column {column}"),
+            ConfigError::MissingField { path } => write!(f, "missing required field '{path}'"),
+            ConfigError::UnknownField { path } => write!(f, "unknown field '{path}'"),
+            ConfigError::InvalidType { path, expected } => write!(f, "field '{path}' must be {expected}"),
+            ConfigError::InvalidValue { path, reason } => write!(f, "invalid value of '{path}': {reason}"),
+            ConfigError::DuplicateTag { path } => write!(f, "tag '{path}' is already defined"),
+            ConfigError::Rejected { path, error } => write!(f, "'{path}' rejected by the builder: {error:?}"),
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {}
+
+/// Load [`HealthMonitorBuilder`] from the configuration file.
+pub(crate) fn load(path: &Path) -> Result<HealthMonitorBuilder, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|e| ConfigError::Io(e.kind()))?;
+    parse(&content)
+}
+
+/// Parse [`HealthMonitorBuilder`] from the configuration document.
+pub(crate) fn parse(content: &str) -> Result<HealthMonitorBuilder, ConfigError> {
+    let root_value: Value = serde_json::from_str(content).map_err(|e| ConfigError::Syntax {
+        line: e.line(),
+        column: e.column(),
+    })?;
+
+    let root = Node::root(&root_value).object(&[
+        "supervisor_api_cycle_ms",
+        "internal_processing_cycle_ms",
+        "cycle_overrun_limit",
+        "worker_stall_timeout_ms",
+        "time_resolution_us",
+        "thread_parameters",
+        "deadline_monitors",
+        "heartbeat_monitors",
+        "logic_monitors",
+    ])?;
+
+    let mut builder = HealthMonitorBuilder::new();
+
+    // Cycles.
+    let internal_processing_cycle = match root.optional("internal_processing_cycle_ms") {
+        Some(node) => node.duration_ms_non_zero()?,
+        None => DEFAULT_INTERNAL_PROCESSING_CYCLE,
+    };
+    builder.with_internal_processing_cycle_internal(internal_processing_cycle);
+
+    let supervisor_api_cycle = match root.optional("supervisor_api_cycle_ms") {
+        Some(node) => node.duration_ms_non_zero()?,
+        None => DEFAULT_SUPERVISOR_API_CYCLE,
+    };
+    builder.with_supervisor_api_cycle_internal(supervisor_api_cycle);
+    builder
+        .validate_cycles()
+        .map_err(|error| rejected("supervisor_api_cycle_ms", error))?;
+
+    if let Some(node) = root.optional("cycle_overrun_limit") {
+        builder.with_cycle_overrun_limit_internal(node.u32()?);
+        builder
+            .validate_cycle_overrun_limit()
+            .map_err(|error| rejected(&node.path, error))?;
+    }
+
+    if let Some(node) = root.optional("worker_stall_timeout_ms") {
+        builder.with_worker_stall_timeout_internal(node.duration_ms()?);
+        builder
+            .validate_worker_stall_timeout()
+            .map_err(|error| rejected(&node.path, error))?;
+    }
+
+    if let Some(node) = root.optional("time_resolution_us") {
+        builder.with_time_resolution_internal(Duration::from_micros(node.u64()?));
+        builder
+            .validate_time_resolution()
+            .map_err(|error| rejected(&node.path, error))?;
+    }
+
+    // Thread parameters.
+    if let Some(node) = root.optional("thread_parameters") {
+        builder.thread_parameters_internal(parse_thread_parameters(&node)?);
+    }
+
+    // Monitors.
+    let mut monitor_tags = HashSet::new();
+
+    if let Some(node) = root.optional("deadline_monitors") {
+        for monitor_node in node.array()? {
+            let monitor = monitor_node.object(&["tag", "deadlines", "failure_tolerance"])?;
+            let monitor_tag = unique_tag(&monitor.required("tag")?, &mut monitor_tags)?;
+
+            let mut deadline_tags = HashSet::new();
+            let mut monitor_builder = DeadlineMonitorBuilder::new();
+            if let Some(deadlines_node) = monitor.optional("deadlines") {
+                for deadline_node in deadlines_node.array()? {
+                    let deadline = deadline_node.object(&["tag", "min_ms", "max_ms", "max_instances"])?;
+                    let deadline_tag = unique_tag(&deadline.required("tag")?, &mut deadline_tags)?;
+                    let range = parse_time_range(&deadline)?;
+                    let max_instances = match deadline.optional("max_instances") {
+                        Some(node) => node.usize()?,
+                        None => 1,
+                    };
+                    monitor_builder =
+                        monitor_builder.add_concurrent_deadline(DeadlineTag::from(deadline_tag), range, max_instances);
+                }
+            }
+
+            monitor_builder
+                .validate(MonitorTag::from(monitor_tag))
+                .map_err(|error| rejected(&monitor.path, error))?;
+
+            builder.add_deadline_monitor_internal(MonitorTag::from(monitor_tag), monitor_builder);
+            parse_failure_tolerance(&mut builder, &monitor, monitor_tag)?;
+        }
+    }
+
+    if let Some(node) = root.optional("heartbeat_monitors") {
+        for monitor_node in node.array()? {
+            let monitor = monitor_node.object(&[
+                "tag",
+                "min_ms",
+                "max_ms",
+                "reporting_cycle_ms",
+                "min_indications",
+                "max_indications",
+                "failure_tolerance",
+            ])?;
+            let monitor_tag = unique_tag(&monitor.required("tag")?, &mut monitor_tags)?;
+            let monitor_builder = match monitor.optional("reporting_cycle_ms") {
+                Some(node) => parse_indication_window(&monitor, &node)?,
+                None => HeartbeatMonitorBuilder::new(parse_time_range(&monitor)?),
+            };
+            monitor_builder
+                .validate(internal_processing_cycle)
+                .map_err(|error| rejected(&monitor.path, error))?;
+
+            builder.add_heartbeat_monitor_internal(MonitorTag::from(monitor_tag), monitor_builder);
+            parse_failure_tolerance(&mut builder, &monitor, monitor_tag)?;
+        }
+    }
+
+    if let Some(node) = root.optional("logic_monitors") {
+        for monitor_node in node.array()? {
+            let monitor = monitor_node.object(&["tag", "initial_state", "states", "failure_tolerance"])?;
+            let monitor_tag = unique_tag(&monitor.required("tag")?, &mut monitor_tags)?;
+            let monitor_builder = parse_logic_monitor(&monitor)?;
+            builder.add_logic_monitor_internal(MonitorTag::from(monitor_tag), monitor_builder);
+            parse_failure_tolerance(&mut builder, &monitor, monitor_tag)?;
+        }
+    }
+
+    builder.validate_monitors().map_err(|error| rejected("", error))?;
+
+    Ok(builder)
+}
+
+fn parse_thread_parameters(node: &Node<'_>) -> Result<ThreadParameters, ConfigError> {
+    let object = node.object(&["scheduler_policy", "priority", "affinity", "stack_size"])?;
+    let mut thread_parameters = ThreadParameters::new();
+
+    let policy = match object.optional("scheduler_policy") {
+        Some(policy_node) => Some(match policy_node.str()? {
+            "other" => SchedulerPolicy::Other,
+            "fifo" => SchedulerPolicy::Fifo,
+            "round_robin" => SchedulerPolicy::RoundRobin,
+            _ => {
+                return Err(ConfigError::InvalidValue {
+                    path: policy_node.path,
+                    reason: "must be one of 'other', 'fifo' or 'round_robin'",
+                })
+            },
+        }),
+        None => None,
+    };
+
+    match (policy, object.optional("priority")) {
+        (Some(policy), Some(priority_node)) => {
+            let priority = priority_node.i64()?;
+            if priority < i64::from(policy.priority_min()) || priority > i64::from(policy.priority_max()) {
+                return Err(ConfigError::InvalidValue {
+                    path: priority_node.path,
+                    reason: "out of range allowed by scheduler_policy",
+                });
+            }
+            thread_parameters =
+                thread_parameters.scheduler_parameters(SchedulerParameters::new(policy, priority as i32));
+        },
+        (Some(policy), None) => {
+            thread_parameters =
+                thread_parameters.scheduler_parameters(SchedulerParameters::new(policy, policy.priority_min()));
+        },
+        (None, Some(_)) => {
+            return Err(ConfigError::MissingField {
+                path: field_path(&object.path, "scheduler_policy"),
+            });
+        },
+        (None, None) => {},
+    }
+
+    if let Some(affinity_node) = object.optional("affinity") {
+        let affinity = affinity_node
+            .array()?
+            .iter()
+            .map(|cpu_node| cpu_node.usize())
+            .collect::<Result<Vec<_>, _>>()?;
+        thread_parameters = thread_parameters.affinity(&affinity);
+    }
+
+    if let Some(stack_size_node) = object.optional("stack_size") {
+        thread_parameters = thread_parameters.stack_size(stack_size_node.usize()?);
+    }
+
+    Ok(thread_parameters)
+}
+
+fn parse_logic_monitor(monitor: &Object<'_>) -> Result<LogicMonitorBuilder, ConfigError> {
+    let initial_state_node = monitor.required("initial_state")?;
+    let initial_state = initial_state_node.str()?;
+
+    // Collect states first - transitions can refer to states defined later.
+    let states_node = monitor.required("states")?;
+    let mut states = Vec::new();
+    let mut state_tags = HashSet::new();
+    for state_node in states_node.array()? {
+        let state = state_node.object(&["tag", "transitions", "min_ms", "max_ms"])?;
+        let state_tag = unique_tag(&state.required("tag")?, &mut state_tags)?;
+        states.push((state_tag, state));
+    }
+
+    if states.is_empty() {
+        return Err(ConfigError::InvalidValue {
+            path: states_node.path,
+            reason: "at least one state must be defined",
+        });
+    }
+    if !state_tags.contains(initial_state) {
+        return Err(ConfigError::InvalidValue {
+            path: initial_state_node.path,
+            reason: "state is not defined",
+        });
+    }
+
+    let mut monitor_builder = LogicMonitorBuilder::new(StateTag::from(initial_state));
+    for (state_tag, state) in states {
+        let mut allowed_targets = Vec::new();
+        if let Some(transitions_node) = state.optional("transitions") {
+            for target_node in transitions_node.array()? {
+                let target = target_node.str()?;
+                if !state_tags.contains(target) {
+                    return Err(ConfigError::InvalidValue {
+                        path: target_node.path,
+                        reason: "state is not defined",
+                    });
+                }
+                allowed_targets.push(StateTag::from(target));
+            }
+        }
+
+        // Dwell time is optional, both bounds are required when provided.
+        if state.optional("min_ms").is_some() || state.optional("max_ms").is_some() {
+            let dwell = parse_time_range(&state)?;
+            monitor_builder.add_timed_state_internal(StateTag::from(state_tag), &allowed_targets, dwell);
+        } else {
+            monitor_builder.add_state_internal(StateTag::from(state_tag), &allowed_targets);
+        }
+    }
+
+    Ok(monitor_builder)
+}
+
+fn parse_indication_window(
+    monitor: &Object<'_>,
+    reporting_cycle_node: &Node<'_>,
+) -> Result<HeartbeatMonitorBuilder, ConfigError> {
+    // Time range fields are not used in this mode.
+    for field in ["min_ms", "max_ms"] {
+        if monitor.optional(field).is_some() {
+            return Err(ConfigError::InvalidValue {
+                path: field_path(&monitor.path, field),
+                reason: "must not be combined with reporting_cycle_ms",
+            });
+        }
+    }
+
+    let reporting_cycle = reporting_cycle_node.duration_ms()?;
+    let min_indications = monitor.required("min_indications")?.u16()?;
+    let max_indications = monitor.required("max_indications")?.u16()?;
+    Ok(HeartbeatMonitorBuilder::with_indications(
+        reporting_cycle,
+        min_indications,
+        max_indications,
+    ))
+}
+
+/// Parse optional failure tolerance of the monitor.
+fn parse_failure_tolerance(
+    builder: &mut HealthMonitorBuilder,
+    monitor: &Object<'_>,
+    monitor_tag: &str,
+) -> Result<(), ConfigError> {
+    let Some(node) = monitor.optional("failure_tolerance") else {
+        return Ok(());
+    };
+    let tolerance = node.object(&["failures", "window", "recovery_cycles"])?;
+
+    let failures = tolerance.required("failures")?.u32()?;
+    let window = tolerance.required("window")?.u32()?;
+    let recovery_cycles = tolerance.required("recovery_cycles")?.u32()?;
+    let failure_tolerance = FailureTolerance::new(failures, window, recovery_cycles);
+    failure_tolerance
+        .validate()
+        .map_err(|error| rejected(&tolerance.path, error))?;
+
+    builder.with_failure_tolerance_internal(MonitorTag::from(monitor_tag), failure_tolerance);
+    Ok(())
+}
+
+fn parse_time_range(object: &Object<'_>) -> Result<TimeRange, ConfigError> {
+    let min = object.required("min_ms")?.duration_ms()?;
+    let max = object.required("max_ms")?.duration_ms()?;
+    TimeRange::new_internal(min, max).ok_or_else(|| ConfigError::InvalidValue {
+        path: field_path(&object.path, "max_ms"),
+        reason: "must not be smaller than min_ms",
+    })
+}
+
+/// Get tag value, checking it was not already used.
+fn unique_tag<'a>(node: &Node<'a>, used_tags: &mut HashSet<&'a str>) -> Result<&'a str, ConfigError> {
+    let tag = node.str()?;
+    if tag.is_empty() {
+        return Err(ConfigError::InvalidValue {
+            path: node.path.clone(),
+            reason: "must not be empty",
+        });
+    }
+    if !used_tags.insert(tag) {
+        return Err(ConfigError::DuplicateTag {
+            path: node.path.clone(),
+        });
+    }
+    Ok(tag)
+}
+
+/// Error of values rejected by the builder, annotated with the path in the document.
+fn rejected(path: &str, error: HealthMonitorError) -> ConfigError {
+    ConfigError::Rejected {
+        path: path.to_string(),
+        error,
+    }
+}
+
+fn field_path(parent: &str, field: &str) -> String {
+    if parent.is_empty() {
+        field.to_string()
+    } else {
+        format!("{parent}.{field}")
+    }
+}
+
+/// JSON value with its path in the document.
+struct Node<'a> {
+    value: &'a Value,
+    path: String,
+}
+
+impl<'a> Node<'a> {
+    fn root(value: &'a Value) -> Self {
+        Self {
+            value,
+            path: String::new(),
+        }
+    }
+
+    fn invalid_type(&self, expected: &'static str) -> ConfigError {
+        ConfigError::InvalidType {
+            path: self.path.clone(),
+            expected,
+        }
+    }
+
+    /// Get as an object, rejecting fields not listed in `known_fields`.
+    fn object(&self, known_fields: &[&str]) -> Result<Object<'a>, ConfigError> {
+        let map = self.value.as_object().ok_or_else(|| self.invalid_type("an object"))?;
+        if let Some(unknown_field) = map.keys().find(|key| !known_fields.contains(&key.as_str())) {
+            return Err(ConfigError::UnknownField {
+                path: field_path(&self.path, unknown_field),
+            });
+        }
+        Ok(Object {
+            map,
+            path: self.path.clone(),
+        })
+    }
+
+    fn array(&self) -> Result<Vec<Node<'a>>, ConfigError> {
+        let values = self.value.as_array().ok_or_else(|| self.invalid_type("an array"))?;
+        Ok(values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| Node {
+                value,
+                path: format!("{}[{}]", self.path, index),
+            })
+            .collect())
+    }
+
+    fn str(&self) -> Result<&'a str, ConfigError> {
+        self.value.as_str().ok_or_else(|| self.invalid_type("a string"))
+    }
+
+    fn i64(&self) -> Result<i64, ConfigError> {
+        self.value.as_i64().ok_or_else(|| self.invalid_type("an integer"))
+    }
+
+    fn u64(&self) -> Result<u64, ConfigError> {
+        self.value
+            .as_u64()
+            .ok_or_else(|| self.invalid_type("a non-negative integer"))
+    }
+
+    fn u16(&self) -> Result<u16, ConfigError> {
+        u16::try_from(self.u64()?).map_err(|_| ConfigError::InvalidValue {
+            path: self.path.clone(),
+            reason: "value is too big",
+        })
+    }
+
+    fn u32(&self) -> Result<u32, ConfigError> {
+        u32::try_from(self.u64()?).map_err(|_| ConfigError::InvalidValue {
+            path: self.path.clone(),
+            reason: "value is too big",
+        })
+    }
+
+    fn usize(&self) -> Result<usize, ConfigError> {
+        usize::try_from(self.u64()?).map_err(|_| ConfigError::InvalidValue {
+            path: self.path.clone(),
+            reason: "value is too big",
+        })
+    }
+
+    fn duration_ms(&self) -> Result<Duration, ConfigError> {
+        Ok(Duration::from_millis(self.u64()?))
+    }
+
+    fn duration_ms_non_zero(&self) -> Result<Duration, ConfigError> {
+        let duration = self.duration_ms()?;
+        if duration.is_zero() {
+            return Err(ConfigError::InvalidValue {
+                path: self.path.clone(),
+                reason: "must be greater than zero",
+            });
+        }
+        Ok(duration)
+    }
+}
+
+/// JSON object with its path in the document.
+struct Object<'a> {
+    map: &'a Map<String, Value>,
+    path: String,
+}
+
+impl<'a> Object<'a> {
+    fn optional(&self, field: &str) -> Option<Node<'a>> {
+        self.map.get(field).map(|value| Node {
+            value,
+            path: field_path(&self.path, field),
+        })
+    }
+
+    fn required(&self, field: &str) -> Result<Node<'a>, ConfigError> {
+        self.optional(field).ok_or_else(|| ConfigError::MissingField {
+            path: field_path(&self.path, field),
+        })
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::config::{load, parse, ConfigError};
+    use crate::health_monitor::HealthMonitorError;
+    use crate::tag::MonitorTag;
+    use crate::Status;
+    use core::time::Duration;
+
+    const FULL_CONFIG: &str = r#"{
+        "supervisor_api_cycle_ms": 200,
+        "internal_processing_cycle_ms": 50,
+        "cycle_overrun_limit": 3,
+        "worker_stall_timeout_ms": 500,
+        "time_resolution_us": 100,
+        "thread_parameters": {
+            "scheduler_policy": "other",
+            "affinity": [0],
+            "stack_size": 65536
+        },
+        "deadline_monitors": [
+            {
+                "tag": "deadline_monitor",
+                "deadlines": [
+                    { "tag": "deadline", "min_ms": 50, "max_ms": 150 },
+                    { "tag": "request", "min_ms": 0, "max_ms": 20, "max_instances": 2 }
+                ]
+            }
+        ],
+        "heartbeat_monitors": [
+            {
+                "tag": "heartbeat_monitor",
+                "min_ms": 800,
+                "max_ms": 1200,
+                "failure_tolerance": { "failures": 2, "window": 10, "recovery_cycles": 5 }
+            },
+            { "tag": "alive_monitor", "reporting_cycle_ms": 1000, "min_indications": 2, "max_indications": 4 }
+        ],
+        "logic_monitors": [
+            {
+                "tag": "logic_monitor",
+                "initial_state": "off",
+                "states": [
+                    { "tag": "off", "transitions": ["on"] },
+                    { "tag": "on", "transitions": ["off"], "min_ms": 0, "max_ms": 60000 }
+                ]
+            }
+        ]
+    }"#;
+
+    fn parse_err(content: &str) -> ConfigError {
+        match parse(content) {
+            Ok(_) => panic!("configuration is expected to be invalid"),
+            Err(e) => e,
+        }
+    }
+
+    fn invalid_value(path: &str, reason: &'static str) -> ConfigError {
+        ConfigError::InvalidValue {
+            path: path.to_string(),
+            reason,
+        }
+    }
+
+    fn rejected(path: &str) -> ConfigError {
+        ConfigError::Rejected {
+            path: path.to_string(),
+            error: HealthMonitorError::InvalidArgument,
+        }
+    }
+
+    #[test]
+    fn parse_full_config_succeeds() {
+        let mut health_monitor = parse(FULL_CONFIG).unwrap().build().unwrap();
+
+        let deadline_monitor = health_monitor
+            .get_deadline_monitor(MonitorTag::from("deadline_monitor"))
+            .unwrap();
+        assert!(deadline_monitor.get_deadline("deadline".into()).is_ok());
+        let _request1 = deadline_monitor.get_deadline("request".into()).unwrap();
+        let _request2 = deadline_monitor.get_deadline("request".into()).unwrap();
+        assert!(deadline_monitor.get_deadline("request".into()).is_err());
+        assert!(health_monitor
+            .get_heartbeat_monitor(MonitorTag::from("heartbeat_monitor"))
+            .is_some());
+        assert!(health_monitor
+            .get_heartbeat_monitor(MonitorTag::from("alive_monitor"))
+            .is_some());
+        let logic_monitor = health_monitor
+            .get_logic_monitor(MonitorTag::from("logic_monitor"))
+            .unwrap();
+        assert_eq!(logic_monitor.state(), Ok("off".into()));
+        assert!(logic_monitor.transition("on".into()).is_ok());
+
+        let driver = health_monitor.into_driver().unwrap();
+        assert_eq!(driver.internal_processing_cycle(), Duration::from_millis(50));
+        assert_eq!(driver.status(), Status::Running);
+    }
+
+    #[test]
+    fn parse_minimal_config_succeeds() {
+        let builder = parse(r#"{ "deadline_monitors": [{ "tag": "deadline_monitor" }] }"#).unwrap();
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn parse_syntax_error() {
+        assert_eq!(
+            parse_err("{\n  \"deadline_monitors\": [\n}"),
+            ConfigError::Syntax { line: 3, column: 1 }
+        );
+    }
+
+    #[test]
+    fn parse_no_monitors() {
+        assert_eq!(
+            parse_err("{}"),
+            ConfigError::Rejected {
+                path: String::new(),
+                error: HealthMonitorError::WrongState
+            }
+        );
+    }
+
+    #[test]
+    fn parse_root_not_object() {
+        assert_eq!(
+            parse_err("[]"),
+            ConfigError::InvalidType {
+                path: String::new(),
+                expected: "an object"
+            }
+        );
+    }
+
+    #[test]
+    fn parse_unknown_field() {
+        assert_eq!(
+            parse_err(r#"{ "deadline_monitors": [{ "tag": "m", "deadlines": [{ "tag": "d", "min": 1 }] }] }"#),
+            ConfigError::UnknownField {
+                path: "deadline_monitors[0].deadlines[0].min".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_missing_field() {
+        assert_eq!(
+            parse_err(r#"{ "heartbeat_monitors": [{ "tag": "m", "min_ms": 100 }] }"#),
+            ConfigError::MissingField {
+                path: "heartbeat_monitors[0].max_ms".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_invalid_type() {
+        assert_eq!(
+            parse_err(r#"{ "internal_processing_cycle_ms": -5, "deadline_monitors": [{ "tag": "m" }] }"#),
+            ConfigError::InvalidType {
+                path: "internal_processing_cycle_ms".to_string(),
+                expected: "a non-negative integer"
+            }
+        );
+    }
+
+    #[test]
+    fn parse_zero_cycle() {
+        assert_eq!(
+            parse_err(r#"{ "supervisor_api_cycle_ms": 0, "deadline_monitors": [{ "tag": "m" }] }"#),
+            invalid_value("supervisor_api_cycle_ms", "must be greater than zero")
+        );
+    }
+
+    #[test]
+    fn parse_zero_cycle_overrun_limit() {
+        assert_eq!(
+            parse_err(r#"{ "cycle_overrun_limit": 0, "deadline_monitors": [{ "tag": "m" }] }"#),
+            rejected("cycle_overrun_limit")
+        );
+    }
+
+    #[test]
+    fn parse_zero_deadline_instances() {
+        assert_eq!(
+            parse_err(
+                r#"{ "deadline_monitors": [{ "tag": "m", "deadlines": [{ "tag": "d", "min_ms": 0, "max_ms": 10, "max_instances": 0 }] }] }"#
+            ),
+            rejected("deadline_monitors[0]")
+        );
+    }
+
+    #[test]
+    fn parse_worker_stall_timeout_too_short() {
+        assert_eq!(
+            parse_err(
+                r#"{ "internal_processing_cycle_ms": 100, "worker_stall_timeout_ms": 100, "deadline_monitors": [{ "tag": "m" }] }"#
+            ),
+            rejected("worker_stall_timeout_ms")
+        );
+    }
+
+    #[test]
+    fn parse_invalid_time_resolution() {
+        for resolution in [0, 1001] {
+            assert_eq!(
+                parse_err(&format!(
+                    r#"{{ "time_resolution_us": {resolution}, "deadline_monitors": [{{ "tag": "m" }}] }}"#
+                )),
+                rejected("time_resolution_us")
+            );
+        }
+    }
+
+    #[test]
+    fn parse_cycles_not_multiple() {
+        assert_eq!(
+            parse_err(
+                r#"{ "supervisor_api_cycle_ms": 150, "internal_processing_cycle_ms": 100, "deadline_monitors": [{ "tag": "m" }] }"#
+            ),
+            rejected("supervisor_api_cycle_ms")
+        );
+    }
+
+    #[test]
+    fn parse_invalid_time_range() {
+        assert_eq!(
+            parse_err(
+                r#"{ "deadline_monitors": [{ "tag": "m", "deadlines": [{ "tag": "d", "min_ms": 20, "max_ms": 10 }] }] }"#
+            ),
+            invalid_value(
+                "deadline_monitors[0].deadlines[0].max_ms",
+                "must not be smaller than min_ms"
+            )
+        );
+    }
+
+    #[test]
+    fn parse_heartbeat_indications_invalid() {
+        for (monitor, expected) in [
+            (
+                r#"{ "tag": "m", "reporting_cycle_ms": 50, "min_indications": 1, "max_indications": 2 }"#,
+                rejected("heartbeat_monitors[0]"),
+            ),
+            (
+                r#"{ "tag": "m", "reporting_cycle_ms": 500, "min_indications": 3, "max_indications": 2 }"#,
+                rejected("heartbeat_monitors[0]"),
+            ),
+            (
+                r#"{ "tag": "m", "reporting_cycle_ms": 500, "min_indications": 1, "max_indications": 70000 }"#,
+                invalid_value("heartbeat_monitors[0].max_indications", "value is too big"),
+            ),
+            (
+                r#"{ "tag": "m", "reporting_cycle_ms": 500, "min_indications": 1, "max_indications": 4095 }"#,
+                rejected("heartbeat_monitors[0]"),
+            ),
+            (
+                r#"{ "tag": "m", "reporting_cycle_ms": 500, "min_ms": 100, "min_indications": 1, "max_indications": 2 }"#,
+                invalid_value(
+                    "heartbeat_monitors[0].min_ms",
+                    "must not be combined with reporting_cycle_ms",
+                ),
+            ),
+        ] {
+            assert_eq!(
+                parse_err(&format!(r#"{{ "heartbeat_monitors": [{monitor}] }}"#)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn parse_failure_tolerance_invalid() {
+        for (tolerance, expected) in [
+            (
+                r#"{ "failures": 0, "window": 5, "recovery_cycles": 1 }"#,
+                rejected("deadline_monitors[0].failure_tolerance"),
+            ),
+            (
+                r#"{ "failures": 3, "window": 2, "recovery_cycles": 1 }"#,
+                rejected("deadline_monitors[0].failure_tolerance"),
+            ),
+            (
+                r#"{ "failures": 3, "window": 65, "recovery_cycles": 1 }"#,
+                rejected("deadline_monitors[0].failure_tolerance"),
+            ),
+            (
+                r#"{ "failures": 3, "window": 5, "recovery_cycles": 0 }"#,
+                rejected("deadline_monitors[0].failure_tolerance"),
+            ),
+            (
+                r#"{ "failures": 5000000000, "window": 5, "recovery_cycles": 1 }"#,
+                invalid_value("deadline_monitors[0].failure_tolerance.failures", "value is too big"),
+            ),
+        ] {
+            assert_eq!(
+                parse_err(&format!(
+                    r#"{{ "deadline_monitors": [{{ "tag": "m", "failure_tolerance": {tolerance} }}] }}"#
+                )),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn parse_failure_tolerance_missing_field() {
+        assert_eq!(
+            parse_err(
+                r#"{ "logic_monitors": [{ "tag": "m", "initial_state": "s", "states": [{ "tag": "s" }], "failure_tolerance": { "failures": 1, "window": 1 } }] }"#
+            ),
+            ConfigError::MissingField {
+                path: "logic_monitors[0].failure_tolerance.recovery_cycles".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_heartbeat_range_too_short() {
+        assert_eq!(
+            parse_err(r#"{ "heartbeat_monitors": [{ "tag": "m", "min_ms": 50, "max_ms": 150 }] }"#),
+            rejected("heartbeat_monitors[0]")
+        );
+    }
+
+    #[test]
+    fn parse_duplicate_monitor_tag() {
+        assert_eq!(
+            parse_err(
+                r#"{
+                    "deadline_monitors": [{ "tag": "m" }],
+                    "heartbeat_monitors": [{ "tag": "m", "min_ms": 100, "max_ms": 200 }]
+                }"#
+            ),
+            ConfigError::DuplicateTag {
+                path: "heartbeat_monitors[0].tag".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_duplicate_deadline_tag() {
+        assert_eq!(
+            parse_err(
+                r#"{ "deadline_monitors": [{ "tag": "m", "deadlines": [
+                    { "tag": "d", "min_ms": 0, "max_ms": 10 },
+                    { "tag": "d", "min_ms": 0, "max_ms": 10 }
+                ] }] }"#
+            ),
+            ConfigError::DuplicateTag {
+                path: "deadline_monitors[0].deadlines[1].tag".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_empty_tag() {
+        assert_eq!(
+            parse_err(r#"{ "deadline_monitors": [{ "tag": "" }] }"#),
+            invalid_value("deadline_monitors[0].tag", "must not be empty")
+        );
+    }
+
+    #[test]
+    fn parse_logic_undefined_initial_state() {
+        assert_eq!(
+            parse_err(r#"{ "logic_monitors": [{ "tag": "m", "initial_state": "x", "states": [{ "tag": "a" }] }] }"#),
+            invalid_value("logic_monitors[0].initial_state", "state is not defined")
+        );
+    }
+
+    #[test]
+    fn parse_logic_undefined_target_state() {
+        assert_eq!(
+            parse_err(
+                r#"{ "logic_monitors": [{ "tag": "m", "initial_state": "a", "states": [
+                    { "tag": "a", "transitions": ["b"] }
+                ] }] }"#
+            ),
+            invalid_value("logic_monitors[0].states[0].transitions[0]", "state is not defined")
+        );
+    }
+
+    #[test]
+    fn parse_logic_incomplete_dwell_time() {
+        assert_eq!(
+            parse_err(
+                r#"{ "logic_monitors": [{ "tag": "m", "initial_state": "a", "states": [{ "tag": "a", "max_ms": 100 }] }] }"#
+            ),
+            ConfigError::MissingField {
+                path: "logic_monitors[0].states[0].min_ms".to_string()
+            }
+        );
+        assert_eq!(
+            parse_err(
+                r#"{ "logic_monitors": [{ "tag": "m", "initial_state": "a", "states": [{ "tag": "a", "min_ms": 200, "max_ms": 100 }] }] }"#
+            ),
+            invalid_value("logic_monitors[0].states[0].max_ms", "must not be smaller than min_ms")
+        );
+    }
+
+    #[test]
+    fn parse_logic_no_states() {
+        assert_eq!(
+            parse_err(r#"{ "logic_monitors": [{ "tag": "m", "initial_state": "a", "states": [] }] }"#),
+            invalid_value("logic_monitors[0].states", "at least one state must be defined")
+        );
+    }
+
+    #[test]
+    fn parse_thread_parameters_invalid_policy() {
+        assert_eq!(
+            parse_err(
+                r#"{ "thread_parameters": { "scheduler_policy": "batch" }, "deadline_monitors": [{ "tag": "m" }] }"#
+            ),
+            invalid_value(
+                "thread_parameters.scheduler_policy",
+                "must be one of 'other', 'fifo' or 'round_robin'"
+            )
+        );
+    }
+
+    #[test]
+    fn parse_thread_parameters_priority_without_policy() {
+        assert_eq!(
+            parse_err(r#"{ "thread_parameters": { "priority": 10 }, "deadline_monitors": [{ "tag": "m" }] }"#),
+            ConfigError::MissingField {
+                path: "thread_parameters.scheduler_policy".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_thread_parameters_priority_out_of_range() {
+        assert_eq!(
+            parse_err(
+                r#"{ "thread_parameters": { "scheduler_policy": "fifo", "priority": 1000 }, "deadline_monitors": [{ "tag": "m" }] }"#
+            ),
+            invalid_value("thread_parameters.priority", "out of range allowed by scheduler_policy")
+        );
+    }
+
+    #[test]
+    fn load_file_succeeds() {
+        let path = std::env::temp_dir().join(format!("hmon_config_{}.json", std::process::id()));
+        std::fs::write(&path, FULL_CONFIG).unwrap();
+        let result = load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn load_file_not_found() {
+        let result = load(std::path::Path::new("/nonexistent/health_monitor.json"));
+        assert!(result.is_err_and(|e| e == ConfigError::Io(std::io::ErrorKind::NotFound)));
+    }
+
+    #[test]
+    fn config_error_display() {
+        let error = invalid_value("heartbeat_monitors[0].min_ms", "must not be empty");
+        assert_eq!(
+            error.to_string(),
+            "invalid value of 'heartbeat_monitors[0].min_ms': must not be empty"
+        );
+
+        let error = rejected("heartbeat_monitors[0]");
+        assert_eq!(
+            error.to_string(),
+            "'heartbeat_monitors[0]' rejected by the builder: InvalidArgument"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::common::{MonitorEvaluator, OnErrorFn};
+use crate::log::ScoreDebug;
+use crate::tag::MonitorTag;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Error reported by a [`CustomMonitor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub struct CustomEvaluationError {
+    code: u8,
+}
+
+impl CustomEvaluationError {
+    /// Create a new error with a code defined by the monitor.
+    pub fn new(code: u8) -> Self {
+        Self { code }
+    }
+
+    /// Error code defined by the monitor.
+    pub fn code(&self) -> u8 {
+        self.code
+    }
+}
+
+impl From<CustomEvaluationError> for u8 {
+    fn from(value: CustomEvaluationError) -> Self {
+        value.code
+    }
+}
+
+/// User-defined monitor, evaluated along with built-in monitors.
+/// Registered using [`crate::HealthMonitorBuilder::add_custom_monitor`].
+///
+/// Errors are handled the same way as errors of built-in monitors:
+/// they are logged, passed to the error handler, aggregated into [`crate::MonitorHealth`]
+/// and reported to the supervisor.
+///
+/// Monitor is owned by the health monitor, state shared with the application can be provided using [`Arc`].
+pub trait CustomMonitor: Send + Sync + 'static {
+    /// Check the monitored condition.
+    /// Called periodically from the monitoring thread, must not block.
+    ///
+    /// - `hmon_starting_point` - starting point of all monitors.
+    fn evaluate(&self, hmon_starting_point: Instant) -> Result<(), CustomEvaluationError>;
+
+    /// Discard errors observed so far and restart monitoring from the current time point.
+    /// Called on [`crate::HealthMonitor::rearm`], does nothing by default.
+    fn rearm(&self) {}
+}
+
+impl<M: CustomMonitor> CustomMonitor for Arc<M> {
+    fn evaluate(&self, hmon_starting_point: Instant) -> Result<(), CustomEvaluationError> {
+        self.as_ref().evaluate(hmon_starting_point)
+    }
+
+    fn rearm(&self) {
+        self.as_ref().rearm()
+    }
+}
+
+/// Evaluator of a [`CustomMonitor`].
+pub(crate) struct CustomMonitorEvaluator<M: CustomMonitor> {
+    monitor_tag: MonitorTag,
+    monitor: M,
+}
+
+impl<M: CustomMonitor> CustomMonitorEvaluator<M> {
+    /// Create a new [`CustomMonitorEvaluator`] instance.
+    pub(crate) fn new(monitor_tag: MonitorTag, monitor: M) -> Self {
+        Self { monitor_tag, monitor }
+    }
+}
+
+impl<M: CustomMonitor> MonitorEvaluator for CustomMonitorEvaluator<M> {
+    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut OnErrorFn<'_>) {
+        if let Err(error) = self.monitor.evaluate(hmon_starting_point) {
+            on_error(&self.monitor_tag, error.into(), None);
+        }
+    }
+
+    fn rearm(&self) {
+        self.monitor.rearm()
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::{MonitorEvaluationError, MonitorEvaluator, MonitorKind};
+    use crate::custom::{CustomEvaluationError, CustomMonitor, CustomMonitorEvaluator};
+    use crate::tag::MonitorTag;
+    use crate::test_common::FlagMonitor;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    fn evaluate_errors<M: CustomMonitor>(evaluator: &CustomMonitorEvaluator<M>) -> Vec<MonitorEvaluationError> {
+        let mut errors = Vec::new();
+        evaluator.evaluate(Instant::now(), &mut |monitor_tag, error, origin| {
+            assert_eq!(*monitor_tag, MonitorTag::from("custom_monitor"));
+            assert!(origin.is_none());
+            errors.push(error);
+        });
+        errors
+    }
+
+    #[test]
+    fn custom_evaluation_error_code() {
+        let error = CustomEvaluationError::new(42);
+        assert_eq!(error.code(), 42);
+        assert_eq!(u8::from(error), 42);
+
+        let error = MonitorEvaluationError::from(error);
+        assert_eq!(error.kind(), MonitorKind::Custom);
+        assert_eq!(error.code(), 42);
+    }
+
+    #[test]
+    fn custom_monitor_evaluator_reports_errors() {
+        let monitor = Arc::new(FlagMonitor {
+            failed: AtomicBool::new(false),
+        });
+        let evaluator = CustomMonitorEvaluator::new(MonitorTag::from("custom_monitor"), monitor.clone());
+        assert!(evaluate_errors(&evaluator).is_empty());
+
+        monitor.failed.store(true, Ordering::Relaxed);
+        assert_eq!(evaluate_errors(&evaluator), vec![CustomEvaluationError::new(7).into()]);
+
+        evaluator.rearm();
+        assert!(evaluate_errors(&evaluator).is_empty());
+    }
+}
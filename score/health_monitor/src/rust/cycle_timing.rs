@@ -0,0 +1,248 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::time::Duration;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Timing statistics of the monitoring cycles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CycleStatistics {
+    /// Number of recorded cycles.
+    pub cycles: u64,
+    /// Shortest evaluation time.
+    pub min_evaluation_time: Duration,
+    /// Longest evaluation time.
+    pub max_evaluation_time: Duration,
+    /// Average evaluation time.
+    pub avg_evaluation_time: Duration,
+    /// Largest delay of a cycle start in relation to its schedule.
+    pub max_jitter: Duration,
+    /// Number of cycles not finished before the next cycle was scheduled.
+    pub overruns: u64,
+    /// Number of cycles skipped due to overruns.
+    pub missed_cycles: u64,
+}
+
+/// Result of a recorded cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct CycleRecord {
+    /// Time point at which the next cycle is scheduled.
+    pub next_cycle: Instant,
+    /// Number of cycles skipped due to overrun, zero if cycle finished on time.
+    pub missed_cycles: u64,
+    /// Number of consecutive overruns reached the configured limit.
+    pub overrun_limit_exceeded: bool,
+}
+
+struct CycleTimingState {
+    statistics: CycleStatistics,
+    total_evaluation_time: Duration,
+    consecutive_overruns: u32,
+}
+
+/// Cycle timing measurement, shared between the monitoring logic and the [`crate::HealthMonitor`].
+pub(crate) struct CycleTiming {
+    interval: Duration,
+    overrun_limit: Option<u32>,
+    state: Mutex<CycleTimingState>,
+}
+
+impl CycleTiming {
+    /// Create a new [`CycleTiming`].
+    ///
+    /// - `interval` - expected interval between cycles.
+    /// - `overrun_limit` - number of consecutive overruns considered a failure, [`None`] if overruns are only reported.
+    pub(crate) fn new(interval: Duration, overrun_limit: Option<u32>) -> Self {
+        Self {
+            interval,
+            overrun_limit,
+            state: Mutex::new(CycleTimingState {
+                statistics: CycleStatistics::default(),
+                total_evaluation_time: Duration::ZERO,
+                consecutive_overruns: 0,
+            }),
+        }
+    }
+
+    /// Record a cycle.
+    ///
+    /// - `scheduled` - time point at which the cycle was scheduled to start.
+    /// - `started` - time point at which the cycle actually started.
+    /// - `evaluation_time` - duration of the cycle evaluation.
+    ///
+    /// Cycles which would start before this cycle is finished are skipped.
+    pub(crate) fn record(&self, scheduled: Instant, started: Instant, evaluation_time: Duration) -> CycleRecord {
+        let jitter = started.saturating_duration_since(scheduled);
+        let elapsed = jitter + evaluation_time;
+
+        // Number of cycle start points passed during this cycle.
+        let missed_cycles = if self.interval.is_zero() {
+            0
+        } else {
+            (elapsed.as_nanos() / self.interval.as_nanos()) as u64
+        };
+        let cycles_to_next = u32::try_from(missed_cycles + 1).unwrap_or(u32::MAX);
+        let next_cycle = scheduled + self.interval.saturating_mul(cycles_to_next);
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let statistics = &mut state.statistics;
+        statistics.min_evaluation_time = if statistics.cycles == 0 {
+            evaluation_time
+        } else {
+            statistics.min_evaluation_time.min(evaluation_time)
+        };
+        statistics.max_evaluation_time = statistics.max_evaluation_time.max(evaluation_time);
+        statistics.max_jitter = statistics.max_jitter.max(jitter);
+        statistics.cycles += 1;
+        state.total_evaluation_time += evaluation_time;
+        let avg_evaluation_time_ns = state.total_evaluation_time.as_nanos() / u128::from(state.statistics.cycles);
+        state.statistics.avg_evaluation_time = Duration::from_nanos(avg_evaluation_time_ns as u64);
+
+        if missed_cycles > 0 {
+            state.statistics.overruns += 1;
+            state.statistics.missed_cycles += missed_cycles;
+            state.consecutive_overruns = state.consecutive_overruns.saturating_add(1);
+        } else {
+            state.consecutive_overruns = 0;
+        }
+
+        let overrun_limit_exceeded = self
+            .overrun_limit
+            .is_some_and(|limit| state.consecutive_overruns >= limit);
+
+        CycleRecord {
+            next_cycle,
+            missed_cycles,
+            overrun_limit_exceeded,
+        }
+    }
+
+    /// Current statistics.
+    pub(crate) fn statistics(&self) -> CycleStatistics {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).statistics
+    }
+
+    /// Reset number of consecutive overruns, e.g., on re-arm.
+    pub(crate) fn reset_overruns(&self) {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .consecutive_overruns = 0;
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::cycle_timing::{CycleStatistics, CycleTiming};
+    use core::time::Duration;
+    use std::time::Instant;
+
+    const INTERVAL: Duration = Duration::from_millis(10);
+
+    fn ms(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn cycle_timing_new_empty_statistics() {
+        let timing = CycleTiming::new(INTERVAL, None);
+        assert_eq!(timing.statistics(), CycleStatistics::default());
+    }
+
+    #[test]
+    fn cycle_timing_record_on_time() {
+        let timing = CycleTiming::new(INTERVAL, None);
+        let scheduled = Instant::now();
+
+        let record = timing.record(scheduled, scheduled + ms(1), ms(2));
+        assert_eq!(record.next_cycle, scheduled + INTERVAL);
+        assert_eq!(record.missed_cycles, 0);
+        assert!(!record.overrun_limit_exceeded);
+
+        let statistics = timing.statistics();
+        assert_eq!(statistics.cycles, 1);
+        assert_eq!(statistics.min_evaluation_time, ms(2));
+        assert_eq!(statistics.max_evaluation_time, ms(2));
+        assert_eq!(statistics.avg_evaluation_time, ms(2));
+        assert_eq!(statistics.max_jitter, ms(1));
+        assert_eq!(statistics.overruns, 0);
+        assert_eq!(statistics.missed_cycles, 0);
+    }
+
+    #[test]
+    fn cycle_timing_record_overrun() {
+        let timing = CycleTiming::new(INTERVAL, None);
+        let scheduled = Instant::now();
+
+        // Next two cycle start points passed.
+        let record = timing.record(scheduled, scheduled + ms(2), ms(21));
+        assert_eq!(record.next_cycle, scheduled + 3 * INTERVAL);
+        assert_eq!(record.missed_cycles, 2);
+        assert!(!record.overrun_limit_exceeded);
+
+        let statistics = timing.statistics();
+        assert_eq!(statistics.overruns, 1);
+        assert_eq!(statistics.missed_cycles, 2);
+    }
+
+    #[test]
+    fn cycle_timing_record_started_in_past() {
+        let timing = CycleTiming::new(INTERVAL, None);
+        let started = Instant::now();
+
+        // Cycle started before its schedule - no jitter, no underflow.
+        let record = timing.record(started + ms(5), started, ms(1));
+        assert_eq!(record.missed_cycles, 0);
+        assert_eq!(timing.statistics().max_jitter, Duration::ZERO);
+    }
+
+    #[test]
+    fn cycle_timing_statistics_aggregated() {
+        let timing = CycleTiming::new(INTERVAL, None);
+        let mut scheduled = Instant::now();
+        for evaluation_time in [ms(3), ms(1), ms(5)] {
+            scheduled = timing.record(scheduled, scheduled, evaluation_time).next_cycle;
+        }
+
+        let statistics = timing.statistics();
+        assert_eq!(statistics.cycles, 3);
+        assert_eq!(statistics.min_evaluation_time, ms(1));
+        assert_eq!(statistics.max_evaluation_time, ms(5));
+        assert_eq!(statistics.avg_evaluation_time, ms(3));
+    }
+
+    #[test]
+    fn cycle_timing_overrun_limit() {
+        let timing = CycleTiming::new(INTERVAL, Some(2));
+        let scheduled = Instant::now();
+
+        assert!(!timing.record(scheduled, scheduled, ms(15)).overrun_limit_exceeded);
+        // Cycle on time resets consecutive overruns.
+        assert!(!timing.record(scheduled, scheduled, ms(1)).overrun_limit_exceeded);
+        assert!(!timing.record(scheduled, scheduled, ms(15)).overrun_limit_exceeded);
+        assert!(timing.record(scheduled, scheduled, ms(15)).overrun_limit_exceeded);
+
+        timing.reset_overruns();
+        assert!(!timing.record(scheduled, scheduled, ms(15)).overrun_limit_exceeded);
+    }
+
+    #[test]
+    fn cycle_timing_zero_interval() {
+        let timing = CycleTiming::new(Duration::ZERO, Some(1));
+        let scheduled = Instant::now();
+        let record = timing.record(scheduled, scheduled, ms(1));
+        assert_eq!(record.next_cycle, scheduled);
+        assert!(!record.overrun_limit_exceeded);
+    }
+}
@@ -32,39 +32,44 @@ impl Deref for StateIndex {
     }
 }
 
-/// Template for a deadline, managing its range and usage state.
+/// Template for a deadline, managing its range and usage state of its instances.
+/// Each instance has a state assigned, at consecutive indexes starting at `first_state_index`.
 pub(super) struct DeadlineTemplate {
     range: TimeRange,
-    is_in_use: AtomicBool,
-    pub assigned_state_index: StateIndex,
+    instances_in_use: Box<[AtomicBool]>,
+    first_state_index: StateIndex,
 }
 
 impl DeadlineTemplate {
-    pub(super) fn new(range: TimeRange, state_index: StateIndex) -> Self {
+    pub(super) fn new(range: TimeRange, first_state_index: StateIndex, max_instances: usize) -> Self {
         Self {
             range,
-            is_in_use: AtomicBool::new(false),
-            assigned_state_index: state_index,
+            instances_in_use: (0..max_instances).map(|_| AtomicBool::new(false)).collect(),
+            first_state_index,
         }
     }
 
-    /// Attempts to acquire the deadline for use.
-    /// Returns Some(TimeRange) if successful, None if already in use.
-    pub(super) fn acquire_deadline(&self) -> Option<TimeRange> {
-        if self
-            .is_in_use
-            .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
-            .is_ok()
-        {
-            Some(self.range)
-        } else {
-            None
-        }
+    /// Number of instances that can be used concurrently.
+    pub(super) fn max_instances(&self) -> usize {
+        self.instances_in_use.len()
+    }
+
+    /// Attempts to acquire a deadline instance for use.
+    /// Returns Some((TimeRange, StateIndex)) with a state assigned to the instance if successful, None if all instances are in use.
+    pub(super) fn acquire_deadline(&self) -> Option<(TimeRange, StateIndex)> {
+        self.instances_in_use
+            .iter()
+            .position(|in_use| {
+                in_use
+                    .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
+                    .is_ok()
+            })
+            .map(|instance| (self.range, StateIndex::new(*self.first_state_index + instance)))
     }
 
-    /// Releases the deadline, marking it as not in use.
-    pub(super) fn release_deadline(&self) {
-        self.is_in_use.store(false, Ordering::Relaxed);
+    /// Releases the deadline instance with the given state assigned, marking it as not in use.
+    pub(super) fn release_deadline(&self, state_index: StateIndex) {
+        self.instances_in_use[*state_index - *self.first_state_index].store(false, Ordering::Relaxed);
     }
 }
 
@@ -80,27 +85,45 @@ mod tests {
     fn new_and_fields() {
         let range = TimeRange::new(Duration::from_secs(1), Duration::from_secs(2));
         let idx = StateIndex::new(7);
-        let tmpl = DeadlineTemplate::new(range, idx);
+        let tmpl = DeadlineTemplate::new(range, idx, 1);
         assert_eq!(tmpl.range.min, Duration::from_secs(1));
         assert_eq!(tmpl.range.max, Duration::from_secs(2));
-        assert_eq!(*tmpl.assigned_state_index, 7);
-        assert!(!tmpl.is_in_use.load(Ordering::Relaxed));
+        assert_eq!(*tmpl.first_state_index, 7);
+        assert_eq!(tmpl.max_instances(), 1);
+        assert!(!tmpl.instances_in_use[0].load(Ordering::Relaxed));
     }
 
     #[test]
     fn acquire_and_release_deadline() {
         let range = TimeRange::new(Duration::from_secs(3), Duration::from_secs(4));
         let idx = StateIndex::new(0);
-        let tmpl = Arc::new(DeadlineTemplate::new(range, idx));
+        let tmpl = Arc::new(DeadlineTemplate::new(range, idx, 1));
 
         // First acquire should succeed
-        assert_eq!(tmpl.acquire_deadline(), Some(range));
+        assert_eq!(tmpl.acquire_deadline(), Some((range, idx)));
         // Second acquire should fail
         assert_eq!(tmpl.acquire_deadline(), None);
 
         // Release and acquire again
-        tmpl.release_deadline();
-        assert_eq!(tmpl.acquire_deadline(), Some(range));
+        tmpl.release_deadline(idx);
+        assert_eq!(tmpl.acquire_deadline(), Some((range, idx)));
+    }
+
+    #[test]
+    fn acquire_and_release_multiple_instances() {
+        let range = TimeRange::new(Duration::from_secs(3), Duration::from_secs(4));
+        let tmpl = DeadlineTemplate::new(range, StateIndex::new(2), 3);
+
+        // Each instance gets its own state.
+        assert_eq!(tmpl.acquire_deadline(), Some((range, StateIndex::new(2))));
+        assert_eq!(tmpl.acquire_deadline(), Some((range, StateIndex::new(3))));
+        assert_eq!(tmpl.acquire_deadline(), Some((range, StateIndex::new(4))));
+        assert_eq!(tmpl.acquire_deadline(), None);
+
+        // Released state is reused.
+        tmpl.release_deadline(StateIndex::new(3));
+        assert_eq!(tmpl.acquire_deadline(), Some((range, StateIndex::new(3))));
+        assert_eq!(tmpl.acquire_deadline(), None);
     }
 
     #[test]
@@ -108,7 +131,7 @@ mod tests {
         use std::thread;
         let range = TimeRange::new(Duration::from_secs(5), Duration::from_secs(6));
         let idx = StateIndex::new(1);
-        let tmpl = Arc::new(DeadlineTemplate::new(range, idx));
+        let tmpl = Arc::new(DeadlineTemplate::new(range, idx, 1));
 
         let tmpl1 = tmpl.clone();
         let tmpl2 = tmpl.clone();
@@ -119,4 +142,27 @@ mod tests {
         // Only one thread should succeed
         assert!(matches!((r1, r2), (Some(_), None) | (None, Some(_))));
     }
+
+    #[test]
+    fn concurrent_acquire_multiple_instances() {
+        use std::thread;
+        const MAX_INSTANCES: usize = 4;
+        let range = TimeRange::new(Duration::from_secs(5), Duration::from_secs(6));
+        let tmpl = Arc::new(DeadlineTemplate::new(range, StateIndex::new(0), MAX_INSTANCES));
+
+        let handles: Vec<_> = (0..MAX_INSTANCES * 2)
+            .map(|_| {
+                let tmpl = tmpl.clone();
+                thread::spawn(move || tmpl.acquire_deadline())
+            })
+            .collect();
+        let mut acquired: Vec<_> = handles
+            .into_iter()
+            .filter_map(|h| h.join().unwrap())
+            .map(|(_, state_index)| *state_index)
+            .collect();
+        acquired.sort();
+        // Each state is assigned to a single instance only.
+        assert_eq!(acquired, (0..MAX_INSTANCES).collect::<Vec<_>>());
+    }
 }
@@ -10,24 +10,38 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
-use crate::common::{duration_to_int, Monitor, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator, TimeRange};
+use crate::clock::Clock;
+use crate::common::{Monitor, MonitorEvalHandle, MonitorEvaluator, OnErrorFn, TimeRange};
 use crate::deadline::common::{DeadlineTemplate, StateIndex};
 use crate::deadline::deadline_state::{DeadlineState, DeadlineStateSnapshot};
-use crate::log::{error, warn, ScoreDebug};
-use crate::protected_memory::ProtectedMemoryAllocator;
+use crate::deadline::supervised_future::SupervisedFuture;
+use crate::health_monitor::HealthMonitorError;
+use crate::log::{debug, error, warn, ScoreDebug};
+use crate::protected_memory::{ProtectedMemoryAllocator, ProtectedSlice};
+use crate::status::FailureOrigin;
 use crate::tag::{DeadlineTag, MonitorTag};
+use core::future::Future;
 use core::hash::Hash;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
 /// Deadline evaluation errors.
+#[repr(u8)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ScoreDebug)]
-pub(crate) enum DeadlineEvaluationError {
+pub enum DeadlineEvaluationError {
     /// Finished too early.
-    TooEarly,
+    TooEarly = 1,
     /// Finished too late.
     TooLate,
+    /// Deadline states memory is corrupted.
+    MemoryCorrupted,
+}
+
+impl From<DeadlineEvaluationError> for u8 {
+    fn from(value: DeadlineEvaluationError) -> Self {
+        value as u8
+    }
 }
 
 ///
@@ -38,6 +52,9 @@ pub enum DeadlineMonitorError {
     /// The requested deadline is already in use (someone keeps the Deadline instance)
     DeadlineInUse,
 
+    /// All instances of the requested concurrent deadline are in use
+    DeadlinePoolExhausted,
+
     /// The requested deadline was not registered during builder phase
     DeadlineNotFound,
 }
@@ -52,7 +69,8 @@ pub enum DeadlineError {
 /// Builder for [`DeadlineMonitor`].
 #[derive(Debug, Default)]
 pub struct DeadlineMonitorBuilder {
-    deadlines: HashMap<DeadlineTag, TimeRange>,
+    /// Range and maximum number of concurrent instances of each deadline.
+    deadlines: HashMap<DeadlineTag, (TimeRange, usize)>,
 }
 
 impl DeadlineMonitorBuilder {
@@ -69,16 +87,70 @@ impl DeadlineMonitorBuilder {
         self
     }
 
+    /// Adds a deadline that can be used by multiple owners at the same time, e.g., by threads of a pool.
+    ///
+    /// - `deadline_tag` - unique tag of the deadline.
+    /// - `range` - duration range of each instance.
+    /// - `max_instances` - maximum number of instances used concurrently, must be greater than zero.
+    ///
+    /// Each instance is tracked and evaluated separately.
+    /// [`DeadlineMonitor::get_deadline`] returns [`DeadlineMonitorError::DeadlinePoolExhausted`] once all instances are in use.
+    pub fn add_concurrent_deadline(
+        mut self,
+        deadline_tag: DeadlineTag,
+        range: TimeRange,
+        max_instances: usize,
+    ) -> Self {
+        self.add_concurrent_deadline_internal(deadline_tag, range, max_instances);
+        self
+    }
+
+    /// Check every deadline allows at least one instance.
+    ///
+    /// - `monitor_tag` - tag of this monitor.
+    pub(crate) fn validate(&self, monitor_tag: MonitorTag) -> Result<(), HealthMonitorError> {
+        for (deadline_tag, (_, max_instances)) in self.deadlines.iter() {
+            if *max_instances == 0 {
+                error!(
+                    "Deadline {:?} of monitor {:?} must allow at least one instance.",
+                    deadline_tag, monitor_tag
+                );
+                return Err(HealthMonitorError::InvalidArgument);
+            }
+        }
+        Ok(())
+    }
+
     /// Builds the DeadlineMonitor with the configured deadlines.
-    pub(crate) fn build(self, monitor_tag: MonitorTag, _allocator: &ProtectedMemoryAllocator) -> DeadlineMonitor {
-        let inner = Arc::new(DeadlineMonitorInner::new(monitor_tag, self.deadlines));
-        DeadlineMonitor::new(inner)
+    ///
+    /// - `monitor_tag` - tag of this monitor.
+    /// - `clock` - clock shared by all monitors.
+    /// - `allocator` - protected memory allocator.
+    pub(crate) fn build(
+        self,
+        monitor_tag: MonitorTag,
+        clock: Clock,
+        allocator: &ProtectedMemoryAllocator,
+    ) -> Result<DeadlineMonitor, HealthMonitorError> {
+        self.validate(monitor_tag)?;
+
+        let inner = Arc::new(DeadlineMonitorInner::new(monitor_tag, clock, self.deadlines, allocator));
+        Ok(DeadlineMonitor::new(inner))
     }
 
     // Used by FFI and config parsing code which prefer not to move builder instance
 
     pub(super) fn add_deadline_internal(&mut self, deadline_tag: DeadlineTag, range: TimeRange) {
-        self.deadlines.insert(deadline_tag, range);
+        self.add_concurrent_deadline_internal(deadline_tag, range, 1);
+    }
+
+    pub(super) fn add_concurrent_deadline_internal(
+        &mut self,
+        deadline_tag: DeadlineTag,
+        range: TimeRange,
+        max_instances: usize,
+    ) {
+        self.deadlines.insert(deadline_tag, (range, max_instances));
     }
 }
 
@@ -97,6 +169,7 @@ impl DeadlineMonitor {
     /// # Returns
     ///  - Ok(Deadline) - if the deadline was acquired successfully.
     ///  - Err(DeadlineMonitorError::DeadlineInUse) - if the deadline is already in use
+    ///  - Err(DeadlineMonitorError::DeadlinePoolExhausted) - if all instances of a concurrent deadline are in use
     ///  - Err(DeadlineMonitorError::DeadlineNotFound) - if the deadline tag is not registered
     pub fn get_deadline(&self, deadline_tag: DeadlineTag) -> Result<Deadline, DeadlineMonitorError> {
         self.inner.get_deadline(deadline_tag)
@@ -133,6 +206,38 @@ impl Drop for DeadlineHandle<'_> {
     }
 }
 
+/// A handle representing a started deadline that owns its [`Deadline`]. When dropped, it stops the deadline.
+///
+/// Unlike [`DeadlineHandle`], it can be moved to another thread or stored in a struct,
+/// e.g., to start a deadline when a request is sent and stop it in a response callback.
+pub struct OwnedDeadlineHandle(Option<Deadline>);
+
+impl OwnedDeadlineHandle {
+    /// Stops the deadline and returns it, so it can be started again.
+    pub fn stop(mut self) -> Deadline {
+        let mut deadline = self
+            .0
+            .take()
+            .expect("deadline is present until handle is stopped or dropped");
+        deadline.stop_internal();
+        deadline
+    }
+}
+
+impl Drop for OwnedDeadlineHandle {
+    fn drop(&mut self) {
+        if let Some(deadline) = self.0.as_mut() {
+            deadline.stop_internal();
+        }
+    }
+}
+
+impl core::fmt::Debug for OwnedDeadlineHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OwnedDeadlineHandle").field(&self.0).finish()
+    }
+}
+
 impl Deadline {
     ///
     /// Starts the deadline - it will be monitored by health monitoring system.
@@ -146,6 +251,29 @@ impl Deadline {
         unsafe { self.start_internal().map(|_| DeadlineHandle(self)) }
     }
 
+    ///
+    /// Starts the deadline and moves it into the returned handle - it will be monitored by health monitoring system.
+    /// The deadline is stopped when the handle is stopped or dropped, possibly on another thread.
+    ///
+    /// # Returns
+    ///  - Ok(OwnedDeadlineHandle) - if the deadline was started successfully.
+    ///  - Err(DeadlineError::DeadlineAlreadyFailed) - if the deadline was already missed before,
+    ///    the deadline instance is released in that case.
+    ///
+    pub fn start_owned(mut self) -> Result<OwnedDeadlineHandle, DeadlineError> {
+        // Safety: the handle takes the ownership of the deadline, so it cannot be started again until it's stopped.
+        unsafe { self.start_internal().map(|_| OwnedDeadlineHandle(Some(self))) }
+    }
+
+    /// Supervises the given future with this deadline.
+    ///
+    /// Deadline is started on the first poll of the returned future and stopped when `future` completes.
+    /// Dropping the returned future before completion cancels the deadline, refer to [`SupervisedFuture`].
+    /// No specific async runtime is required.
+    pub fn supervise<F: Future>(self, future: F) -> SupervisedFuture<F> {
+        SupervisedFuture::new(self, future)
+    }
+
     /// Starts the deadline - it will be monitored by health monitoring system.
     /// This function is for FFI usage only!
     ///
@@ -154,8 +282,18 @@ impl Deadline {
     /// Caller must ensure that deadline is not used until it's stopped.
     /// After this call You shall assure there's only a single owner of the `Deadline` instance and it does not call start before stopping.
     pub(super) unsafe fn start_internal(&mut self) -> Result<(), DeadlineError> {
-        let now = duration_to_int::<u32>(self.monitor.monitor_starting_point.elapsed());
-        let max_time = now + self.range.max.as_millis() as u32;
+        let now = self.monitor.clock.now();
+        // SAFETY: the caller upholds the safety contract.
+        unsafe { self.start_at(now) }
+    }
+
+    /// Starts the deadline at provided time point in clock ticks.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::start_internal`].
+    unsafe fn start_at(&mut self, now: u64) -> Result<(), DeadlineError> {
+        let max_time = now.saturating_add(self.monitor.clock.ticks(self.range.max));
 
         let mut is_broken = false;
         let _ = self.monitor.active_deadlines[*self.state_index].1.update(|current| {
@@ -165,7 +303,7 @@ impl Deadline {
             }
 
             let mut new = DeadlineStateSnapshot::default();
-            new.set_timestamp_ms(max_time);
+            new.set_timestamp(max_time);
             new.set_running();
             Some(new)
         });
@@ -179,29 +317,54 @@ impl Deadline {
     }
 
     pub(super) fn stop_internal(&mut self) {
-        let now = duration_to_int::<u32>(self.monitor.monitor_starting_point.elapsed());
-        let max = self.range.max.as_millis() as u32;
-        let min = self.range.min.as_millis() as u32;
+        let now = self.monitor.clock.now();
+        self.stop_at(now);
+    }
+
+    /// Cancels the running deadline.
+    /// Deadline is stopped without checking minimum duration, missed deadline is left for reporting by BG thread.
+    pub(super) fn cancel_internal(&mut self) {
+        let now = self.monitor.clock.now();
+        self.cancel_at(now);
+    }
+
+    /// Cancels the running deadline at provided time point in clock ticks.
+    fn cancel_at(&mut self, now: u64) {
+        let _ = self.monitor.active_deadlines[*self.state_index].1.update(|current| {
+            if !current.is_running() || current.timestamp() < now {
+                return None; // Not running or already missed, let state as is
+            }
+
+            debug!("Deadline({:?}) cancelled", self.deadline_tag);
+            Some(DeadlineStateSnapshot::default())
+        });
+    }
+
+    /// Stops the deadline at provided time point in clock ticks.
+    fn stop_at(&mut self, now: u64) {
+        let clock = &self.monitor.clock;
+        let max = clock.ticks(self.range.max);
+        let min = clock.ticks(self.range.min);
 
         let mut possible_err = (None, 0);
 
         let _ = self.monitor.active_deadlines[*self.state_index]
             .1
             .update(|mut current| {
-                debug_assert!(
-                    current.is_running(),
-                    "Deadline({:?}) is not running when trying to stop",
-                    self.deadline_tag
-                );
+                // Failed deadline might be re-armed before it is stopped.
+                if !current.is_running() {
+                    debug!("Deadline({:?}) is not running when trying to stop", self.deadline_tag);
+                    return None;
+                }
 
-                let expected = current.timestamp_ms();
+                let expected = current.timestamp();
                 if expected < now {
                     possible_err = (Some(DeadlineEvaluationError::TooLate), now - expected);
                     return None; // Deadline missed, let state as is for BG thread to report
                 }
 
-                let start_time = expected - max;
-                let earliest_time = start_time + min;
+                let start_time = expected.saturating_sub(max);
+                let earliest_time = start_time.saturating_add(min);
 
                 if now < earliest_time {
                     // Finished too early, leave it for reporting by BG thread
@@ -216,12 +379,21 @@ impl Deadline {
 
         match possible_err {
             (Some(DeadlineEvaluationError::TooEarly), val) => {
-                error!("Deadline {:?} stopped too early by {} ms", self.deadline_tag, val);
+                error!(
+                    "Deadline {:?} stopped too early by {} us",
+                    self.deadline_tag,
+                    clock.duration(val).as_micros() as u64
+                );
             },
             (Some(DeadlineEvaluationError::TooLate), val) => {
-                error!("Deadline {:?} stopped too late by {} ms", self.deadline_tag, val);
+                error!(
+                    "Deadline {:?} stopped too late by {} us",
+                    self.deadline_tag,
+                    clock.duration(val).as_micros() as u64
+                );
             },
-            (None, _) => {},
+            // Memory corruption is only detected during evaluation.
+            (Some(DeadlineEvaluationError::MemoryCorrupted), _) | (None, _) => {},
         }
     }
 
@@ -240,7 +412,7 @@ impl core::fmt::Debug for Deadline {
 
 impl Drop for Deadline {
     fn drop(&mut self) {
-        self.monitor.release_deadline(self.deadline_tag);
+        self.monitor.release_deadline(self.deadline_tag, self.state_index);
     }
 }
 
@@ -248,20 +420,67 @@ struct DeadlineMonitorInner {
     /// Tag of this monitor.
     monitor_tag: MonitorTag,
 
-    /// Monitor starting point.
-    monitor_starting_point: Instant,
+    /// Clock shared by all monitors, deadline timestamps are stored in its ticks.
+    clock: Clock,
 
     // Templates for deadlines registered in the monitor to create `Deadline` instances.
     deadlines: HashMap<DeadlineTag, DeadlineTemplate>,
 
-    // This is shared state. Each deadline template has assigned index into this array.
+    // This is shared state. Each deadline template has assigned a range of indexes into this array, one per instance.
     // Each deadline instance updates its state (under given index) and the deadline pointing to a state is Single-Producer
     // On the other side there is background thread evaluating all deadlines states - this is Single-Consumer for each given state.
-    active_deadlines: Arc<[(DeadlineTag, DeadlineState)]>,
+    // States are placed in protected memory, integrity is checked on each evaluation.
+    active_deadlines: ProtectedSlice<(DeadlineTag, DeadlineState)>,
 }
 
 impl MonitorEvaluator for DeadlineMonitorInner {
-    fn evaluate(&self, _hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError)) {
+    fn evaluate(&self, _hmon_starting_point: Instant, on_error: &mut OnErrorFn<'_>) {
+        self.evaluate_at(self.clock.now(), on_error);
+    }
+
+    fn rearm(&self) {
+        self.rearm_at(self.clock.now());
+    }
+}
+
+impl DeadlineMonitorInner {
+    fn new(
+        monitor_tag: MonitorTag,
+        clock: Clock,
+        deadlines: HashMap<DeadlineTag, (TimeRange, usize)>,
+        allocator: &ProtectedMemoryAllocator,
+    ) -> Self {
+        let mut active_deadlines = vec![];
+
+        let deadlines = deadlines
+            .into_iter()
+            .map(|(deadline_tag, (range, max_instances))| {
+                let first_state_index = StateIndex::new(active_deadlines.len());
+                active_deadlines.extend((0..max_instances).map(|_| (deadline_tag, DeadlineState::new())));
+                (
+                    deadline_tag,
+                    DeadlineTemplate::new(range, first_state_index, max_instances),
+                )
+            })
+            .collect();
+
+        Self {
+            monitor_tag,
+            clock,
+            deadlines,
+            active_deadlines: allocator.allocate_slice(active_deadlines),
+        }
+    }
+
+    /// Evaluate deadlines at provided time point in clock ticks.
+    fn evaluate_at(&self, now: u64, on_error: &mut OnErrorFn<'_>) {
+        // Deadline states cannot be trusted if memory is corrupted.
+        if !self.active_deadlines.is_intact() {
+            error!("Deadline monitor ({:?}) states memory is corrupted!", self.monitor_tag);
+            on_error(&self.monitor_tag, DeadlineEvaluationError::MemoryCorrupted.into(), None);
+            return;
+        }
+
         for (deadline_tag, deadline) in self.active_deadlines.iter() {
             let snapshot = deadline.snapshot();
             if snapshot.is_underrun() {
@@ -269,15 +488,18 @@ impl MonitorEvaluator for DeadlineMonitorInner {
                 warn!("Deadline ({:?}) finished too early!", deadline_tag);
 
                 // Here we would normally report the underrun to the monitoring system
-                on_error(&self.monitor_tag, DeadlineEvaluationError::TooEarly.into());
+                on_error(
+                    &self.monitor_tag,
+                    DeadlineEvaluationError::TooEarly.into(),
+                    Some(FailureOrigin::Deadline(*deadline_tag)),
+                );
             } else if snapshot.is_running() {
                 debug_assert!(
                     snapshot.is_stopped(),
                     "Deadline snapshot cannot be both running and stopped"
                 );
 
-                let now = duration_to_int::<u32>(self.monitor_starting_point.elapsed());
-                let expected = snapshot.timestamp_ms();
+                let expected = snapshot.timestamp();
                 if now > expected {
                     // Deadline missed, report
                     warn!(
@@ -286,37 +508,35 @@ impl MonitorEvaluator for DeadlineMonitorInner {
                     );
 
                     // Here we would normally report the missed deadline to the monitoring system
-                    on_error(&self.monitor_tag, DeadlineEvaluationError::TooLate.into());
+                    on_error(
+                        &self.monitor_tag,
+                        DeadlineEvaluationError::TooLate.into(),
+                        Some(FailureOrigin::Deadline(*deadline_tag)),
+                    );
                 }
             }
         }
     }
-}
-
-impl DeadlineMonitorInner {
-    fn new(monitor_tag: MonitorTag, deadlines: HashMap<DeadlineTag, TimeRange>) -> Self {
-        let mut active_deadlines = vec![];
-
-        let deadlines = deadlines
-            .into_iter()
-            .enumerate()
-            .map(|(index, (deadline_tag, range))| {
-                active_deadlines.push((deadline_tag, DeadlineState::new()));
-                (deadline_tag, DeadlineTemplate::new(range, StateIndex::new(index)))
-            })
-            .collect();
 
-        Self {
-            monitor_tag,
-            deadlines,
-            active_deadlines: active_deadlines.into(),
-            monitor_starting_point: Instant::now(),
+    /// Re-arm deadlines at provided time point in clock ticks.
+    fn rearm_at(&self, now: u64) {
+        for (deadline_tag, deadline) in self.active_deadlines.iter() {
+            let _ = deadline.update(|current| {
+                // Reset failed deadlines, running deadlines within the range are kept.
+                let is_missed = current.is_running() && now > current.timestamp();
+                if current.is_underrun() || is_missed {
+                    debug!("Deadline ({:?}) re-armed", deadline_tag);
+                    Some(DeadlineStateSnapshot::default())
+                } else {
+                    None
+                }
+            });
         }
     }
 
-    fn release_deadline(&self, deadline_tag: DeadlineTag) {
+    fn release_deadline(&self, deadline_tag: DeadlineTag, state_index: StateIndex) {
         if let Some(template) = self.deadlines.get(&deadline_tag) {
-            template.release_deadline();
+            template.release_deadline(state_index);
         } else {
             unreachable!("Releasing unknown deadline tag: {:?}", deadline_tag);
         }
@@ -325,12 +545,13 @@ impl DeadlineMonitorInner {
     pub(crate) fn get_deadline(self: &Arc<Self>, deadline_tag: DeadlineTag) -> Result<Deadline, DeadlineMonitorError> {
         if let Some(template) = self.deadlines.get(&deadline_tag) {
             match template.acquire_deadline() {
-                Some(range) => Ok(Deadline {
+                Some((range, state_index)) => Ok(Deadline {
                     range,
                     deadline_tag,
                     monitor: self.clone(),
-                    state_index: template.assigned_state_index,
+                    state_index,
                 }),
+                None if template.max_instances() > 1 => Err(DeadlineMonitorError::DeadlinePoolExhausted),
                 None => Err(DeadlineMonitorError::DeadlineInUse),
             }
         } else {
@@ -343,78 +564,82 @@ impl DeadlineMonitorInner {
 #[cfg(all(test, not(loom)))]
 mod tests {
     use super::*;
+    use crate::clock::{VirtualClock, DEFAULT_CLOCK_RESOLUTION};
+    use crate::common::MonitorEvaluationError;
+    use crate::test_common::YieldOnce;
+    use core::task::{Context, Poll, Waker};
+    use core::time::Duration;
+
+    fn virtual_clock(resolution: Duration) -> (Clock, VirtualClock) {
+        let time_source = VirtualClock::new();
+        (Clock::new(resolution, Arc::new(time_source.clone())), time_source)
+    }
 
-    fn create_monitor_with_deadlines() -> DeadlineMonitor {
-        let allocator = ProtectedMemoryAllocator {};
+    fn create_monitor_with_deadlines() -> (DeadlineMonitor, VirtualClock) {
+        let (clock, time_source) = virtual_clock(DEFAULT_CLOCK_RESOLUTION);
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("deadline_monitor");
-        DeadlineMonitorBuilder::new()
+        let monitor = DeadlineMonitorBuilder::new()
             .add_deadline(
                 DeadlineTag::from("deadline_long"),
-                TimeRange::new(core::time::Duration::from_secs(1), core::time::Duration::from_secs(50)),
+                TimeRange::new(Duration::from_secs(1), Duration::from_secs(50)),
             )
             .add_deadline(
                 DeadlineTag::from("deadline_fast"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(50),
-                ),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(50)),
             )
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, clock, &allocator)
+            .unwrap();
+        (monitor, time_source)
     }
 
-    fn create_monitor_with_multiple_running_deadlines() -> DeadlineMonitor {
-        let allocator = ProtectedMemoryAllocator {};
+    fn create_monitor_with_multiple_running_deadlines() -> (DeadlineMonitor, VirtualClock) {
+        let (clock, time_source) = virtual_clock(DEFAULT_CLOCK_RESOLUTION);
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("deadline_monitor");
-        DeadlineMonitorBuilder::new()
+        let monitor = DeadlineMonitorBuilder::new()
             .add_deadline(
                 DeadlineTag::from("slow"),
-                TimeRange::new(core::time::Duration::from_secs(0), core::time::Duration::from_secs(50)),
+                TimeRange::new(Duration::from_secs(0), Duration::from_secs(50)),
             )
             .add_deadline(
                 DeadlineTag::from("deadline_fast1"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(50),
-                ),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(50)),
             )
             .add_deadline(
                 DeadlineTag::from("deadline_fast2"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(34),
-                ),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(34)),
             )
             .add_deadline(
                 DeadlineTag::from("deadline_fast3"),
-                TimeRange::new(
-                    core::time::Duration::from_millis(0),
-                    core::time::Duration::from_millis(10),
-                ),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(10)),
             )
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, clock, &allocator)
+            .unwrap();
+        (monitor, time_source)
     }
 
     #[test]
     fn get_deadline_unknown_tag() {
-        let monitor = create_monitor_with_deadlines();
+        let (monitor, _) = create_monitor_with_deadlines();
         let result = monitor.get_deadline(DeadlineTag::from("unknown"));
         assert_eq!(result.err(), Some(DeadlineMonitorError::DeadlineNotFound));
     }
 
     #[test]
     fn start_stop_deadline_within_range_works() {
-        let monitor = create_monitor_with_deadlines();
+        let (monitor, time_source) = create_monitor_with_deadlines();
         let hmon_starting_point = Instant::now();
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
         let handle = deadline.start().unwrap();
 
-        std::thread::sleep(core::time::Duration::from_millis(1001)); // Sleep to simulate work within the deadline range
+        time_source.advance(Duration::from_millis(1001)); // Simulate work within the deadline range
 
         drop(handle); // stop the deadline
 
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
                 panic!(
                     "Deadline {:?} should not have failed or underrun({:?})",
                     monitor_tag, deadline_failure
@@ -424,7 +649,7 @@ mod tests {
 
     #[test]
     fn start_stop_deadline_outside_ranges_is_error_when_dropped_before_evaluate() {
-        let monitor = create_monitor_with_deadlines();
+        let (monitor, _) = create_monitor_with_deadlines();
         let hmon_starting_point = Instant::now();
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
         let handle = deadline.start().unwrap();
@@ -433,7 +658,7 @@ mod tests {
 
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
                 assert_eq!(
                     deadline_failure,
                     DeadlineEvaluationError::TooEarly.into(),
@@ -446,7 +671,7 @@ mod tests {
 
     #[test]
     fn deadline_outside_time_range_is_error_when_dropped_after_evaluate() {
-        let monitor = create_monitor_with_deadlines();
+        let (monitor, _) = create_monitor_with_deadlines();
         let hmon_starting_point = Instant::now();
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
         let handle = deadline.start().unwrap();
@@ -455,7 +680,7 @@ mod tests {
 
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
                 assert_eq!(
                     deadline_failure,
                     DeadlineEvaluationError::TooEarly.into(),
@@ -470,7 +695,7 @@ mod tests {
 
     #[test]
     fn deadline_failed_on_first_run_and_then_restarted_is_evaluated_as_error() {
-        let monitor = create_monitor_with_deadlines();
+        let (monitor, _) = create_monitor_with_deadlines();
         let hmon_starting_point = Instant::now();
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
         let handle = deadline.start().unwrap();
@@ -484,7 +709,7 @@ mod tests {
 
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
                 assert_eq!(
                     deadline_failure,
                     DeadlineEvaluationError::TooEarly.into(),
@@ -497,7 +722,7 @@ mod tests {
 
     #[test]
     fn start_stop_deadline_outside_ranges_is_evaluated_as_error() {
-        let monitor = create_monitor_with_deadlines();
+        let (monitor, _) = create_monitor_with_deadlines();
         let hmon_starting_point = Instant::now();
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
         let handle = deadline.start().unwrap();
@@ -506,7 +731,7 @@ mod tests {
 
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
                 assert_eq!(
                     deadline_failure,
                     DeadlineEvaluationError::TooLate.into(),
@@ -519,7 +744,7 @@ mod tests {
 
     #[test]
     fn monitor_with_multiple_running_deadlines() {
-        let monitor = create_monitor_with_multiple_running_deadlines();
+        let (monitor, time_source) = create_monitor_with_multiple_running_deadlines();
         let hmon_starting_point = Instant::now();
 
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast1")).unwrap();
@@ -531,13 +756,13 @@ mod tests {
         let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast3")).unwrap();
         let _handle3 = deadline.start().unwrap();
 
-        std::thread::sleep(core::time::Duration::from_millis(51)); // Sleep to simulate work within the deadline range
+        time_source.advance(Duration::from_millis(51)); // Simulate work beyond the fast deadlines range
 
         let mut cnt = 0;
 
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
                 cnt += 1;
                 assert_eq!(
                     deadline_failure,
@@ -550,4 +775,445 @@ mod tests {
 
         assert_eq!(cnt, 3, "All three deadlines should have been evaluated");
     }
+
+    fn create_monitor_with_concurrent_deadline(max_instances: usize) -> (DeadlineMonitor, VirtualClock) {
+        let (clock, time_source) = virtual_clock(DEFAULT_CLOCK_RESOLUTION);
+        let allocator = ProtectedMemoryAllocator::new();
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_concurrent_deadline(
+                DeadlineTag::from("request"),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(50)),
+                max_instances,
+            )
+            .build(MonitorTag::from("deadline_monitor"), clock, &allocator)
+            .unwrap();
+        (monitor, time_source)
+    }
+
+    #[test]
+    fn build_concurrent_deadline_without_instances_fails() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let result = DeadlineMonitorBuilder::new()
+            .add_concurrent_deadline(
+                DeadlineTag::from("request"),
+                TimeRange::new(Duration::from_millis(0), Duration::from_millis(50)),
+                0,
+            )
+            .build(MonitorTag::from("deadline_monitor"), Clock::default(), &allocator);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn concurrent_deadline_pool_exhausted() {
+        let (monitor, _) = create_monitor_with_concurrent_deadline(2);
+        let deadline_tag = DeadlineTag::from("request");
+
+        let deadline1 = monitor.get_deadline(deadline_tag).unwrap();
+        let deadline2 = monitor.get_deadline(deadline_tag).unwrap();
+        assert_eq!(
+            monitor.get_deadline(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlinePoolExhausted)
+        );
+
+        // Dropping an instance returns it to the pool.
+        drop(deadline1);
+        let _deadline3 = monitor.get_deadline(deadline_tag).unwrap();
+        drop(deadline2);
+        let _deadline4 = monitor.get_deadline(deadline_tag).unwrap();
+    }
+
+    #[test]
+    fn single_instance_deadline_in_use() {
+        let (monitor, _) = create_monitor_with_concurrent_deadline(1);
+        let deadline_tag = DeadlineTag::from("request");
+
+        let _deadline = monitor.get_deadline(deadline_tag).unwrap();
+        assert_eq!(
+            monitor.get_deadline(deadline_tag).err(),
+            Some(DeadlineMonitorError::DeadlineInUse)
+        );
+    }
+
+    #[test]
+    fn concurrent_deadline_instances_evaluated_independently() {
+        let (monitor, time_source) = create_monitor_with_concurrent_deadline(3);
+        let hmon_starting_point = Instant::now();
+        let deadline_tag = DeadlineTag::from("request");
+
+        let mut deadline1 = monitor.get_deadline(deadline_tag).unwrap();
+        let mut deadline2 = monitor.get_deadline(deadline_tag).unwrap();
+        let _deadline3 = monitor.get_deadline(deadline_tag).unwrap();
+
+        let handle1 = deadline1.start().unwrap();
+        let _handle2 = deadline2.start().unwrap();
+
+        time_source.advance(Duration::from_millis(20));
+        drop(handle1); // first instance finishes within range
+
+        time_source.advance(Duration::from_millis(40)); // second instance is still running and misses its deadline
+
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
+                assert_eq!(*monitor_tag, MonitorTag::from("deadline_monitor"));
+                errors.push(deadline_failure);
+            });
+        assert_eq!(errors, vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn concurrent_deadline_instances_used_from_multiple_threads() {
+        const NUM_THREADS: usize = 4;
+        let (monitor, _) = create_monitor_with_concurrent_deadline(NUM_THREADS);
+        let deadline_tag = DeadlineTag::from("request");
+
+        std::thread::scope(|scope| {
+            for _ in 0..NUM_THREADS {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        let mut deadline = monitor.get_deadline(deadline_tag).unwrap();
+                        let handle = deadline.start().unwrap();
+                        drop(handle);
+                    }
+                });
+            }
+        });
+
+        monitor
+            .inner
+            .evaluate(Instant::now(), &mut |monitor_tag, deadline_failure, _| {
+                panic!(
+                    "Deadline {:?} should not have failed ({:?})",
+                    monitor_tag, deadline_failure
+                );
+            });
+    }
+
+    #[test]
+    fn owned_handle_is_send() {
+        fn assert_send<T: Send + 'static>() {}
+        assert_send::<Deadline>();
+        assert_send::<OwnedDeadlineHandle>();
+    }
+
+    #[test]
+    fn owned_handle_stopped_on_another_thread() {
+        let (monitor, time_source) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let handle = deadline.start_owned().unwrap();
+
+        time_source.advance(Duration::from_millis(1001)); // Simulate work within the deadline range
+
+        let deadline = std::thread::spawn(move || handle.stop()).join().unwrap();
+        assert!(evaluate_errors(&monitor).is_empty());
+
+        // Stopped deadline can be started again.
+        let handle = deadline.start_owned().unwrap();
+        drop(handle); // stop the deadline
+        assert_eq!(
+            evaluate_errors(&monitor),
+            vec![DeadlineEvaluationError::TooEarly.into()]
+        );
+    }
+
+    #[test]
+    fn owned_handle_dropped_stops_deadline() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let handle = deadline.start_owned().unwrap();
+
+        drop(handle); // stop the deadline
+        assert!(evaluate_errors(&monitor).is_empty());
+
+        // Deadline instance is released with the handle.
+        assert!(monitor.get_deadline(DeadlineTag::from("deadline_fast")).is_ok());
+    }
+
+    #[test]
+    fn owned_handle_missed_deadline() {
+        let (monitor, time_source) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let handle = deadline.start_owned().unwrap();
+
+        time_source.advance(Duration::from_millis(51)); // Simulate work beyond the deadline range
+
+        assert_eq!(evaluate_errors(&monitor), vec![DeadlineEvaluationError::TooLate.into()]);
+        let deadline = handle.stop();
+        assert_eq!(deadline.start_owned().err(), Some(DeadlineError::DeadlineAlreadyFailed));
+
+        // Deadline instance is released on failed start.
+        assert!(monitor.get_deadline(DeadlineTag::from("deadline_fast")).is_ok());
+    }
+
+    #[test]
+    fn supervised_future_completed_within_range() {
+        let (monitor, time_source) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut future = Box::pin(deadline.supervise(YieldOnce::new(42)));
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+        time_source.advance(Duration::from_millis(1001)); // Simulate work within the deadline range
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(42));
+
+        assert!(evaluate_errors(&monitor).is_empty());
+    }
+
+    #[test]
+    fn supervised_future_measured_from_first_poll() {
+        let (monitor, time_source) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut future = Box::pin(deadline.supervise(YieldOnce::new(())));
+        time_source.advance(Duration::from_millis(100)); // Future is not polled yet
+        assert!(evaluate_errors(&monitor).is_empty());
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(()));
+        assert!(evaluate_errors(&monitor).is_empty());
+    }
+
+    #[test]
+    fn supervised_future_completed_too_early() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut future = Box::pin(deadline.supervise(core::future::ready(())));
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(()));
+
+        assert_eq!(
+            evaluate_errors(&monitor),
+            vec![DeadlineEvaluationError::TooEarly.into()]
+        );
+    }
+
+    #[test]
+    fn supervised_future_cancelled() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut future = Box::pin(deadline.supervise(YieldOnce::new(())));
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+        drop(future); // cancel before minimum duration passed
+
+        assert!(evaluate_errors(&monitor).is_empty());
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        assert!(deadline.start().is_ok());
+    }
+
+    #[test]
+    fn supervised_future_cancelled_after_missed_deadline() {
+        let (monitor, time_source) = create_monitor_with_deadlines();
+        let deadline = monitor.get_deadline(DeadlineTag::from("deadline_fast")).unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut future = Box::pin(deadline.supervise(YieldOnce::new(())));
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+        time_source.advance(Duration::from_millis(51)); // Simulate work beyond the deadline range
+        drop(future);
+
+        assert_eq!(evaluate_errors(&monitor), vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    #[test]
+    fn rearm_resets_failed_deadline() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        drop(deadline.start().unwrap()); // finished too early
+
+        assert_eq!(deadline.start().err(), Some(DeadlineError::DeadlineAlreadyFailed));
+
+        monitor.inner.rearm();
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, deadline_failure, _| {
+                panic!(
+                    "Deadline {:?} should not have failed after re-arm ({:?})",
+                    monitor_tag, deadline_failure
+                );
+            });
+
+        // Deadline can be started again.
+        assert!(deadline.start().is_ok());
+    }
+
+    #[test]
+    fn rearm_keeps_running_deadline() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+        let handle = deadline.start().unwrap();
+
+        // Deadline within range is not affected by re-arm, so it's still reported when stopped too early.
+        monitor.inner.rearm();
+        drop(handle);
+
+        let mut reported = false;
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, deadline_failure, _| {
+                assert_eq!(deadline_failure, DeadlineEvaluationError::TooEarly.into());
+                reported = true;
+            });
+        assert!(reported);
+    }
+
+    #[test]
+    fn corrupted_memory_is_evaluated_as_error() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let hmon_starting_point = Instant::now();
+        monitor.inner.active_deadlines.corrupt_canary();
+
+        let mut reported = false;
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |_, deadline_failure, origin| {
+                assert_eq!(deadline_failure, DeadlineEvaluationError::MemoryCorrupted.into());
+                assert!(origin.is_none());
+                reported = true;
+            });
+        assert!(reported);
+    }
+
+    fn evaluate_errors(monitor: &DeadlineMonitor) -> Vec<MonitorEvaluationError> {
+        let mut errors = vec![];
+        monitor.inner.evaluate(Instant::now(), &mut |_, deadline_failure, _| {
+            errors.push(deadline_failure)
+        });
+        errors
+    }
+
+    #[test]
+    fn deadline_exact_boundaries() {
+        let (monitor, time_source) = create_monitor_with_deadlines();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        // Stopped exactly at the lower bound.
+        let handle = deadline.start().unwrap();
+        time_source.advance(Duration::from_secs(1));
+        handle.stop();
+        assert!(evaluate_errors(&monitor).is_empty());
+
+        // Evaluated and stopped exactly at the upper bound.
+        let handle = deadline.start().unwrap();
+        time_source.advance(Duration::from_secs(50));
+        assert!(evaluate_errors(&monitor).is_empty());
+        handle.stop();
+        assert!(evaluate_errors(&monitor).is_empty());
+
+        // Stopped one tick before the lower bound.
+        let handle = deadline.start().unwrap();
+        time_source.advance(Duration::from_millis(999));
+        handle.stop();
+        assert_eq!(
+            evaluate_errors(&monitor),
+            vec![DeadlineEvaluationError::TooEarly.into()]
+        );
+        monitor.inner.rearm();
+
+        // Evaluated one tick after the upper bound.
+        let _handle = deadline.start().unwrap();
+        time_source.advance(Duration::from_millis(50_001));
+        assert_eq!(evaluate_errors(&monitor), vec![DeadlineEvaluationError::TooLate.into()]);
+    }
+
+    /// Time point after 100 days of uptime in milliseconds, does not fit into 32 bits.
+    const LONG_UPTIME: u64 = 100 * 24 * 60 * 60 * 1000;
+
+    fn evaluate_no_error(monitor: &DeadlineMonitor, now: u64) {
+        monitor.inner.evaluate_at(now, &mut |monitor_tag, deadline_failure, _| {
+            panic!(
+                "Deadline {:?} should not have failed ({:?})",
+                monitor_tag, deadline_failure
+            );
+        });
+    }
+
+    fn evaluate_single_error(monitor: &DeadlineMonitor, now: u64) -> MonitorEvaluationError {
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate_at(now, &mut |_, deadline_failure, _| errors.push(deadline_failure));
+        assert_eq!(errors.len(), 1);
+        errors[0]
+    }
+
+    #[test]
+    fn long_uptime_deadline_within_range() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        // SAFETY: deadline is stopped before it is started again.
+        unsafe { deadline.start_at(LONG_UPTIME) }.unwrap();
+        evaluate_no_error(&monitor, LONG_UPTIME + 40_000);
+        deadline.stop_at(LONG_UPTIME + 2_000);
+        evaluate_no_error(&monitor, LONG_UPTIME + 60_000);
+    }
+
+    #[test]
+    fn long_uptime_deadline_too_early() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        // SAFETY: deadline is stopped before it is started again.
+        unsafe { deadline.start_at(LONG_UPTIME) }.unwrap();
+        deadline.stop_at(LONG_UPTIME + 500);
+        assert_eq!(
+            evaluate_single_error(&monitor, LONG_UPTIME + 500),
+            DeadlineEvaluationError::TooEarly.into()
+        );
+    }
+
+    #[test]
+    fn long_uptime_deadline_missed_and_rearmed() {
+        let (monitor, _) = create_monitor_with_deadlines();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline_long")).unwrap();
+
+        // SAFETY: deadline is stopped before it is started again.
+        unsafe { deadline.start_at(LONG_UPTIME) }.unwrap();
+        evaluate_no_error(&monitor, LONG_UPTIME + 50_000);
+        assert_eq!(
+            evaluate_single_error(&monitor, LONG_UPTIME + 50_001),
+            DeadlineEvaluationError::TooLate.into()
+        );
+
+        monitor.inner.rearm_at(LONG_UPTIME + 50_001);
+        evaluate_no_error(&monitor, LONG_UPTIME + 100_000);
+    }
+
+    #[test]
+    fn sub_millisecond_resolution_deadline() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let monitor = DeadlineMonitorBuilder::new()
+            .add_deadline(
+                DeadlineTag::from("deadline"),
+                TimeRange::new(Duration::from_micros(100), Duration::from_micros(200)),
+            )
+            .build(
+                MonitorTag::from("deadline_monitor"),
+                virtual_clock(Duration::from_micros(1)).0,
+                &allocator,
+            )
+            .unwrap();
+        let mut deadline = monitor.get_deadline(DeadlineTag::from("deadline")).unwrap();
+
+        // Deadline range is shorter than a millisecond.
+        // SAFETY: deadline is stopped before it is started again.
+        unsafe { deadline.start_at(LONG_UPTIME) }.unwrap();
+        deadline.stop_at(LONG_UPTIME + 150);
+        evaluate_no_error(&monitor, LONG_UPTIME + 1_000);
+
+        // SAFETY: deadline is stopped before it is started again.
+        unsafe { deadline.start_at(LONG_UPTIME + 1_000) }.unwrap();
+        evaluate_no_error(&monitor, LONG_UPTIME + 1_200);
+        assert_eq!(
+            evaluate_single_error(&monitor, LONG_UPTIME + 1_201),
+            DeadlineEvaluationError::TooLate.into()
+        );
+    }
 }
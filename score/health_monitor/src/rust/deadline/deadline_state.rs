@@ -18,8 +18,11 @@ use core::{
 #[derive(Clone, Copy)]
 pub(super) struct DeadlineStateSnapshot(u64);
 
-// Deadline State layout (u64) = | timestamp: u32 | reserved: 28 bits | finished_too_early: 1 bit | reserved: 1 bit | stopped: 1 bit | running: 1 bit |
+// Deadline State layout (u64) = | timestamp: 60 bits | finished_too_early: 1 bit | reserved: 1 bit | stopped: 1 bit | running: 1 bit |
 const DEADLINE_STATE_MASK: u64 = 0b0000_1111;
+const DEADLINE_TIMESTAMP_OFFSET: u32 = 4;
+/// Max timestamp stored in the state, larger values are saturated.
+const DEADLINE_TIMESTAMP_MAX: u64 = u64::MAX >> DEADLINE_TIMESTAMP_OFFSET;
 const DEADLINE_STATE_RUNNING: u64 = 0b0000_0010;
 const DEADLINE_STATE_STOPPED: u64 = 0b0000_0001;
 const DEADLINE_STATE_FINISHED_TOO_EARLY: u64 = 0b0000_1000;
@@ -45,13 +48,15 @@ impl DeadlineStateSnapshot {
         (self.0 & DEADLINE_STATE_FINISHED_TOO_EARLY) != 0
     }
 
-    /// Get timestamp in milliseconds. This is a offset from an start timer that is stored in DeadlineMonitor
-    pub(super) fn timestamp_ms(&self) -> u32 {
-        ((self.0 & !DEADLINE_STATE_MASK) >> u32::BITS) as u32
+    /// Get timestamp in clock ticks. This is a offset from the clock starting point shared by DeadlineMonitor
+    pub(super) fn timestamp(&self) -> u64 {
+        self.0 >> DEADLINE_TIMESTAMP_OFFSET
     }
 
-    pub(super) fn set_timestamp_ms(&mut self, timestamp: u32) {
-        self.0 = ((timestamp as u64) << u32::BITS) | (self.0 & DEADLINE_STATE_MASK);
+    /// Set timestamp in clock ticks. Value is 60-bit, larger values are saturated to [`DEADLINE_TIMESTAMP_MAX`].
+    pub(super) fn set_timestamp(&mut self, timestamp: u64) {
+        let timestamp = timestamp.min(DEADLINE_TIMESTAMP_MAX);
+        self.0 = (timestamp << DEADLINE_TIMESTAMP_OFFSET) | (self.0 & DEADLINE_STATE_MASK);
     }
 
     pub(super) fn set_running(&mut self) {
@@ -66,7 +71,7 @@ impl DeadlineStateSnapshot {
 impl Debug for DeadlineStateSnapshot {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("DeadlineStateSnapshot")
-            .field("timestamp", &self.timestamp_ms())
+            .field("timestamp", &self.timestamp())
             .field("is_running", &self.is_running())
             .field("is_stopped", &self.is_stopped())
             .field("is_underrun", &self.is_underrun())
@@ -77,7 +82,7 @@ impl Debug for DeadlineStateSnapshot {
 impl crate::log::ScoreDebug for DeadlineStateSnapshot {
     fn fmt(&self, f: crate::log::Writer, spec: &crate::log::FormatSpec) -> Result<(), crate::log::Error> {
         crate::log::DebugStruct::new(f, spec, "DeadlineStateSnapshot")
-            .field("timestamp", &self.timestamp_ms())
+            .field("timestamp", &self.timestamp())
             .field("is_running", &self.is_running())
             .field("is_stopped", &self.is_stopped())
             .field("is_underrun", &self.is_underrun())
@@ -142,13 +147,13 @@ mod tests {
         // Set running and timestamp
         let res = state.update(|mut snap| {
             snap.set_running();
-            snap.set_timestamp_ms(1234);
+            snap.set_timestamp(1234);
             Some(snap)
         });
         assert!(res.is_ok());
         let snap = state.snapshot();
         assert!(snap.is_running());
-        assert_eq!(snap.timestamp_ms(), 1234);
+        assert_eq!(snap.timestamp(), 1234);
     }
 
     #[test]
@@ -167,19 +172,43 @@ mod tests {
         assert!(snap.is_stopped());
         assert!(!snap.is_running());
         assert!(!snap.is_underrun());
-        assert_eq!(snap.timestamp_ms(), 0);
+        assert_eq!(snap.timestamp(), 0);
     }
 
     #[test]
-    fn set_and_get_timestamp_ms() {
+    fn set_and_get_timestamp() {
         let mut snap = DeadlineStateSnapshot::default();
-        snap.set_timestamp_ms(0x12345678);
-        assert_eq!(snap.timestamp_ms(), 0x12345678);
+        snap.set_timestamp(0x12345678);
+        assert_eq!(snap.timestamp(), 0x12345678);
         assert!(!snap.is_running());
         assert!(snap.is_stopped()); // Default is stopped, running is set as a flag
         assert!(!snap.is_underrun());
     }
 
+    #[test]
+    fn set_timestamp_beyond_32_bits() {
+        // 100 days in milliseconds does not fit into 32 bits.
+        const HUNDRED_DAYS_MS: u64 = 100 * 24 * 60 * 60 * 1000;
+        let mut snap = DeadlineStateSnapshot::default();
+        snap.set_running();
+        snap.set_underrun();
+        snap.set_timestamp(HUNDRED_DAYS_MS);
+        assert_eq!(snap.timestamp(), HUNDRED_DAYS_MS);
+        assert!(snap.is_running());
+        assert!(snap.is_stopped());
+        assert!(snap.is_underrun());
+    }
+
+    #[test]
+    fn set_timestamp_saturated() {
+        let mut snap = DeadlineStateSnapshot::default();
+        snap.set_timestamp(u64::MAX);
+        assert_eq!(snap.timestamp(), DEADLINE_TIMESTAMP_MAX);
+        assert!(snap.is_stopped());
+        assert!(!snap.is_running());
+        assert!(!snap.is_underrun());
+    }
+
     #[test]
     fn set_running() {
         let mut snap = DeadlineStateSnapshot::default();
@@ -201,11 +230,11 @@ mod tests {
     #[test]
     fn as_u64_and_new() {
         let mut snap = DeadlineStateSnapshot::default();
-        snap.set_timestamp_ms(42);
+        snap.set_timestamp(42);
         snap.set_running();
         let val = snap.as_u64();
         let snap2 = DeadlineStateSnapshot::new(val);
-        assert_eq!(snap2.timestamp_ms(), 42);
+        assert_eq!(snap2.timestamp(), 42);
         assert!(snap2.is_running());
     }
 }
@@ -14,7 +14,7 @@ use crate::common::TimeRange;
 use crate::deadline::deadline_monitor::Deadline;
 use crate::deadline::{DeadlineMonitor, DeadlineMonitorBuilder, DeadlineMonitorError};
 use crate::ffi::{FFIBorrowed, FFICode, FFIHandle};
-use crate::tag::DeadlineTag;
+use crate::tag::{DeadlineTag, FFITag};
 use core::time::Duration;
 
 pub(crate) struct DeadlineMonitorCpp {
@@ -34,7 +34,9 @@ impl DeadlineMonitorCpp {
                 // Now we allocate at runtime. As next step we will add a memory pool for deadlines into self and this way we will not need allocate anymore
                 Ok(Box::into_raw(Box::new(deadline)).cast())
             },
-            Err(DeadlineMonitorError::DeadlineInUse) => Err(FFICode::AlreadyExists),
+            Err(DeadlineMonitorError::DeadlineInUse) | Err(DeadlineMonitorError::DeadlinePoolExhausted) => {
+                Err(FFICode::AlreadyExists)
+            },
             Err(DeadlineMonitorError::DeadlineNotFound) => Err(FFICode::NotFound),
         }
     }
@@ -73,7 +75,7 @@ pub extern "C" fn deadline_monitor_builder_destroy(deadline_monitor_builder_hand
 #[unsafe(no_mangle)]
 pub extern "C" fn deadline_monitor_builder_add_deadline(
     deadline_monitor_builder_handle: FFIHandle,
-    deadline_tag: *const DeadlineTag,
+    deadline_tag: *const FFITag,
     min_ms: u32,
     max_ms: u32,
 ) -> FFICode {
@@ -83,8 +85,11 @@ pub extern "C" fn deadline_monitor_builder_add_deadline(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `DeadlineTag` type must be compatible between C++ and Rust.
-    let deadline_tag = unsafe { *deadline_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(deadline_tag) = (unsafe { DeadlineTag::from_ffi(&*deadline_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of this pointer is ensured.
@@ -105,10 +110,49 @@ pub extern "C" fn deadline_monitor_builder_add_deadline(
     FFICode::Success
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn deadline_monitor_builder_add_concurrent_deadline(
+    deadline_monitor_builder_handle: FFIHandle,
+    deadline_tag: *const FFITag,
+    min_ms: u32,
+    max_ms: u32,
+    max_instances: usize,
+) -> FFICode {
+    if deadline_monitor_builder_handle.is_null() || deadline_tag.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(deadline_tag) = (unsafe { DeadlineTag::from_ffi(&*deadline_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `deadline_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by a call to `deadline_monitor_builder_destroy`.
+    let mut deadline_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(deadline_monitor_builder_handle as *mut DeadlineMonitorBuilder) });
+
+    let range_min = Duration::from_millis(min_ms as u64);
+    let range_max = Duration::from_millis(max_ms as u64);
+    let range = match TimeRange::new_internal(range_min, range_max) {
+        Some(range) => range,
+        None => return FFICode::InvalidArgument,
+    };
+
+    deadline_monitor_builder.add_concurrent_deadline_internal(deadline_tag, range, max_instances);
+
+    FFICode::Success
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn deadline_monitor_get_deadline(
     deadline_monitor_handle: FFIHandle,
-    deadline_tag: *const DeadlineTag,
+    deadline_tag: *const FFITag,
     deadline_handle_out: *mut FFIHandle,
 ) -> FFICode {
     if deadline_monitor_handle.is_null() || deadline_tag.is_null() || deadline_handle_out.is_null() {
@@ -117,8 +161,11 @@ pub extern "C" fn deadline_monitor_get_deadline(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `DeadlineTag` type must be compatible between C++ and Rust.
-    let deadline_tag = unsafe { *deadline_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(deadline_tag) = (unsafe { DeadlineTag::from_ffi(&*deadline_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of this pointer is ensured.
@@ -210,15 +257,15 @@ pub extern "C" fn deadline_destroy(deadline_handle: FFIHandle) -> FFICode {
 #[cfg(all(test, not(loom)))]
 mod tests {
     use crate::deadline::ffi::{
-        deadline_destroy, deadline_monitor_builder_add_deadline, deadline_monitor_builder_create,
-        deadline_monitor_builder_destroy, deadline_monitor_destroy, deadline_monitor_get_deadline, deadline_start,
-        deadline_stop,
+        deadline_destroy, deadline_monitor_builder_add_concurrent_deadline, deadline_monitor_builder_add_deadline,
+        deadline_monitor_builder_create, deadline_monitor_builder_destroy, deadline_monitor_destroy,
+        deadline_monitor_get_deadline, deadline_start, deadline_stop,
     };
     use crate::ffi::{
         health_monitor_builder_add_deadline_monitor, health_monitor_builder_build, health_monitor_builder_create,
         health_monitor_destroy, health_monitor_get_deadline_monitor, FFICode, FFIHandle,
     };
-    use crate::tag::{DeadlineTag, MonitorTag};
+    use crate::tag::{DeadlineTag, FFITag, MonitorTag};
     use core::ptr::null_mut;
 
     #[test]
@@ -257,7 +304,7 @@ mod tests {
 
         let deadline_monitor_builder_add_deadline_result = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             100,
             200,
         );
@@ -267,6 +314,25 @@ mod tests {
         deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
     }
 
+    #[test]
+    fn deadline_monitor_builder_add_deadline_invalid_tag() {
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let deadline_tag = FFITag::from_bytes(b"deadline_\xff");
+
+        let deadline_monitor_builder_add_deadline_result = deadline_monitor_builder_add_deadline(
+            deadline_monitor_builder_handle,
+            &deadline_tag as *const FFITag,
+            100,
+            200,
+        );
+        assert_eq!(deadline_monitor_builder_add_deadline_result, FFICode::InvalidArgument);
+
+        // Clean-up.
+        deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
+    }
+
     #[test]
     fn deadline_monitor_builder_add_deadline_invalid_range() {
         let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
@@ -276,7 +342,7 @@ mod tests {
 
         let deadline_monitor_builder_add_deadline_result = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             10000,
             100,
         );
@@ -291,7 +357,7 @@ mod tests {
         let deadline_tag = DeadlineTag::from("deadline_1");
 
         let deadline_monitor_builder_add_deadline_result =
-            deadline_monitor_builder_add_deadline(null_mut(), &deadline_tag as *const DeadlineTag, 100, 200);
+            deadline_monitor_builder_add_deadline(null_mut(), &FFITag::from(deadline_tag) as *const FFITag, 100, 200);
         assert_eq!(deadline_monitor_builder_add_deadline_result, FFICode::NullParameter);
     }
 
@@ -323,13 +389,13 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             100,
             200,
         );
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -341,13 +407,13 @@ mod tests {
         );
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
 
         let deadline_monitor_get_deadline_result = deadline_monitor_get_deadline(
             deadline_monitor_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             &mut deadline_handle as *mut FFIHandle,
         );
         assert!(!deadline_handle.is_null());
@@ -359,6 +425,141 @@ mod tests {
         health_monitor_destroy(health_monitor_handle);
     }
 
+    #[test]
+    fn deadline_monitor_builder_add_concurrent_deadline_null_builder() {
+        let deadline_tag = DeadlineTag::from("deadline_1");
+
+        let deadline_monitor_builder_add_concurrent_deadline_result = deadline_monitor_builder_add_concurrent_deadline(
+            null_mut(),
+            &FFITag::from(deadline_tag) as *const FFITag,
+            100,
+            200,
+            2,
+        );
+        assert_eq!(
+            deadline_monitor_builder_add_concurrent_deadline_result,
+            FFICode::NullParameter
+        );
+    }
+
+    #[test]
+    fn deadline_monitor_builder_add_concurrent_deadline_invalid_range() {
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let deadline_tag = DeadlineTag::from("deadline_1");
+
+        let deadline_monitor_builder_add_concurrent_deadline_result = deadline_monitor_builder_add_concurrent_deadline(
+            deadline_monitor_builder_handle,
+            &FFITag::from(deadline_tag) as *const FFITag,
+            10000,
+            100,
+            2,
+        );
+        assert_eq!(
+            deadline_monitor_builder_add_concurrent_deadline_result,
+            FFICode::InvalidArgument
+        );
+
+        // Clean-up.
+        deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
+    }
+
+    #[test]
+    fn deadline_monitor_builder_add_concurrent_deadline_no_instances() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_builder_add_concurrent_deadline_result = deadline_monitor_builder_add_concurrent_deadline(
+            deadline_monitor_builder_handle,
+            &FFITag::from(deadline_tag) as *const FFITag,
+            100,
+            200,
+            0,
+        );
+        assert_eq!(
+            deadline_monitor_builder_add_concurrent_deadline_result,
+            FFICode::Success
+        );
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+
+        // Pool size is validated on build.
+        let health_monitor_builder_build_result = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert!(health_monitor_handle.is_null());
+        assert_eq!(health_monitor_builder_build_result, FFICode::InvalidArgument);
+    }
+
+    #[test]
+    fn deadline_monitor_get_deadline_concurrent_instances() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_handles: [FFIHandle; 3] = [null_mut(); 3];
+
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline_1");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = deadline_monitor_builder_add_concurrent_deadline(
+            deadline_monitor_builder_handle,
+            &FFITag::from(deadline_tag) as *const FFITag,
+            100,
+            200,
+            2,
+        );
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_deadline_monitor(
+            health_monitor_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            &mut deadline_monitor_handle as *mut FFIHandle,
+        );
+
+        let results = deadline_handles.each_mut().map(|deadline_handle| {
+            deadline_monitor_get_deadline(
+                deadline_monitor_handle,
+                &FFITag::from(deadline_tag) as *const FFITag,
+                deadline_handle as *mut FFIHandle,
+            )
+        });
+        assert_eq!(results, [FFICode::Success, FFICode::Success, FFICode::AlreadyExists]);
+        assert!(!deadline_handles[0].is_null());
+        assert!(!deadline_handles[1].is_null());
+        assert!(deadline_handles[2].is_null());
+
+        // Clean-up.
+        deadline_destroy(deadline_handles[0]);
+        deadline_destroy(deadline_handles[1]);
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
     #[test]
     fn deadline_monitor_get_deadline_unknown_deadline() {
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
@@ -373,13 +574,13 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             100,
             200,
         );
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -391,14 +592,14 @@ mod tests {
         );
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
 
         let unknown_deadline_tag = DeadlineTag::from("deadline_2");
         let deadline_monitor_get_deadline_result = deadline_monitor_get_deadline(
             deadline_monitor_handle,
-            &unknown_deadline_tag as *const DeadlineTag,
+            &FFITag::from(unknown_deadline_tag) as *const FFITag,
             &mut deadline_handle as *mut FFIHandle,
         );
         assert!(deadline_handle.is_null());
@@ -417,7 +618,7 @@ mod tests {
 
         let deadline_monitor_get_deadline_result = deadline_monitor_get_deadline(
             null_mut(),
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             &mut deadline_handle as *mut FFIHandle,
         );
         assert_eq!(deadline_monitor_get_deadline_result, FFICode::NullParameter);
@@ -436,7 +637,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -448,7 +649,7 @@ mod tests {
         );
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
 
@@ -477,13 +678,13 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             100,
             200,
         );
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -495,12 +696,15 @@ mod tests {
         );
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
 
-        let deadline_monitor_get_deadline_result =
-            deadline_monitor_get_deadline(deadline_monitor_handle, &deadline_tag as *const DeadlineTag, null_mut());
+        let deadline_monitor_get_deadline_result = deadline_monitor_get_deadline(
+            deadline_monitor_handle,
+            &FFITag::from(deadline_tag) as *const FFITag,
+            null_mut(),
+        );
         assert_eq!(deadline_monitor_get_deadline_result, FFICode::NullParameter);
 
         // Clean-up.
@@ -528,13 +732,13 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             100,
             200,
         );
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -546,12 +750,12 @@ mod tests {
         );
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
         let _ = deadline_monitor_get_deadline(
             deadline_monitor_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             &mut deadline_handle as *mut FFIHandle,
         );
 
@@ -578,13 +782,13 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             100,
             200,
         );
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -596,12 +800,12 @@ mod tests {
         );
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
         let _ = deadline_monitor_get_deadline(
             deadline_monitor_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             &mut deadline_handle as *mut FFIHandle,
         );
 
@@ -635,13 +839,13 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = deadline_monitor_builder_add_deadline(
             deadline_monitor_builder_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             100,
             200,
         );
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -653,12 +857,12 @@ mod tests {
         );
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
         let _ = deadline_monitor_get_deadline(
             deadline_monitor_handle,
-            &deadline_tag as *const DeadlineTag,
+            &FFITag::from(deadline_tag) as *const FFITag,
             &mut deadline_handle as *mut FFIHandle,
         );
         let _ = deadline_start(deadline_handle);
@@ -16,11 +16,13 @@
 mod common;
 mod deadline_monitor;
 mod deadline_state;
+mod supervised_future;
 
-pub(crate) use deadline_monitor::DeadlineEvaluationError;
 pub use deadline_monitor::{
-    DeadlineError, DeadlineHandle, DeadlineMonitor, DeadlineMonitorBuilder, DeadlineMonitorError,
+    DeadlineError, DeadlineEvaluationError, DeadlineHandle, DeadlineMonitor, DeadlineMonitorBuilder,
+    DeadlineMonitorError, OwnedDeadlineHandle,
 };
+pub use supervised_future::SupervisedFuture;
 
 // FFI bindings
 pub(super) mod ffi;
@@ -0,0 +1,98 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::deadline::deadline_monitor::Deadline;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Progress of a [`SupervisedFuture`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Progress {
+    /// Future was not polled yet, deadline is not started.
+    NotStarted,
+    /// Deadline is running.
+    Running,
+    /// Deadline could not be started, future is polled without supervision.
+    Unsupervised,
+    /// Future completed, deadline is stopped.
+    Completed,
+}
+
+/// Future supervised by a [`Deadline`], created with [`Deadline::supervise`].
+///
+/// Deadline is started on the first poll and stopped when the wrapped future completes.
+/// Dropping the future before completion cancels the deadline - it is stopped without reporting `TooEarly`,
+/// but a deadline that was already missed is still reported as `TooLate`.
+#[must_use = "futures do nothing unless polled"]
+pub struct SupervisedFuture<F> {
+    future: F,
+    deadline: Deadline,
+    progress: Progress,
+}
+
+impl<F: Future> SupervisedFuture<F> {
+    pub(super) fn new(deadline: Deadline, future: F) -> Self {
+        Self {
+            future,
+            deadline,
+            progress: Progress::NotStarted,
+        }
+    }
+}
+
+impl<F: Future> Future for SupervisedFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is structurally pinned - it is never moved out of `self`, also not in `Drop`.
+        // Remaining fields are not pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        if this.progress == Progress::NotStarted {
+            // SAFETY: the deadline is owned by this future, it is stopped or cancelled before it can be started again.
+            this.progress = match unsafe { this.deadline.start_internal() } {
+                Ok(()) => Progress::Running,
+                // Failure is logged on start and reported by health monitoring.
+                Err(_) => Progress::Unsupervised,
+            };
+        }
+
+        // SAFETY: refer to comment above.
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        let result = future.poll(cx);
+        if result.is_ready() {
+            if this.progress == Progress::Running {
+                this.deadline.stop_internal();
+            }
+            this.progress = Progress::Completed;
+        }
+        result
+    }
+}
+
+impl<F> Drop for SupervisedFuture<F> {
+    fn drop(&mut self) {
+        if self.progress == Progress::Running {
+            self.deadline.cancel_internal();
+        }
+    }
+}
+
+impl<F> core::fmt::Debug for SupervisedFuture<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SupervisedFuture")
+            .field("deadline", &self.deadline)
+            .field("progress", &self.progress)
+            .finish_non_exhaustive()
+    }
+}
@@ -10,13 +10,19 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
+use crate::common::MonitorEvaluationError;
+use crate::config::ConfigError;
+use crate::cycle_timing::CycleStatistics;
 use crate::deadline::ffi::DeadlineMonitorCpp;
 use crate::deadline::DeadlineMonitorBuilder;
 use crate::health_monitor::{HealthMonitor, HealthMonitorBuilder, HealthMonitorError};
 use crate::heartbeat::HeartbeatMonitorBuilder;
+use crate::log::error;
 use crate::logic::LogicMonitorBuilder;
-use crate::tag::MonitorTag;
+use crate::tag::{FFITag, MonitorTag};
 use crate::thread_ffi::ThreadParametersCpp;
+use crate::tolerance::FailureTolerance;
+use core::ffi::{c_char, c_void, CStr};
 use core::mem::ManuallyDrop;
 use core::ops::{Deref, DerefMut};
 use core::time::Duration;
@@ -90,6 +96,40 @@ pub extern "C" fn health_monitor_builder_create(health_monitor_builder_handle_ou
     FFICode::Success
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_builder_from_config(
+    config_path: *const c_char,
+    health_monitor_builder_handle_out: *mut FFIHandle,
+) -> FFICode {
+    if config_path.is_null() || health_monitor_builder_handle_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer points to a null-terminated string.
+    let Ok(config_path) = unsafe { CStr::from_ptr(config_path) }.to_str() else {
+        return FFICode::InvalidArgument;
+    };
+
+    match HealthMonitorBuilder::from_config(config_path) {
+        Ok(health_monitor_builder) => {
+            unsafe {
+                *health_monitor_builder_handle_out = Box::into_raw(Box::new(health_monitor_builder)).cast();
+            }
+            FFICode::Success
+        },
+        Err(e) => {
+            let message = e.to_string();
+            error!("Failed to load health monitor configuration: {}", message);
+            match e {
+                ConfigError::Io(std::io::ErrorKind::NotFound) => FFICode::NotFound,
+                _ => FFICode::InvalidArgument,
+            }
+        },
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_builder_destroy(health_monitor_builder_handle: FFIHandle) -> FFICode {
     if health_monitor_builder_handle.is_null() {
@@ -162,7 +202,7 @@ pub extern "C" fn health_monitor_builder_build(
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_builder_add_deadline_monitor(
     health_monitor_builder_handle: FFIHandle,
-    monitor_tag: *const MonitorTag,
+    monitor_tag: *const FFITag,
     deadline_monitor_builder_handle: FFIHandle,
 ) -> FFICode {
     if health_monitor_builder_handle.is_null() || monitor_tag.is_null() || deadline_monitor_builder_handle.is_null() {
@@ -171,8 +211,11 @@ pub extern "C" fn health_monitor_builder_add_deadline_monitor(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `MonitorTag` type must be compatible between C++ and Rust.
-    let monitor_tag = unsafe { *monitor_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(monitor_tag) = (unsafe { MonitorTag::from_ffi(&*monitor_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of this pointer is ensured.
@@ -196,7 +239,7 @@ pub extern "C" fn health_monitor_builder_add_deadline_monitor(
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_builder_add_heartbeat_monitor(
     health_monitor_builder_handle: FFIHandle,
-    monitor_tag: *const MonitorTag,
+    monitor_tag: *const FFITag,
     heartbeat_monitor_builder_handle: FFIHandle,
 ) -> FFICode {
     if health_monitor_builder_handle.is_null() || monitor_tag.is_null() || heartbeat_monitor_builder_handle.is_null() {
@@ -205,8 +248,11 @@ pub extern "C" fn health_monitor_builder_add_heartbeat_monitor(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `MonitorTag` type must be compatible between C++ and Rust.
-    let monitor_tag = unsafe { *monitor_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(monitor_tag) = (unsafe { MonitorTag::from_ffi(&*monitor_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of this pointer is ensured.
@@ -230,7 +276,7 @@ pub extern "C" fn health_monitor_builder_add_heartbeat_monitor(
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_builder_add_logic_monitor(
     health_monitor_builder_handle: FFIHandle,
-    monitor_tag: *const MonitorTag,
+    monitor_tag: *const FFITag,
     logic_monitor_builder_handle: FFIHandle,
 ) -> FFICode {
     if health_monitor_builder_handle.is_null() || monitor_tag.is_null() || logic_monitor_builder_handle.is_null() {
@@ -239,8 +285,11 @@ pub extern "C" fn health_monitor_builder_add_logic_monitor(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `MonitorTag` type must be compatible between C++ and Rust.
-    let monitor_tag = unsafe { *monitor_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(monitor_tag) = (unsafe { MonitorTag::from_ffi(&*monitor_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of this pointer is ensured.
@@ -260,10 +309,182 @@ pub extern "C" fn health_monitor_builder_add_logic_monitor(
     FFICode::Success
 }
 
+/// Error handler callback.
+/// Receives tag of the failing monitor, monitor kind, error code specific to the monitor kind and user context.
+pub type ErrorHandlerFn =
+    extern "C" fn(monitor_tag: *const FFITag, monitor_kind: u8, error_code: u8, context: *mut c_void);
+
+/// Error handler context destruction callback.
+pub type ErrorHandlerContextDestroyFn = extern "C" fn(context: *mut c_void);
+
+/// Error handler provided over FFI.
+/// Owns the user context and destroys it on drop.
+struct ErrorHandlerCpp {
+    error_handler: ErrorHandlerFn,
+    context: *mut c_void,
+    context_destroy: Option<ErrorHandlerContextDestroyFn>,
+}
+
+// SAFETY: it is assumed that the user context can be used from the monitoring thread.
+unsafe impl Send for ErrorHandlerCpp {}
+
+impl ErrorHandlerCpp {
+    fn call(&self, monitor_tag: &MonitorTag, error: MonitorEvaluationError) {
+        let monitor_tag = FFITag::from(*monitor_tag);
+        (self.error_handler)(
+            &monitor_tag as *const FFITag,
+            error.kind().into(),
+            error.code(),
+            self.context,
+        );
+    }
+}
+
+impl Drop for ErrorHandlerCpp {
+    fn drop(&mut self) {
+        if let Some(context_destroy) = self.context_destroy {
+            context_destroy(self.context);
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_builder_with_error_handler(
+    health_monitor_builder_handle: FFIHandle,
+    error_handler: Option<ErrorHandlerFn>,
+    context: *mut c_void,
+    context_destroy: Option<ErrorHandlerContextDestroyFn>,
+) -> FFICode {
+    let Some(error_handler) = error_handler else {
+        return FFICode::NullParameter;
+    };
+    if health_monitor_builder_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by calls to `health_monitor_builder_destroy` or `health_monitor_builder_build`.
+    let mut health_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) });
+
+    // `context` is owned by the handler from now on - including null value.
+    let error_handler = ErrorHandlerCpp {
+        error_handler,
+        context,
+        context_destroy,
+    };
+    health_monitor_builder
+        .with_error_handler_internal(move |monitor_tag, error| error_handler.call(monitor_tag, error));
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_builder_with_cycle_overrun_limit(
+    health_monitor_builder_handle: FFIHandle,
+    cycle_overrun_limit: u32,
+) -> FFICode {
+    if health_monitor_builder_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by calls to `health_monitor_builder_destroy` or `health_monitor_builder_build`.
+    let mut health_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) });
+
+    // Limit is validated on build.
+    health_monitor_builder.with_cycle_overrun_limit_internal(cycle_overrun_limit);
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_builder_with_failure_tolerance(
+    health_monitor_builder_handle: FFIHandle,
+    monitor_tag: *const FFITag,
+    failures: u32,
+    window: u32,
+    recovery_cycles: u32,
+) -> FFICode {
+    if health_monitor_builder_handle.is_null() || monitor_tag.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(monitor_tag) = (unsafe { MonitorTag::from_ffi(&*monitor_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by calls to `health_monitor_builder_destroy` or `health_monitor_builder_build`.
+    let mut health_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) });
+
+    // Tolerance is validated on build.
+    health_monitor_builder
+        .with_failure_tolerance_internal(monitor_tag, FailureTolerance::new(failures, window, recovery_cycles));
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_builder_with_worker_stall_timeout(
+    health_monitor_builder_handle: FFIHandle,
+    stall_timeout_ms: u64,
+) -> FFICode {
+    if health_monitor_builder_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by calls to `health_monitor_builder_destroy` or `health_monitor_builder_build`.
+    let mut health_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) });
+
+    // Timeout is validated on build.
+    health_monitor_builder.with_worker_stall_timeout_internal(Duration::from_millis(stall_timeout_ms));
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_builder_with_time_resolution(
+    health_monitor_builder_handle: FFIHandle,
+    time_resolution_us: u64,
+) -> FFICode {
+    if health_monitor_builder_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by calls to `health_monitor_builder_destroy` or `health_monitor_builder_build`.
+    let mut health_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_builder_handle as *mut HealthMonitorBuilder) });
+
+    // Resolution is validated on build.
+    health_monitor_builder.with_time_resolution_internal(Duration::from_micros(time_resolution_us));
+
+    FFICode::Success
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_get_deadline_monitor(
     health_monitor_handle: FFIHandle,
-    monitor_tag: *const MonitorTag,
+    monitor_tag: *const FFITag,
     deadline_monitor_handle_out: *mut FFIHandle,
 ) -> FFICode {
     if health_monitor_handle.is_null() || monitor_tag.is_null() || deadline_monitor_handle_out.is_null() {
@@ -272,8 +493,11 @@ pub extern "C" fn health_monitor_get_deadline_monitor(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `MonitorTag` type must be compatible between C++ and Rust.
-    let monitor_tag = unsafe { *monitor_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(monitor_tag) = (unsafe { MonitorTag::from_ffi(&*monitor_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of the pointer is ensured.
@@ -294,7 +518,7 @@ pub extern "C" fn health_monitor_get_deadline_monitor(
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_get_heartbeat_monitor(
     health_monitor_handle: FFIHandle,
-    monitor_tag: *const MonitorTag,
+    monitor_tag: *const FFITag,
     heartbeat_monitor_handle_out: *mut FFIHandle,
 ) -> FFICode {
     if health_monitor_handle.is_null() || monitor_tag.is_null() || heartbeat_monitor_handle_out.is_null() {
@@ -303,8 +527,11 @@ pub extern "C" fn health_monitor_get_heartbeat_monitor(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `MonitorTag` type must be compatible between C++ and Rust.
-    let monitor_tag = unsafe { *monitor_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(monitor_tag) = (unsafe { MonitorTag::from_ffi(&*monitor_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of the pointer is ensured.
@@ -325,7 +552,7 @@ pub extern "C" fn health_monitor_get_heartbeat_monitor(
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_get_logic_monitor(
     health_monitor_handle: FFIHandle,
-    monitor_tag: *const MonitorTag,
+    monitor_tag: *const FFITag,
     logic_monitor_handle_out: *mut FFIHandle,
 ) -> FFICode {
     if health_monitor_handle.is_null() || monitor_tag.is_null() || logic_monitor_handle_out.is_null() {
@@ -334,8 +561,11 @@ pub extern "C" fn health_monitor_get_logic_monitor(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `MonitorTag` type must be compatible between C++ and Rust.
-    let monitor_tag = unsafe { *monitor_tag };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(monitor_tag) = (unsafe { MonitorTag::from_ffi(&*monitor_tag) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of the pointer is ensured.
@@ -372,6 +602,160 @@ pub extern "C" fn health_monitor_start(health_monitor_handle: FFIHandle) -> FFIC
     }
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_stop(health_monitor_handle: FFIHandle) -> FFICode {
+    if health_monitor_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let mut health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    // Stop monitoring logic.
+    match health_monitor.stop() {
+        Ok(_) => FFICode::Success,
+        Err(error) => error.into(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_pause(health_monitor_handle: FFIHandle) -> FFICode {
+    if health_monitor_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let mut health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    // Pause monitoring logic.
+    match health_monitor.pause() {
+        Ok(_) => FFICode::Success,
+        Err(error) => error.into(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_resume(health_monitor_handle: FFIHandle) -> FFICode {
+    if health_monitor_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let mut health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    // Resume monitoring logic.
+    match health_monitor.resume() {
+        Ok(_) => FFICode::Success,
+        Err(error) => error.into(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_rearm(health_monitor_handle: FFIHandle) -> FFICode {
+    if health_monitor_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let mut health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    // Re-arm monitors.
+    match health_monitor.rearm() {
+        Ok(_) => FFICode::Success,
+        Err(error) => error.into(),
+    }
+}
+
+/// Cycle timing statistics provided over FFI.
+/// Must be aligned with `score::mw::health::CycleStatistics`.
+/// Durations are provided in nanoseconds.
+#[repr(C)]
+#[derive(Default)]
+pub struct CycleStatisticsCpp {
+    cycles: u64,
+    min_evaluation_time_ns: u64,
+    max_evaluation_time_ns: u64,
+    avg_evaluation_time_ns: u64,
+    max_jitter_ns: u64,
+    overruns: u64,
+    missed_cycles: u64,
+}
+
+impl From<CycleStatistics> for CycleStatisticsCpp {
+    fn from(value: CycleStatistics) -> Self {
+        let as_ns = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        Self {
+            cycles: value.cycles,
+            min_evaluation_time_ns: as_ns(value.min_evaluation_time),
+            max_evaluation_time_ns: as_ns(value.max_evaluation_time),
+            avg_evaluation_time_ns: as_ns(value.avg_evaluation_time),
+            max_jitter_ns: as_ns(value.max_jitter),
+            overruns: value.overruns,
+            missed_cycles: value.missed_cycles,
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_cycle_statistics(
+    health_monitor_handle: FFIHandle,
+    cycle_statistics_out: *mut CycleStatisticsCpp,
+) -> FFICode {
+    if health_monitor_handle.is_null() || cycle_statistics_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    unsafe {
+        *cycle_statistics_out = health_monitor.cycle_statistics().into();
+    }
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn health_monitor_check_worker(
+    health_monitor_handle: FFIHandle,
+    worker_alive_out: *mut bool,
+) -> FFICode {
+    if health_monitor_handle.is_null() || worker_alive_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_builder_build`.
+    // It is assumed that the pointer was not consumed by a call to `health_monitor_destroy`.
+    let health_monitor = FFIBorrowed::new(unsafe { Box::from_raw(health_monitor_handle as *mut HealthMonitor) });
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    unsafe {
+        *worker_alive_out = health_monitor.worker_watchdog().check();
+    }
+
+    FFICode::Success
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn health_monitor_destroy(health_monitor_handle: FFIHandle) -> FFICode {
     if health_monitor_handle.is_null() {
@@ -391,44 +775,55 @@ pub extern "C" fn health_monitor_destroy(health_monitor_handle: FFIHandle) -> FF
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
+    use crate::common::MonitorKind;
     use crate::deadline::ffi::{
         deadline_monitor_builder_create, deadline_monitor_builder_destroy, deadline_monitor_destroy,
     };
     use crate::ffi::{
         health_monitor_builder_add_deadline_monitor, health_monitor_builder_add_heartbeat_monitor,
         health_monitor_builder_add_logic_monitor, health_monitor_builder_build, health_monitor_builder_create,
-        health_monitor_builder_destroy, health_monitor_destroy, health_monitor_get_deadline_monitor,
-        health_monitor_get_heartbeat_monitor, health_monitor_get_logic_monitor, health_monitor_start, FFICode,
-        FFIHandle,
+        health_monitor_builder_destroy, health_monitor_builder_from_config,
+        health_monitor_builder_with_cycle_overrun_limit, health_monitor_builder_with_error_handler,
+        health_monitor_builder_with_failure_tolerance, health_monitor_builder_with_time_resolution,
+        health_monitor_builder_with_worker_stall_timeout, health_monitor_check_worker, health_monitor_cycle_statistics,
+        health_monitor_destroy, health_monitor_get_deadline_monitor, health_monitor_get_heartbeat_monitor,
+        health_monitor_get_logic_monitor, health_monitor_pause, health_monitor_rearm, health_monitor_resume,
+        health_monitor_start, health_monitor_stop, CycleStatisticsCpp, ErrorHandlerCpp, FFICode, FFIHandle,
     };
     use crate::heartbeat::ffi::{
         heartbeat_monitor_builder_create, heartbeat_monitor_builder_destroy, heartbeat_monitor_destroy,
     };
+    use crate::heartbeat::HeartbeatEvaluationError;
     use crate::logic::ffi::{
         logic_monitor_builder_add_state, logic_monitor_builder_create, logic_monitor_builder_destroy,
         logic_monitor_destroy,
     };
-    use crate::tag::{MonitorTag, StateTag};
+    use crate::tag::{FFITag, MonitorTag, StateTag};
     use crate::thread_ffi::thread_parameters_create;
-    use core::ptr::null_mut;
+    use core::ffi::c_void;
+    use core::ptr::{null, null_mut};
 
     fn def_logic_monitor_builder() -> FFIHandle {
         let mut logic_monitor_builder_handle = null_mut();
         let state1 = StateTag::from("state1");
         let state2 = StateTag::from("state2");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
 
         let _ = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
-            [state2].as_ptr(),
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
             1,
         );
-        let _ =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state2 as *const StateTag, [].as_ptr(), 0);
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
 
         logic_monitor_builder_handle
     }
@@ -461,21 +856,86 @@ mod tests {
     }
 
     #[test]
-    fn health_monitor_builder_build_succeeds() {
+    fn health_monitor_builder_from_config_succeeds() {
+        let config_path = std::env::temp_dir().join(format!("hmon_ffi_config_{}.json", std::process::id()));
+        std::fs::write(
+            &config_path,
+            r#"{ "deadline_monitors": [{ "tag": "deadline_monitor" }] }"#,
+        )
+        .unwrap();
+        let config_path_cstr = std::ffi::CString::new(config_path.to_str().unwrap()).unwrap();
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
-        let mut health_monitor_handle: FFIHandle = null_mut();
-        let mut deadline_monitor_builder_handle = null_mut();
 
-        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
-        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
-        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
-        let _ = health_monitor_builder_add_deadline_monitor(
-            health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
-            deadline_monitor_builder_handle,
+        let health_monitor_builder_from_config_result = health_monitor_builder_from_config(
+            config_path_cstr.as_ptr(),
+            &mut health_monitor_builder_handle as *mut FFIHandle,
         );
+        std::fs::remove_file(&config_path).unwrap();
+        assert!(!health_monitor_builder_handle.is_null());
+        assert_eq!(health_monitor_builder_from_config_result, FFICode::Success);
 
-        let health_monitor_builder_build_result = health_monitor_builder_build(
+        // Clean-up.
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_from_config_not_found() {
+        let config_path_cstr = c"/nonexistent/health_monitor.json";
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+
+        let health_monitor_builder_from_config_result = health_monitor_builder_from_config(
+            config_path_cstr.as_ptr(),
+            &mut health_monitor_builder_handle as *mut FFIHandle,
+        );
+        assert!(health_monitor_builder_handle.is_null());
+        assert_eq!(health_monitor_builder_from_config_result, FFICode::NotFound);
+    }
+
+    #[test]
+    fn health_monitor_builder_from_config_invalid() {
+        let config_path = std::env::temp_dir().join(format!("hmon_ffi_invalid_config_{}.json", std::process::id()));
+        std::fs::write(&config_path, "{}").unwrap();
+        let config_path_cstr = std::ffi::CString::new(config_path.to_str().unwrap()).unwrap();
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+
+        let health_monitor_builder_from_config_result = health_monitor_builder_from_config(
+            config_path_cstr.as_ptr(),
+            &mut health_monitor_builder_handle as *mut FFIHandle,
+        );
+        std::fs::remove_file(&config_path).unwrap();
+        assert!(health_monitor_builder_handle.is_null());
+        assert_eq!(health_monitor_builder_from_config_result, FFICode::InvalidArgument);
+    }
+
+    #[test]
+    fn health_monitor_builder_from_config_null_parameters() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        assert_eq!(
+            health_monitor_builder_from_config(null(), &mut health_monitor_builder_handle as *mut FFIHandle),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            health_monitor_builder_from_config(c"config.json".as_ptr(), null_mut()),
+            FFICode::NullParameter
+        );
+    }
+
+    #[test]
+    fn health_monitor_builder_build_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+
+        let health_monitor_builder_build_result = health_monitor_builder_build(
             health_monitor_builder_handle,
             null_mut(),
             null_mut(),
@@ -502,7 +962,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
 
@@ -611,7 +1071,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
 
@@ -643,7 +1103,7 @@ mod tests {
 
         let health_monitor_builder_add_deadline_monitor_result = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         assert_eq!(health_monitor_builder_add_deadline_monitor_result, FFICode::Success);
@@ -661,7 +1121,7 @@ mod tests {
 
         let health_monitor_builder_add_deadline_monitor_result = health_monitor_builder_add_deadline_monitor(
             null_mut(),
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         assert_eq!(
@@ -673,6 +1133,31 @@ mod tests {
         deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
     }
 
+    #[test]
+    fn health_monitor_builder_add_deadline_monitor_invalid_monitor_tag() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+
+        let deadline_monitor_tag = FFITag::from_bytes(b"\xc3\x28");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+
+        let health_monitor_builder_add_deadline_monitor_result = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &deadline_monitor_tag as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+        assert_eq!(
+            health_monitor_builder_add_deadline_monitor_result,
+            FFICode::InvalidArgument
+        );
+
+        // Clean-up.
+        deadline_monitor_builder_destroy(deadline_monitor_builder_handle);
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
     #[test]
     fn health_monitor_builder_add_deadline_monitor_null_monitor_tag() {
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
@@ -705,7 +1190,7 @@ mod tests {
 
         let health_monitor_builder_add_deadline_monitor_result = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             null_mut(),
         );
         assert_eq!(
@@ -729,7 +1214,7 @@ mod tests {
 
         let health_monitor_builder_add_heartbeat_monitor_result = health_monitor_builder_add_heartbeat_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         assert_eq!(health_monitor_builder_add_heartbeat_monitor_result, FFICode::Success);
@@ -747,149 +1232,512 @@ mod tests {
 
         let health_monitor_builder_add_heartbeat_monitor_result = health_monitor_builder_add_heartbeat_monitor(
             null_mut(),
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         assert_eq!(
             health_monitor_builder_add_heartbeat_monitor_result,
             FFICode::NullParameter
         );
-
-        // Clean-up.
-        heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle);
+
+        // Clean-up.
+        heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_add_heartbeat_monitor_null_monitor_tag() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
+
+        let health_monitor_builder_add_heartbeat_monitor_result = health_monitor_builder_add_heartbeat_monitor(
+            health_monitor_builder_handle,
+            null_mut(),
+            heartbeat_monitor_builder_handle,
+        );
+        assert_eq!(
+            health_monitor_builder_add_heartbeat_monitor_result,
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle);
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_add_heartbeat_monitor_null_heartbeat_monitor_builder() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+
+        let health_monitor_builder_add_heartbeat_monitor_result = health_monitor_builder_add_heartbeat_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
+            null_mut(),
+        );
+        assert_eq!(
+            health_monitor_builder_add_heartbeat_monitor_result,
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_add_logic_monitor_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let initial_state = StateTag::from("initial_state");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(initial_state) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
+            logic_monitor_builder_handle,
+        );
+        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::Success);
+
+        // Clean-up.
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_add_logic_monitor_null_hmon_builder() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let initial_state = StateTag::from("initial_state");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(initial_state) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
+            null_mut(),
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
+            logic_monitor_builder_handle,
+        );
+        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::NullParameter);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_add_logic_monitor_null_monitor_tag() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let initial_state = StateTag::from("initial_state");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(initial_state) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
+            health_monitor_builder_handle,
+            null_mut(),
+            logic_monitor_builder_handle,
+        );
+        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::NullParameter);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_add_logic_monitor_null_logic_monitor_builder() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+
+        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
+            null_mut(),
+        );
+        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::NullParameter);
+
+        // Clean-up.
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
+    #[derive(Default)]
+    struct ErrorHandlerContext {
+        calls: Vec<(MonitorTag, u8, u8)>,
+        destroyed: bool,
+    }
+
+    extern "C" fn test_error_handler(
+        monitor_tag: *const FFITag,
+        monitor_kind: u8,
+        error_code: u8,
+        context: *mut c_void,
+    ) {
+        let context = unsafe { &mut *(context as *mut ErrorHandlerContext) };
+        let monitor_tag = unsafe { MonitorTag::from_ffi(&*monitor_tag) }.unwrap();
+        context.calls.push((monitor_tag, monitor_kind, error_code));
+    }
+
+    extern "C" fn test_error_handler_context_destroy(context: *mut c_void) {
+        let context = unsafe { &mut *(context as *mut ErrorHandlerContext) };
+        context.destroyed = true;
+    }
+
+    #[test]
+    fn health_monitor_builder_with_error_handler_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut context = ErrorHandlerContext::default();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+
+        let health_monitor_builder_with_error_handler_result = health_monitor_builder_with_error_handler(
+            health_monitor_builder_handle,
+            Some(test_error_handler),
+            &mut context as *mut ErrorHandlerContext as *mut c_void,
+            Some(test_error_handler_context_destroy),
+        );
+        assert_eq!(health_monitor_builder_with_error_handler_result, FFICode::Success);
+        assert!(!context.destroyed);
+
+        // Clean-up.
+        // Context is destroyed together with the builder.
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+        assert!(context.destroyed);
+    }
+
+    #[test]
+    fn health_monitor_builder_with_error_handler_null_hmon_builder() {
+        let mut context = ErrorHandlerContext::default();
+
+        let health_monitor_builder_with_error_handler_result = health_monitor_builder_with_error_handler(
+            null_mut(),
+            Some(test_error_handler),
+            &mut context as *mut ErrorHandlerContext as *mut c_void,
+            Some(test_error_handler_context_destroy),
+        );
+        assert_eq!(health_monitor_builder_with_error_handler_result, FFICode::NullParameter);
+        assert!(!context.destroyed);
+    }
+
+    #[test]
+    fn health_monitor_builder_with_error_handler_null_error_handler() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+
+        let health_monitor_builder_with_error_handler_result =
+            health_monitor_builder_with_error_handler(health_monitor_builder_handle, None, null_mut(), None);
+        assert_eq!(health_monitor_builder_with_error_handler_result, FFICode::NullParameter);
+
+        // Clean-up.
+        health_monitor_builder_destroy(health_monitor_builder_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_with_cycle_overrun_limit_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+
+        let health_monitor_builder_with_cycle_overrun_limit_result =
+            health_monitor_builder_with_cycle_overrun_limit(health_monitor_builder_handle, 3);
+        assert_eq!(health_monitor_builder_with_cycle_overrun_limit_result, FFICode::Success);
+
+        let health_monitor_builder_build_result = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert_eq!(health_monitor_builder_build_result, FFICode::Success);
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_builder_with_cycle_overrun_limit_zero() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_with_cycle_overrun_limit(health_monitor_builder_handle, 0);
+
+        let health_monitor_builder_build_result = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert!(health_monitor_handle.is_null());
+        assert_eq!(health_monitor_builder_build_result, FFICode::InvalidArgument);
+    }
+
+    #[test]
+    fn health_monitor_builder_with_cycle_overrun_limit_null_hmon_builder() {
+        let health_monitor_builder_with_cycle_overrun_limit_result =
+            health_monitor_builder_with_cycle_overrun_limit(null_mut(), 3);
+        assert_eq!(
+            health_monitor_builder_with_cycle_overrun_limit_result,
+            FFICode::NullParameter
+        );
     }
 
     #[test]
-    fn health_monitor_builder_add_heartbeat_monitor_null_monitor_tag() {
+    fn health_monitor_builder_with_failure_tolerance_succeeds() {
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
-        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
 
         let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
-        let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
 
-        let health_monitor_builder_add_heartbeat_monitor_result = health_monitor_builder_add_heartbeat_monitor(
+        let health_monitor_builder_with_failure_tolerance_result = health_monitor_builder_with_failure_tolerance(
             health_monitor_builder_handle,
-            null_mut(),
-            heartbeat_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            2,
+            5,
+            3,
         );
-        assert_eq!(
-            health_monitor_builder_add_heartbeat_monitor_result,
-            FFICode::NullParameter
+        assert_eq!(health_monitor_builder_with_failure_tolerance_result, FFICode::Success);
+
+        let health_monitor_builder_build_result = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
         );
+        assert_eq!(health_monitor_builder_build_result, FFICode::Success);
 
         // Clean-up.
-        heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle);
-        health_monitor_builder_destroy(health_monitor_builder_handle);
+        health_monitor_destroy(health_monitor_handle);
     }
 
     #[test]
-    fn health_monitor_builder_add_heartbeat_monitor_null_heartbeat_monitor_builder() {
+    fn health_monitor_builder_with_failure_tolerance_invalid() {
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
 
         let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
-        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
-
-        let health_monitor_builder_add_heartbeat_monitor_result = health_monitor_builder_add_heartbeat_monitor(
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
-            null_mut(),
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
         );
-        assert_eq!(
-            health_monitor_builder_add_heartbeat_monitor_result,
-            FFICode::NullParameter
+        let _ = health_monitor_builder_with_failure_tolerance(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            0,
+            5,
+            3,
         );
 
-        // Clean-up.
-        health_monitor_builder_destroy(health_monitor_builder_handle);
+        let health_monitor_builder_build_result = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        assert!(health_monitor_handle.is_null());
+        assert_eq!(health_monitor_builder_build_result, FFICode::InvalidArgument);
     }
 
     #[test]
-    fn health_monitor_builder_add_logic_monitor_succeeds() {
+    fn health_monitor_builder_with_failure_tolerance_null_parameters() {
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
-        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
-
         let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
 
-        let logic_monitor_tag = MonitorTag::from("logic_monitor");
-        let initial_state = StateTag::from("initial_state");
-        let _ = logic_monitor_builder_create(
-            &initial_state as *const StateTag,
-            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let health_monitor_builder_with_failure_tolerance_result = health_monitor_builder_with_failure_tolerance(
+            null_mut(),
+            &FFITag::from(monitor_tag) as *const FFITag,
+            2,
+            5,
+            3,
+        );
+        assert_eq!(
+            health_monitor_builder_with_failure_tolerance_result,
+            FFICode::NullParameter
         );
 
-        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
-            health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
-            logic_monitor_builder_handle,
+        let health_monitor_builder_with_failure_tolerance_result =
+            health_monitor_builder_with_failure_tolerance(health_monitor_builder_handle, null(), 2, 5, 3);
+        assert_eq!(
+            health_monitor_builder_with_failure_tolerance_result,
+            FFICode::NullParameter
         );
-        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::Success);
 
         // Clean-up.
         health_monitor_builder_destroy(health_monitor_builder_handle);
     }
 
     #[test]
-    fn health_monitor_builder_add_logic_monitor_null_hmon_builder() {
-        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+    fn health_monitor_builder_with_worker_stall_timeout_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
 
-        let logic_monitor_tag = MonitorTag::from("logic_monitor");
-        let initial_state = StateTag::from("initial_state");
-        let _ = logic_monitor_builder_create(
-            &initial_state as *const StateTag,
-            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
         );
 
-        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
+        let health_monitor_builder_with_worker_stall_timeout_result =
+            health_monitor_builder_with_worker_stall_timeout(health_monitor_builder_handle, 1000);
+        assert_eq!(
+            health_monitor_builder_with_worker_stall_timeout_result,
+            FFICode::Success
+        );
+
+        let health_monitor_builder_build_result = health_monitor_builder_build(
+            health_monitor_builder_handle,
             null_mut(),
-            &logic_monitor_tag as *const MonitorTag,
-            logic_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
         );
-        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::NullParameter);
+        assert_eq!(health_monitor_builder_build_result, FFICode::Success);
 
         // Clean-up.
-        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+        health_monitor_destroy(health_monitor_handle);
     }
 
     #[test]
-    fn health_monitor_builder_add_logic_monitor_null_monitor_tag() {
+    fn health_monitor_builder_with_worker_stall_timeout_null_hmon_builder() {
+        let health_monitor_builder_with_worker_stall_timeout_result =
+            health_monitor_builder_with_worker_stall_timeout(null_mut(), 1000);
+        assert_eq!(
+            health_monitor_builder_with_worker_stall_timeout_result,
+            FFICode::NullParameter
+        );
+    }
+
+    fn build_with_time_resolution(time_resolution_us: u64) -> (FFICode, FFIHandle) {
         let mut health_monitor_builder_handle: FFIHandle = null_mut();
-        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
 
         let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
-        let initial_state = StateTag::from("initial_state");
-        let _ = logic_monitor_builder_create(
-            &initial_state as *const StateTag,
-            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
         );
 
-        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
+        let health_monitor_builder_with_time_resolution_result =
+            health_monitor_builder_with_time_resolution(health_monitor_builder_handle, time_resolution_us);
+        assert_eq!(health_monitor_builder_with_time_resolution_result, FFICode::Success);
+
+        let health_monitor_builder_build_result = health_monitor_builder_build(
             health_monitor_builder_handle,
             null_mut(),
-            logic_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
         );
-        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::NullParameter);
+        (health_monitor_builder_build_result, health_monitor_handle)
+    }
+
+    #[test]
+    fn health_monitor_builder_with_time_resolution_succeeds() {
+        let (health_monitor_builder_build_result, health_monitor_handle) = build_with_time_resolution(10);
+        assert_eq!(health_monitor_builder_build_result, FFICode::Success);
 
         // Clean-up.
-        logic_monitor_builder_destroy(logic_monitor_builder_handle);
-        health_monitor_builder_destroy(health_monitor_builder_handle);
+        health_monitor_destroy(health_monitor_handle);
     }
 
     #[test]
-    fn health_monitor_builder_add_logic_monitor_null_logic_monitor_builder() {
-        let mut health_monitor_builder_handle: FFIHandle = null_mut();
-
-        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
-        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+    fn health_monitor_builder_with_time_resolution_invalid() {
+        let (health_monitor_builder_build_result, health_monitor_handle) = build_with_time_resolution(0);
+        assert!(health_monitor_handle.is_null());
+        assert_eq!(health_monitor_builder_build_result, FFICode::InvalidArgument);
+    }
 
-        let health_monitor_builder_add_logic_monitor_result = health_monitor_builder_add_logic_monitor(
-            health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
-            null_mut(),
+    #[test]
+    fn health_monitor_builder_with_time_resolution_null_hmon_builder() {
+        let health_monitor_builder_with_time_resolution_result =
+            health_monitor_builder_with_time_resolution(null_mut(), 10);
+        assert_eq!(
+            health_monitor_builder_with_time_resolution_result,
+            FFICode::NullParameter
         );
-        assert_eq!(health_monitor_builder_add_logic_monitor_result, FFICode::NullParameter);
+    }
 
-        // Clean-up.
-        health_monitor_builder_destroy(health_monitor_builder_handle);
+    #[test]
+    fn error_handler_cpp_call_succeeds() {
+        let mut context = ErrorHandlerContext::default();
+        let monitor_tag = MonitorTag::from("heartbeat_monitor");
+
+        let error_handler = ErrorHandlerCpp {
+            error_handler: test_error_handler,
+            context: &mut context as *mut ErrorHandlerContext as *mut c_void,
+            context_destroy: Some(test_error_handler_context_destroy),
+        };
+        error_handler.call(&monitor_tag, HeartbeatEvaluationError::MultipleHeartbeats.into());
+        drop(error_handler);
+
+        assert_eq!(
+            context.calls,
+            vec![(
+                monitor_tag,
+                MonitorKind::Heartbeat as u8,
+                HeartbeatEvaluationError::MultipleHeartbeats as u8
+            )]
+        );
+        assert!(context.destroyed);
     }
 
     #[test]
@@ -904,7 +1752,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -917,7 +1765,7 @@ mod tests {
 
         let health_monitor_get_deadline_monitor_result = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
         assert!(!deadline_monitor_handle.is_null());
@@ -941,7 +1789,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -955,7 +1803,7 @@ mod tests {
         // First get.
         let health_monitor_get_deadline_monitor_result_1 = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_1_handle as *mut FFIHandle,
         );
         assert!(!deadline_monitor_1_handle.is_null());
@@ -964,7 +1812,7 @@ mod tests {
         // Second get.
         let health_monitor_get_deadline_monitor_result_2 = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_2_handle as *mut FFIHandle,
         );
         assert!(deadline_monitor_2_handle.is_null());
@@ -987,7 +1835,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1000,7 +1848,7 @@ mod tests {
 
         let health_monitor_get_deadline_monitor_result = health_monitor_get_deadline_monitor(
             null_mut(),
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
         assert!(deadline_monitor_handle.is_null());
@@ -1022,7 +1870,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1056,7 +1904,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1069,7 +1917,7 @@ mod tests {
 
         let health_monitor_get_deadline_monitor_result = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             null_mut(),
         );
         assert_eq!(health_monitor_get_deadline_monitor_result, FFICode::NullParameter);
@@ -1090,7 +1938,7 @@ mod tests {
         let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_heartbeat_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1103,7 +1951,7 @@ mod tests {
 
         let health_monitor_get_heartbeat_monitor_result = health_monitor_get_heartbeat_monitor(
             health_monitor_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             &mut heartbeat_monitor_handle as *mut FFIHandle,
         );
         assert!(!heartbeat_monitor_handle.is_null());
@@ -1127,7 +1975,7 @@ mod tests {
         let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_heartbeat_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1141,7 +1989,7 @@ mod tests {
         // First get.
         let health_monitor_get_heartbeat_monitor_result_1 = health_monitor_get_heartbeat_monitor(
             health_monitor_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             &mut heartbeat_monitor_1_handle as *mut FFIHandle,
         );
         assert!(!heartbeat_monitor_1_handle.is_null());
@@ -1150,7 +1998,7 @@ mod tests {
         // Second get.
         let health_monitor_get_heartbeat_monitor_result_2 = health_monitor_get_heartbeat_monitor(
             health_monitor_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             &mut heartbeat_monitor_2_handle as *mut FFIHandle,
         );
         assert!(heartbeat_monitor_2_handle.is_null());
@@ -1173,7 +2021,7 @@ mod tests {
         let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_heartbeat_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1186,7 +2034,7 @@ mod tests {
 
         let health_monitor_get_heartbeat_monitor_result = health_monitor_get_heartbeat_monitor(
             null_mut(),
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             &mut heartbeat_monitor_handle as *mut FFIHandle,
         );
         assert!(heartbeat_monitor_handle.is_null());
@@ -1208,7 +2056,7 @@ mod tests {
         let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_heartbeat_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1242,7 +2090,7 @@ mod tests {
         let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_heartbeat_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1255,7 +2103,7 @@ mod tests {
 
         let health_monitor_get_heartbeat_monitor_result = health_monitor_get_heartbeat_monitor(
             health_monitor_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             null_mut(),
         );
         assert_eq!(health_monitor_get_heartbeat_monitor_result, FFICode::NullParameter);
@@ -1275,7 +2123,7 @@ mod tests {
         let logic_monitor_builder_handle = def_logic_monitor_builder();
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1288,7 +2136,7 @@ mod tests {
 
         let health_monitor_get_logic_monitor_result = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
         assert!(!logic_monitor_handle.is_null());
@@ -1311,7 +2159,7 @@ mod tests {
         let logic_monitor_builder_handle = def_logic_monitor_builder();
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1325,7 +2173,7 @@ mod tests {
         // First get.
         let health_monitor_get_logic_monitor_result_1 = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_1_handle as *mut FFIHandle,
         );
         assert!(!logic_monitor_1_handle.is_null());
@@ -1334,7 +2182,7 @@ mod tests {
         // Second get.
         let health_monitor_get_logic_monitor_result_2 = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_2_handle as *mut FFIHandle,
         );
         assert!(logic_monitor_2_handle.is_null());
@@ -1356,7 +2204,7 @@ mod tests {
         let logic_monitor_builder_handle = def_logic_monitor_builder();
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1369,7 +2217,7 @@ mod tests {
 
         let health_monitor_get_logic_monitor_result = health_monitor_get_logic_monitor(
             null_mut(),
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
         assert!(logic_monitor_handle.is_null());
@@ -1390,7 +2238,7 @@ mod tests {
         let logic_monitor_builder_handle = def_logic_monitor_builder();
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1423,7 +2271,7 @@ mod tests {
         let logic_monitor_builder_handle = def_logic_monitor_builder();
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1436,7 +2284,7 @@ mod tests {
 
         let health_monitor_get_logic_monitor_result = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             null_mut(),
         );
         assert_eq!(health_monitor_get_logic_monitor_result, FFICode::NullParameter);
@@ -1458,7 +2306,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1471,7 +2319,7 @@ mod tests {
 
         let _ = health_monitor_get_deadline_monitor(
             health_monitor_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             &mut deadline_monitor_handle as *mut FFIHandle,
         );
 
@@ -1494,7 +2342,7 @@ mod tests {
         let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_deadline_monitor(
             health_monitor_builder_handle,
-            &deadline_monitor_tag as *const MonitorTag,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
             deadline_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -1523,4 +2371,148 @@ mod tests {
         let health_monitor_destroy_result = health_monitor_destroy(null_mut());
         assert_eq!(health_monitor_destroy_result, FFICode::NullParameter);
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn health_monitor_stop_pause_resume_rearm_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+
+        let _ = health_monitor_get_deadline_monitor(
+            health_monitor_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            &mut deadline_monitor_handle as *mut FFIHandle,
+        );
+
+        let _ = health_monitor_start(health_monitor_handle);
+        assert_eq!(health_monitor_pause(health_monitor_handle), FFICode::Success);
+        assert_eq!(health_monitor_resume(health_monitor_handle), FFICode::Success);
+        assert_eq!(health_monitor_rearm(health_monitor_handle), FFICode::WrongState);
+        assert_eq!(health_monitor_stop(health_monitor_handle), FFICode::Success);
+        assert_eq!(health_monitor_stop(health_monitor_handle), FFICode::WrongState);
+
+        // Clean-up.
+        deadline_monitor_destroy(deadline_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_stop_pause_resume_rearm_null_hmon() {
+        assert_eq!(health_monitor_stop(null_mut()), FFICode::NullParameter);
+        assert_eq!(health_monitor_pause(null_mut()), FFICode::NullParameter);
+        assert_eq!(health_monitor_resume(null_mut()), FFICode::NullParameter);
+        assert_eq!(health_monitor_rearm(null_mut()), FFICode::NullParameter);
+    }
+
+    #[test]
+    fn health_monitor_cycle_statistics_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+
+        let mut cycle_statistics = CycleStatisticsCpp {
+            cycles: 123,
+            ..Default::default()
+        };
+        let health_monitor_cycle_statistics_result =
+            health_monitor_cycle_statistics(health_monitor_handle, &mut cycle_statistics as *mut CycleStatisticsCpp);
+        assert_eq!(health_monitor_cycle_statistics_result, FFICode::Success);
+        // Monitoring not started - no cycles recorded.
+        assert_eq!(cycle_statistics.cycles, 0);
+        assert_eq!(cycle_statistics.overruns, 0);
+        assert_eq!(
+            health_monitor_cycle_statistics(health_monitor_handle, null_mut()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_cycle_statistics_null_hmon() {
+        let mut cycle_statistics = CycleStatisticsCpp::default();
+        let health_monitor_cycle_statistics_result =
+            health_monitor_cycle_statistics(null_mut(), &mut cycle_statistics as *mut CycleStatisticsCpp);
+        assert_eq!(health_monitor_cycle_statistics_result, FFICode::NullParameter);
+    }
+
+    #[test]
+    fn health_monitor_check_worker_succeeds() {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut deadline_monitor_builder_handle: FFIHandle = null_mut();
+
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let _ = deadline_monitor_builder_create(&mut deadline_monitor_builder_handle as *mut FFIHandle);
+        let _ = health_monitor_builder_add_deadline_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(deadline_monitor_tag) as *const FFITag,
+            deadline_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+
+        let mut worker_alive = false;
+        let health_monitor_check_worker_result =
+            health_monitor_check_worker(health_monitor_handle, &mut worker_alive as *mut bool);
+        assert_eq!(health_monitor_check_worker_result, FFICode::Success);
+        // Monitoring not started - nothing to supervise.
+        assert!(worker_alive);
+        assert_eq!(
+            health_monitor_check_worker(health_monitor_handle, null_mut()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn health_monitor_check_worker_null_hmon() {
+        let mut worker_alive = false;
+        let health_monitor_check_worker_result =
+            health_monitor_check_worker(null_mut(), &mut worker_alive as *mut bool);
+        assert_eq!(health_monitor_check_worker_result, FFICode::NullParameter);
+    }
 }
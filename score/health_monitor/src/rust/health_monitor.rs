@@ -11,22 +11,46 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use crate::common::{Monitor, MonitorEvalHandle};
+use crate::clock::{
+    Clock, SystemClock, TimeSource, DEFAULT_CLOCK_RESOLUTION, MAX_CLOCK_RESOLUTION, MIN_CLOCK_RESOLUTION,
+};
+use crate::common::{Monitor, MonitorEvalHandle, MonitorEvaluationError};
+use crate::config::{self, ConfigError};
+use crate::custom::{CustomMonitor, CustomMonitorEvaluator};
+use crate::cycle_timing::{CycleStatistics, CycleTiming};
 use crate::deadline::{DeadlineMonitor, DeadlineMonitorBuilder};
 use crate::heartbeat::{HeartbeatMonitor, HeartbeatMonitorBuilder};
-use crate::log::{error, ScoreDebug};
-use crate::logic::{LogicMonitor, LogicMonitorBuilder};
+use crate::log::{error, info, warn, ScoreDebug};
+use crate::logic::{LogicMonitor, LogicMonitorBuilder, LogicStates, TypedLogicMonitor};
 use crate::protected_memory::ProtectedMemoryAllocator;
+use crate::status::{HealthState, MonitorHealth, Status};
 use crate::supervisor_api_client;
 use crate::tag::MonitorTag;
-use crate::worker::{MonitoringLogic, UniqueThreadRunner};
+use crate::tolerance::FailureTolerance;
+use crate::watchdog::WorkerWatchdog;
+use crate::worker::{ErrorHandler, MonitoringLogic, UniqueThreadRunner};
 use containers::fixed_capacity::FixedCapacityVec;
 use core::time::Duration;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
 use thread::ThreadParameters;
 
+#[cfg(not(any(test, feature = "stub_supervisor_api_client")))]
+type SupervisorAPIClientImpl = supervisor_api_client::score_supervisor_api_client::ScoreSupervisorAPIClient;
+#[cfg(any(test, feature = "stub_supervisor_api_client"))]
+type SupervisorAPIClientImpl = supervisor_api_client::stub_supervisor_api_client::StubSupervisorAPIClient;
+
+/// Default supervisor API cycle.
+pub(crate) const DEFAULT_SUPERVISOR_API_CYCLE: Duration = Duration::from_millis(500);
+/// Default internal processing cycle.
+pub(crate) const DEFAULT_INTERNAL_PROCESSING_CYCLE: Duration = Duration::from_millis(100);
+/// Default worker stall timeout, as a number of internal processing cycles.
+const DEFAULT_WORKER_STALL_CYCLES: u32 = 5;
+
 /// Health monitor errors.
-#[derive(PartialEq, Eq, Debug, ScoreDebug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ScoreDebug)]
 pub enum HealthMonitorError {
     /// Requested entry not found.
     NotFound,
@@ -37,14 +61,20 @@ pub enum HealthMonitorError {
 }
 
 /// Builder for the [`HealthMonitor`].
-#[derive(Default)]
 pub struct HealthMonitorBuilder {
     deadline_monitor_builders: HashMap<MonitorTag, DeadlineMonitorBuilder>,
     heartbeat_monitor_builders: HashMap<MonitorTag, HeartbeatMonitorBuilder>,
     logic_monitor_builders: HashMap<MonitorTag, LogicMonitorBuilder>,
+    custom_monitors: HashMap<MonitorTag, MonitorEvalHandle>,
     supervisor_api_cycle: Duration,
     internal_processing_cycle: Duration,
     thread_parameters: ThreadParameters,
+    error_handler: Option<ErrorHandler>,
+    cycle_overrun_limit: Option<u32>,
+    failure_tolerances: HashMap<MonitorTag, FailureTolerance>,
+    worker_stall_timeout: Option<Duration>,
+    time_resolution: Duration,
+    time_source: Arc<dyn TimeSource>,
 }
 
 impl HealthMonitorBuilder {
@@ -54,12 +84,88 @@ impl HealthMonitorBuilder {
             deadline_monitor_builders: HashMap::new(),
             heartbeat_monitor_builders: HashMap::new(),
             logic_monitor_builders: HashMap::new(),
-            supervisor_api_cycle: Duration::from_millis(500),
-            internal_processing_cycle: Duration::from_millis(100),
+            custom_monitors: HashMap::new(),
+            supervisor_api_cycle: DEFAULT_SUPERVISOR_API_CYCLE,
+            internal_processing_cycle: DEFAULT_INTERNAL_PROCESSING_CYCLE,
             thread_parameters: ThreadParameters::default(),
+            error_handler: None,
+            cycle_overrun_limit: None,
+            failure_tolerances: HashMap::new(),
+            worker_stall_timeout: None,
+            time_resolution: DEFAULT_CLOCK_RESOLUTION,
+            time_source: Arc::new(SystemClock),
         }
     }
 
+    /// Create a new [`HealthMonitorBuilder`] instance from the configuration file.
+    ///
+    /// - `path` - path to the JSON configuration file.
+    ///
+    /// Configuration is a JSON document with following structure:
+    ///
+    /// ```json
+    /// {
+    ///     "supervisor_api_cycle_ms": 500,
+    ///     "internal_processing_cycle_ms": 100,
+    ///     "cycle_overrun_limit": 3,
+    ///     "worker_stall_timeout_ms": 500,
+    ///     "time_resolution_us": 1000,
+    ///     "thread_parameters": {
+    ///         "scheduler_policy": "fifo",
+    ///         "priority": 10,
+    ///         "affinity": [0, 1],
+    ///         "stack_size": 65536
+    ///     },
+    ///     "deadline_monitors": [
+    ///         {
+    ///             "tag": "deadline_monitor",
+    ///             "deadlines": [
+    ///                 { "tag": "deadline", "min_ms": 50, "max_ms": 150 },
+    ///                 { "tag": "request", "min_ms": 0, "max_ms": 20, "max_instances": 4 }
+    ///             ]
+    ///         }
+    ///     ],
+    ///     "heartbeat_monitors": [
+    ///         {
+    ///             "tag": "heartbeat_monitor",
+    ///             "min_ms": 800,
+    ///             "max_ms": 1200,
+    ///             "failure_tolerance": { "failures": 2, "window": 10, "recovery_cycles": 5 }
+    ///         },
+    ///         { "tag": "alive_monitor", "reporting_cycle_ms": 1000, "min_indications": 2, "max_indications": 4 }
+    ///     ],
+    ///     "logic_monitors": [
+    ///         {
+    ///             "tag": "logic_monitor",
+    ///             "initial_state": "off",
+    ///             "states": [
+    ///                 { "tag": "off", "transitions": ["on"] },
+    ///                 { "tag": "on", "transitions": ["off"], "min_ms": 100, "max_ms": 5000 }
+    ///             ]
+    ///         }
+    ///     ]
+    /// }
+    /// ```
+    ///
+    /// - All top-level fields are optional, but at least one monitor must be defined.
+    /// - Cycles and time ranges are provided in milliseconds.
+    /// - `cycle_overrun_limit` is a positive integer, refer to [`with_cycle_overrun_limit`](Self::with_cycle_overrun_limit).
+    /// - `max_instances` is a positive number of concurrent deadline instances, defaults to 1,
+    ///   refer to [`DeadlineMonitorBuilder::add_concurrent_deadline`].
+    /// - Heartbeat monitor uses either `min_ms`/`max_ms` time range or `reporting_cycle_ms` with
+    ///   `min_indications`/`max_indications`, refer to [`HeartbeatMonitorBuilder::with_indications`].
+    /// - Logic monitor state `min_ms`/`max_ms` dwell time is optional, refer to [`LogicMonitorBuilder::add_timed_state`].
+    /// - `failure_tolerance` is optional for each monitor, refer to [`FailureTolerance::new`].
+    /// - `worker_stall_timeout_ms` must be longer than `internal_processing_cycle_ms`.
+    /// - `scheduler_policy` is one of `other`, `fifo` or `round_robin`, `priority` requires `scheduler_policy`.
+    /// - Monitor tags must be unique across all monitor kinds.
+    /// - Unknown fields are rejected.
+    ///
+    /// Configuration is fully validated, but resulting builder can still be modified before [`build`](Self::build).
+    pub fn from_config<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        config::load(path.as_ref())
+    }
+
     /// Add a [`DeadlineMonitor`] for the given [`MonitorTag`].
     ///
     /// - `monitor_tag` - unique tag for the [`DeadlineMonitor`].
@@ -99,6 +205,19 @@ impl HealthMonitorBuilder {
         self
     }
 
+    /// Add a [`CustomMonitor`] for the given [`MonitorTag`].
+    ///
+    /// - `monitor_tag` - unique tag for the [`CustomMonitor`].
+    /// - `monitor` - user-defined monitor.
+    ///
+    /// # Note
+    ///
+    /// If a custom monitor with the same tag already exists, it will be overwritten.
+    pub fn add_custom_monitor<M: CustomMonitor>(mut self, monitor_tag: MonitorTag, monitor: M) -> Self {
+        self.add_custom_monitor_internal(monitor_tag, monitor);
+        self
+    }
+
     /// Set the interval between supervisor API notifications.
     /// This duration determines how often the health monitor notifies the supervisor about system liveness.
     ///
@@ -124,59 +243,164 @@ impl HealthMonitorBuilder {
         self
     }
 
+    /// Set the handler called on each error reported by a monitor.
+    ///
+    /// - `error_handler` - handler receiving tag of the failing monitor and the reported error.
+    ///   Kind of the failing monitor is available using [`MonitorEvaluationError::kind`].
+    ///
+    /// # Note
+    ///
+    /// Handler is called from the monitoring thread (or the caller's thread when using [`HealthMonitorDriver`]).
+    /// It shall not block, otherwise monitoring cycles will be delayed.
+    pub fn with_error_handler<F>(mut self, error_handler: F) -> Self
+    where
+        F: FnMut(&MonitorTag, MonitorEvaluationError) + Send + 'static,
+    {
+        self.with_error_handler_internal(error_handler);
+        self
+    }
+
+    /// Set the number of consecutive monitoring cycle overruns considered a failure.
+    /// By default, overruns are only reported and included in [`HealthMonitor::cycle_statistics`].
+    ///
+    /// - `limit` - number of consecutive overruns, must be greater than zero.
+    ///
+    /// Once the limit is reached, status is changed to [`Status::Failed`] and the failure is reported to the supervisor.
+    pub fn with_cycle_overrun_limit(mut self, limit: u32) -> Self {
+        self.with_cycle_overrun_limit_internal(limit);
+        self
+    }
+
+    /// Set the failure tolerance of the monitor with given tag.
+    /// By default, first failed evaluation cycle of a monitor is considered a failure.
+    ///
+    /// - `monitor_tag` - tag of a monitor added to this builder.
+    /// - `tolerance` - failure tolerance, refer to [`FailureTolerance`].
+    ///
+    /// Tolerated failures are reported as [`MonitorHealth::Tolerated`] and supervisor is still notified about liveness.
+    pub fn with_failure_tolerance(mut self, monitor_tag: MonitorTag, tolerance: FailureTolerance) -> Self {
+        self.with_failure_tolerance_internal(monitor_tag, tolerance);
+        self
+    }
+
+    /// Set the time without monitoring cycle after which the monitoring logic is considered stalled.
+    /// By default, five internal processing cycles.
+    ///
+    /// - `stall_timeout` - stall timeout, must be longer than the internal processing cycle.
+    ///
+    /// Refer to [`WorkerWatchdog`] for details.
+    pub fn with_worker_stall_timeout(mut self, stall_timeout: Duration) -> Self {
+        self.with_worker_stall_timeout_internal(stall_timeout);
+        self
+    }
+
+    /// Set the resolution of the clock shared by deadline and heartbeat monitors.
+    /// By default, one millisecond.
+    ///
+    /// - `resolution` - clock resolution, must be between one microsecond and one millisecond.
+    ///
+    /// Finer resolution allows for time ranges with sub-millisecond precision.
+    pub fn with_time_resolution(mut self, resolution: Duration) -> Self {
+        self.with_time_resolution_internal(resolution);
+        self
+    }
+
+    /// Set the source of time used by monitors and the monitoring logic.
+    /// By default, [`SystemClock`].
+    ///
+    /// - `time_source` - source of time points, e.g., [`crate::VirtualClock`] for deterministic tests.
+    pub fn with_time_source<T: TimeSource + 'static>(mut self, time_source: T) -> Self {
+        self.with_time_source_internal(Arc::new(time_source));
+        self
+    }
+
     /// Build a new [`HealthMonitor`] instance based on provided parameters.
+    /// Monitor tags must be unique across all monitor kinds.
     pub fn build(self) -> Result<HealthMonitor, HealthMonitorError> {
-        // Check cycle values.
-        // `supervisor_api_cycle` must be a multiple of `internal_processing_cycle`.
-        let supervisor_api_cycle_ms = self.supervisor_api_cycle.as_millis() as u64;
-        let internal_processing_cycle_ms = self.internal_processing_cycle.as_millis() as u64;
-        if !supervisor_api_cycle_ms.is_multiple_of(internal_processing_cycle_ms) {
-            error!(
-                "Supervisor API cycle duration ({} ms) must be a multiple of internal processing cycle interval ({} ms).",
-                supervisor_api_cycle_ms, internal_processing_cycle_ms
-            );
-            return Err(HealthMonitorError::InvalidArgument);
-        }
+        self.validate_cycles()?;
+        self.validate_cycle_overrun_limit()?;
+        self.validate_worker_stall_timeout()?;
+        self.validate_time_resolution()?;
+        self.validate_monitors()?;
+        let worker_stall_timeout = self.worker_stall_timeout();
+
+        // Check failure tolerances.
+        for (monitor_tag, tolerance) in self.failure_tolerances.iter() {
+            if let Err(e) = tolerance.validate() {
+                error!(
+                    "Invalid failure tolerance of monitor with tag {:?}: {:?}.",
+                    monitor_tag, tolerance
+                );
+                return Err(e);
+            }
 
-        // Check number of monitors.
-        let num_monitors = self.deadline_monitor_builders.len()
-            + self.heartbeat_monitor_builders.len()
-            + self.logic_monitor_builders.len();
-        if num_monitors == 0 {
-            error!("No monitors have been added. HealthMonitor cannot be created.");
-            return Err(HealthMonitorError::WrongState);
+            let monitor_exists = self.deadline_monitor_builders.contains_key(monitor_tag)
+                || self.heartbeat_monitor_builders.contains_key(monitor_tag)
+                || self.logic_monitor_builders.contains_key(monitor_tag)
+                || self.custom_monitors.contains_key(monitor_tag);
+            if !monitor_exists {
+                error!(
+                    "Failure tolerance provided for unknown monitor with tag {:?}.",
+                    monitor_tag
+                );
+                return Err(HealthMonitorError::InvalidArgument);
+            }
         }
 
-        // Create allocator.
-        let allocator = ProtectedMemoryAllocator {};
+        // Create allocator and clock shared by all monitors.
+        let allocator = ProtectedMemoryAllocator::new();
+        let clock = Clock::new(self.time_resolution, self.time_source);
 
         // Create deadline monitors.
         let mut deadline_monitors = HashMap::new();
         for (tag, builder) in self.deadline_monitor_builders {
-            let monitor = builder.build(tag, &allocator);
+            let monitor = builder.build(tag, clock.clone(), &allocator)?;
             deadline_monitors.insert(tag, Some(MonitorState::Available(monitor)));
         }
 
         // Create heartbeat monitors.
         let mut heartbeat_monitors = HashMap::new();
         for (tag, builder) in self.heartbeat_monitor_builders {
-            let monitor = builder.build(tag, self.internal_processing_cycle, &allocator)?;
+            let monitor = builder.build(tag, self.internal_processing_cycle, clock.clone(), &allocator)?;
             heartbeat_monitors.insert(tag, Some(MonitorState::Available(monitor)));
         }
 
         // Create logic monitors.
         let mut logic_monitors = HashMap::new();
         for (tag, builder) in self.logic_monitor_builders {
-            let monitor = builder.build(tag, &allocator)?;
+            let monitor = builder.build(tag, clock.clone(), &allocator)?;
             logic_monitors.insert(tag, Some(MonitorState::Available(monitor)));
         }
 
+        // Create health state containing all monitors.
+        let monitor_tags = deadline_monitors
+            .keys()
+            .chain(heartbeat_monitors.keys())
+            .chain(logic_monitors.keys())
+            .chain(self.custom_monitors.keys())
+            .copied();
+        let health_state = Arc::new(HealthState::new(monitor_tags));
+        let cycle_timing = Arc::new(CycleTiming::new(
+            self.internal_processing_cycle,
+            self.cycle_overrun_limit,
+        ));
+        let watchdog = WorkerWatchdog::new(worker_stall_timeout, Arc::clone(&health_state), clock.clone());
+
         Ok(HealthMonitor {
             deadline_monitors,
             heartbeat_monitors,
             logic_monitors,
-            worker: UniqueThreadRunner::new(self.internal_processing_cycle, self.thread_parameters),
+            // Custom monitors are not handed out, only their evaluation handles are kept.
+            custom_monitors: self.custom_monitors.into_values().collect(),
+            health_state,
+            cycle_timing,
+            watchdog,
+            error_handler: self.error_handler,
+            failure_tolerances: self.failure_tolerances,
+            worker: UniqueThreadRunner::new(self.internal_processing_cycle, self.thread_parameters, clock.clone()),
             supervisor_api_cycle: self.supervisor_api_cycle,
+            internal_processing_cycle: self.internal_processing_cycle,
+            clock,
         })
     }
 
@@ -202,6 +426,12 @@ impl HealthMonitorBuilder {
         self.logic_monitor_builders.insert(monitor_tag, monitor_builder);
     }
 
+    pub(crate) fn add_custom_monitor_internal<M: CustomMonitor>(&mut self, monitor_tag: MonitorTag, monitor: M) {
+        let evaluator = CustomMonitorEvaluator::new(monitor_tag, monitor);
+        self.custom_monitors
+            .insert(monitor_tag, MonitorEvalHandle::new(Arc::new(evaluator)));
+    }
+
     pub(crate) fn with_supervisor_api_cycle_internal(&mut self, cycle_duration: Duration) {
         self.supervisor_api_cycle = cycle_duration;
     }
@@ -213,6 +443,126 @@ impl HealthMonitorBuilder {
     pub(crate) fn thread_parameters_internal(&mut self, thread_parameters: ThreadParameters) {
         self.thread_parameters = thread_parameters;
     }
+
+    pub(crate) fn with_error_handler_internal<F>(&mut self, error_handler: F)
+    where
+        F: FnMut(&MonitorTag, MonitorEvaluationError) + Send + 'static,
+    {
+        self.error_handler = Some(Box::new(error_handler));
+    }
+
+    pub(crate) fn with_cycle_overrun_limit_internal(&mut self, limit: u32) {
+        self.cycle_overrun_limit = Some(limit);
+    }
+
+    pub(crate) fn with_failure_tolerance_internal(&mut self, monitor_tag: MonitorTag, tolerance: FailureTolerance) {
+        self.failure_tolerances.insert(monitor_tag, tolerance);
+    }
+
+    pub(crate) fn with_worker_stall_timeout_internal(&mut self, stall_timeout: Duration) {
+        self.worker_stall_timeout = Some(stall_timeout);
+    }
+
+    pub(crate) fn with_time_resolution_internal(&mut self, resolution: Duration) {
+        self.time_resolution = resolution;
+    }
+
+    pub(crate) fn with_time_source_internal(&mut self, time_source: Arc<dyn TimeSource>) {
+        self.time_source = time_source;
+    }
+
+    // Used by `build` and config parsing code to validate parameters
+
+    /// Check `supervisor_api_cycle` is a multiple of `internal_processing_cycle`.
+    pub(crate) fn validate_cycles(&self) -> Result<(), HealthMonitorError> {
+        let supervisor_api_cycle_ms = self.supervisor_api_cycle.as_millis() as u64;
+        let internal_processing_cycle_ms = self.internal_processing_cycle.as_millis() as u64;
+        if !supervisor_api_cycle_ms.is_multiple_of(internal_processing_cycle_ms) {
+            error!(
+                "Supervisor API cycle duration ({} ms) must be a multiple of internal processing cycle interval ({} ms).",
+                supervisor_api_cycle_ms, internal_processing_cycle_ms
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Check cycle overrun limit is greater than zero.
+    pub(crate) fn validate_cycle_overrun_limit(&self) -> Result<(), HealthMonitorError> {
+        if self.cycle_overrun_limit == Some(0) {
+            error!("Cycle overrun limit must be greater than zero.");
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Check worker stall timeout is longer than `internal_processing_cycle`.
+    pub(crate) fn validate_worker_stall_timeout(&self) -> Result<(), HealthMonitorError> {
+        let worker_stall_timeout = self.worker_stall_timeout();
+        if worker_stall_timeout <= self.internal_processing_cycle {
+            error!(
+                "Worker stall timeout ({} ms) must be longer than internal processing cycle interval ({} ms).",
+                worker_stall_timeout.as_millis() as u64,
+                self.internal_processing_cycle.as_millis() as u64
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Check time resolution is in the accepted range.
+    pub(crate) fn validate_time_resolution(&self) -> Result<(), HealthMonitorError> {
+        if !(MIN_CLOCK_RESOLUTION..=MAX_CLOCK_RESOLUTION).contains(&self.time_resolution) {
+            error!(
+                "Time resolution ({} ns) must be between 1 us and 1 ms.",
+                self.time_resolution.as_nanos() as u64
+            );
+            return Err(HealthMonitorError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Check at least one monitor is added and monitor tags are unique across all monitor kinds.
+    pub(crate) fn validate_monitors(&self) -> Result<(), HealthMonitorError> {
+        let num_monitors = self.deadline_monitor_builders.len()
+            + self.heartbeat_monitor_builders.len()
+            + self.logic_monitor_builders.len()
+            + self.custom_monitors.len();
+        if num_monitors == 0 {
+            error!("No monitors have been added. HealthMonitor cannot be created.");
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        // Health state, debouncers and failure tolerances are keyed only by the monitor tag.
+        let mut monitor_tags = HashSet::with_capacity(num_monitors);
+        let all_tags = self
+            .deadline_monitor_builders
+            .keys()
+            .chain(self.heartbeat_monitor_builders.keys())
+            .chain(self.logic_monitor_builders.keys())
+            .chain(self.custom_monitors.keys());
+        for monitor_tag in all_tags {
+            if !monitor_tags.insert(monitor_tag) {
+                error!("Monitor tag {:?} is used by more than one monitor.", monitor_tag);
+                return Err(HealthMonitorError::InvalidArgument);
+            }
+        }
+        Ok(())
+    }
+
+    /// Worker stall timeout, defaults to [`DEFAULT_WORKER_STALL_CYCLES`] internal processing cycles.
+    fn worker_stall_timeout(&self) -> Duration {
+        self.worker_stall_timeout.unwrap_or(
+            self.internal_processing_cycle
+                .saturating_mul(DEFAULT_WORKER_STALL_CYCLES),
+        )
+    }
+}
+
+impl Default for HealthMonitorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Monitor ownership state in the [`HealthMonitor`].
@@ -233,8 +583,16 @@ pub struct HealthMonitor {
     deadline_monitors: HashMap<MonitorTag, MonitorContainer<DeadlineMonitor>>,
     heartbeat_monitors: HashMap<MonitorTag, MonitorContainer<HeartbeatMonitor>>,
     logic_monitors: HashMap<MonitorTag, MonitorContainer<LogicMonitor>>,
+    custom_monitors: Vec<MonitorEvalHandle>,
+    health_state: Arc<HealthState>,
+    cycle_timing: Arc<CycleTiming>,
+    watchdog: WorkerWatchdog,
+    error_handler: Option<ErrorHandler>,
+    failure_tolerances: HashMap<MonitorTag, FailureTolerance>,
     worker: UniqueThreadRunner,
     supervisor_api_cycle: Duration,
+    internal_processing_cycle: Duration,
+    clock: Clock,
 }
 
 impl HealthMonitor {
@@ -288,6 +646,48 @@ impl HealthMonitor {
         Self::get_monitor(&mut self.logic_monitors, monitor_tag)
     }
 
+    /// Get and pass ownership of a [`TypedLogicMonitor`] for the given [`MonitorTag`].
+    ///
+    /// - `monitor_tag` - unique tag for the [`LogicMonitor`].
+    ///
+    /// Returns [`Some`] containing [`TypedLogicMonitor`] if found, not taken and all states of `E` are defined.
+    /// Otherwise returns [`None`].
+    pub fn get_typed_logic_monitor<E: LogicStates>(&mut self, monitor_tag: MonitorTag) -> Option<TypedLogicMonitor<E>> {
+        if let Some(Some(MonitorState::Available(monitor))) = self.logic_monitors.get(&monitor_tag) {
+            if !TypedLogicMonitor::<E>::matches(monitor) {
+                warn!("Logic monitor ({:?}) does not define all requested states", monitor_tag);
+                return None;
+            }
+        }
+        Self::get_monitor(&mut self.logic_monitors, monitor_tag).and_then(TypedLogicMonitor::new)
+    }
+
+    /// Current [`Status`] of the health monitoring.
+    pub fn status(&self) -> Status {
+        self.health_state.status()
+    }
+
+    /// Get health of a monitor with the given [`MonitorTag`].
+    ///
+    /// - `monitor_tag` - unique tag of the monitor.
+    ///
+    /// Returns [`Some`] containing [`MonitorHealth`] if monitor is found.
+    /// Otherwise returns [`None`].
+    pub fn monitor_health(&self, monitor_tag: MonitorTag) -> Option<MonitorHealth> {
+        self.health_state.monitor_health(monitor_tag)
+    }
+
+    /// Timing statistics of the monitoring cycles.
+    pub fn cycle_statistics(&self) -> CycleStatistics {
+        self.cycle_timing.statistics()
+    }
+
+    /// Get a [`WorkerWatchdog`] supervising the monitoring thread.
+    /// Watchdog can be moved to and checked from another thread.
+    pub fn worker_watchdog(&self) -> WorkerWatchdog {
+        self.watchdog.clone()
+    }
+
     fn collect_given_monitors<M>(
         monitors_to_collect: &mut HashMap<MonitorTag, MonitorContainer<M>>,
         collected_monitors: &mut FixedCapacityVec<MonitorEvalHandle>,
@@ -331,7 +731,7 @@ impl HealthMonitor {
     /// This method shall be called before `Lifecycle.running()`.
     /// Otherwise the supervisor might consider the process not alive.
     ///
-    /// Health monitoring logic stops when the [`HealthMonitor`] is dropped.
+    /// Health monitoring logic stops when [`HealthMonitor::stop`] is called or the [`HealthMonitor`] is dropped.
     ///
     /// # Panics
     ///
@@ -341,40 +741,276 @@ impl HealthMonitor {
     }
 
     pub(crate) fn start_internal(&mut self) -> Result<(), HealthMonitorError> {
+        let monitoring_logic = self.create_monitoring_logic()?;
+        self.health_state.set_status(Status::Running);
+        self.watchdog.arm(self.clock.instant());
+        self.worker.start(monitoring_logic);
+        Ok(())
+    }
+
+    /// Stop the health monitoring logic and join the monitoring thread.
+    ///
+    /// Status is changed to [`Status::Stopped`], monitoring cannot be restarted afterwards.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if monitoring is not started or already stopped.
+    pub fn stop(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.worker.is_started() || !self.health_state.stop() {
+            return Err(HealthMonitorError::WrongState);
+        }
+
+        self.worker.join();
+        Ok(())
+    }
+
+    /// Pause the health monitoring logic, e.g., for a planned maintenance window.
+    ///
+    /// Monitors are not evaluated until [`HealthMonitor::resume`] is called.
+    /// Supervisor is still notified about liveness.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if status is not [`Status::Running`].
+    pub fn pause(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.health_state.transition(Status::Running, Status::Disabled) {
+            return Err(HealthMonitorError::WrongState);
+        }
+        Ok(())
+    }
+
+    /// Resume the health monitoring logic paused with [`HealthMonitor::pause`].
+    ///
+    /// All monitors are re-armed - errors observed during the pause are discarded.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if monitoring is not paused.
+    pub fn resume(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.worker.is_started() || !self.health_state.request_rearm(Status::Disabled) {
+            return Err(HealthMonitorError::WrongState);
+        }
+        Ok(())
+    }
+
+    /// Re-arm monitors after a recoverable failure.
+    ///
+    /// Errors observed so far are discarded, all monitors are healthy again and are evaluated from the next cycle.
+    ///
+    /// # Notes
+    ///
+    /// The first failure is reported to the supervisor immediately, which expires the process supervision.
+    /// Supervisor-side expiry is final - re-arming only restores local monitoring, the process is not brought back.
+    /// Subsequent failures are not reported to the supervisor again.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if monitoring is not started or status is not [`Status::Failed`].
+    pub fn rearm(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.worker.is_started() || !self.health_state.request_rearm(Status::Failed) {
+            return Err(HealthMonitorError::WrongState);
+        }
+        Ok(())
+    }
+
+    /// Convert the [`HealthMonitor`] into a [`HealthMonitorDriver`] that runs the health monitoring logic in the caller's thread.
+    ///
+    /// This is an alternative to [`HealthMonitor::start`] - no monitoring thread is spawned.
+    /// The caller is responsible for calling [`HealthMonitorDriver::tick`] periodically.
+    ///
+    /// # Notes
+    ///
+    /// All monitors must be taken before calling this method.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if monitors are not taken.
+    pub fn into_driver(mut self) -> Result<HealthMonitorDriver, HealthMonitorError> {
+        let monitoring_logic = self.create_monitoring_logic()?;
+        self.health_state.set_status(Status::Running);
+        self.watchdog.arm(self.clock.instant());
+        Ok(HealthMonitorDriver::new(
+            monitoring_logic,
+            self.clock.instant(),
+            self.internal_processing_cycle,
+            Arc::clone(&self.health_state),
+            Arc::clone(&self.cycle_timing),
+            self.watchdog.clone(),
+        ))
+    }
+
+    fn create_monitoring_logic(&mut self) -> Result<MonitoringLogic<SupervisorAPIClientImpl>, HealthMonitorError> {
         // Collect all monitors.
-        let num_monitors = self.deadline_monitors.len() + self.heartbeat_monitors.len() + self.logic_monitors.len();
+        let num_monitors = self.deadline_monitors.len()
+            + self.heartbeat_monitors.len()
+            + self.logic_monitors.len()
+            + self.custom_monitors.len();
         let mut collected_monitors = FixedCapacityVec::new(num_monitors);
         Self::collect_given_monitors(&mut self.deadline_monitors, &mut collected_monitors)?;
         Self::collect_given_monitors(&mut self.heartbeat_monitors, &mut collected_monitors)?;
         Self::collect_given_monitors(&mut self.logic_monitors, &mut collected_monitors)?;
+        for handle in self.custom_monitors.drain(..) {
+            if collected_monitors.push(handle).is_err() {
+                // Should not fail - capacity was preallocated.
+                error!("Failed to push monitor handle.");
+                return Err(HealthMonitorError::WrongState);
+            }
+        }
 
-        // Start monitoring logic.
-        let monitoring_logic = MonitoringLogic::new(
+        Ok(MonitoringLogic::new(
             collected_monitors,
             self.supervisor_api_cycle,
-            #[cfg(not(any(test, feature = "stub_supervisor_api_client")))]
-            supervisor_api_client::score_supervisor_api_client::ScoreSupervisorAPIClient::new(),
-            #[cfg(any(test, feature = "stub_supervisor_api_client"))]
-            supervisor_api_client::stub_supervisor_api_client::StubSupervisorAPIClient::new(),
-        );
+            SupervisorAPIClientImpl::new(),
+            Arc::clone(&self.health_state),
+            Arc::clone(&self.cycle_timing),
+            self.watchdog.clone(),
+            self.clock.clone(),
+            self.error_handler.take(),
+        )
+        .with_failure_tolerances(&self.failure_tolerances))
+    }
+}
 
-        self.worker.start(monitoring_logic);
+/// Health monitoring logic driven from the caller's thread.
+///
+/// Created using [`HealthMonitor::into_driver`].
+pub struct HealthMonitorDriver {
+    monitoring_logic: MonitoringLogic<SupervisorAPIClientImpl>,
+    hmon_starting_point: Instant,
+    internal_processing_cycle: Duration,
+    health_state: Arc<HealthState>,
+    cycle_timing: Arc<CycleTiming>,
+    watchdog: WorkerWatchdog,
+    next_cycle: Option<Instant>,
+}
+
+impl HealthMonitorDriver {
+    fn new(
+        monitoring_logic: MonitoringLogic<SupervisorAPIClientImpl>,
+        hmon_starting_point: Instant,
+        internal_processing_cycle: Duration,
+        health_state: Arc<HealthState>,
+        cycle_timing: Arc<CycleTiming>,
+        watchdog: WorkerWatchdog,
+    ) -> Self {
+        Self {
+            monitoring_logic,
+            hmon_starting_point,
+            internal_processing_cycle,
+            health_state,
+            cycle_timing,
+            watchdog,
+            next_cycle: None,
+        }
+    }
+
+    /// Current [`Status`] of the health monitoring.
+    pub fn status(&self) -> Status {
+        self.health_state.status()
+    }
+
+    /// Get health of a monitor with the given [`MonitorTag`].
+    ///
+    /// - `monitor_tag` - unique tag of the monitor.
+    ///
+    /// Returns [`Some`] containing [`MonitorHealth`] if monitor is found.
+    /// Otherwise returns [`None`].
+    pub fn monitor_health(&self, monitor_tag: MonitorTag) -> Option<MonitorHealth> {
+        self.health_state.monitor_health(monitor_tag)
+    }
+
+    /// Interval in which [`HealthMonitorDriver::tick`] is expected to be called.
+    pub fn internal_processing_cycle(&self) -> Duration {
+        self.internal_processing_cycle
+    }
+
+    /// Timing statistics of the monitoring cycles.
+    /// Jitter and overruns are measured in relation to the schedule set by the first tick.
+    pub fn cycle_statistics(&self) -> CycleStatistics {
+        self.cycle_timing.statistics()
+    }
+
+    /// Get a [`WorkerWatchdog`] supervising the thread calling [`HealthMonitorDriver::tick`].
+    /// Watchdog shall be checked from another thread.
+    pub fn worker_watchdog(&self) -> WorkerWatchdog {
+        self.watchdog.clone()
+    }
+
+    /// Run a single health monitoring cycle.
+    /// All monitors are evaluated and the supervisor is notified about liveness if the supervisor API cycle elapsed.
+    ///
+    /// - `now` - current time point, used to determine whether the supervisor shall be notified.
+    ///   Shall be provided by the time source set with [`HealthMonitorBuilder::with_time_source`].
+    ///
+    /// Returns `true` if all monitors are healthy or monitoring is paused.
+    /// Once any monitor fails, `false` is returned and no further evaluations or notifications are performed
+    /// until [`HealthMonitorDriver::rearm`] is called.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let status = self.health_state.status();
+        let scheduled = self.next_cycle.unwrap_or(now);
+        let (ok, next_cycle) = self
+            .monitoring_logic
+            .run_cycle(self.hmon_starting_point, scheduled, now);
+        self.next_cycle = Some(next_cycle);
+        if !ok {
+            if status == Status::Running {
+                info!("Monitoring logic failed, further ticks are ignored until re-armed.");
+            }
+            return false;
+        }
+
+        true
+    }
+
+    /// Stop the health monitoring logic.
+    ///
+    /// Status is changed to [`Status::Stopped`], further ticks are ignored.
+    ///
+    /// Returns [`HealthMonitorError::WrongState`] if already stopped.
+    pub fn stop(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.health_state.stop() {
+            return Err(HealthMonitorError::WrongState);
+        }
+        Ok(())
+    }
+
+    /// Pause the health monitoring logic.
+    /// Refer to [`HealthMonitor::pause`] for details.
+    pub fn pause(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.health_state.transition(Status::Running, Status::Disabled) {
+            return Err(HealthMonitorError::WrongState);
+        }
         Ok(())
     }
 
-    //TODO: Add possibility to run HM in the current thread - ie in main
+    /// Resume the health monitoring logic.
+    /// Refer to [`HealthMonitor::resume`] for details.
+    pub fn resume(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.health_state.request_rearm(Status::Disabled) {
+            return Err(HealthMonitorError::WrongState);
+        }
+        Ok(())
+    }
+
+    /// Re-arm monitors after a recoverable failure.
+    /// Refer to [`HealthMonitor::rearm`] for details.
+    pub fn rearm(&mut self) -> Result<(), HealthMonitorError> {
+        if !self.health_state.request_rearm(Status::Failed) {
+            return Err(HealthMonitorError::WrongState);
+        }
+        Ok(())
+    }
 }
 
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
+    use crate::clock::{TimeSource, VirtualClock};
     use crate::common::TimeRange;
+    use crate::custom::CustomEvaluationError;
+    use crate::cycle_timing::CycleStatistics;
     use crate::deadline::DeadlineMonitorBuilder;
     use crate::health_monitor::{HealthMonitorBuilder, HealthMonitorError};
     use crate::heartbeat::HeartbeatMonitorBuilder;
-    use crate::logic::LogicMonitorBuilder;
-    use crate::tag::{MonitorTag, StateTag};
+    use crate::logic::{LogicEvaluationError, LogicMonitorBuilder};
+    use crate::status::{FailureOrigin, MonitorHealth, Status};
+    use crate::tag::{DeadlineTag, MonitorTag, StateTag};
+    use crate::test_common::FlagMonitor;
+    use crate::tolerance::FailureTolerance;
+    use core::sync::atomic::{AtomicBool, Ordering};
     use core::time::Duration;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
 
     fn def_heartbeat_monitor_builder() -> HeartbeatMonitorBuilder {
         let range = TimeRange::new(Duration::from_millis(100), Duration::from_millis(200));
@@ -419,6 +1055,20 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn health_monitor_builder_build_custom_monitor_only() {
+        let custom_monitor_tag = MonitorTag::from("custom_monitor");
+        let monitor = FlagMonitor {
+            failed: AtomicBool::new(false),
+        };
+
+        let result = HealthMonitorBuilder::new()
+            .add_custom_monitor(custom_monitor_tag, monitor)
+            .with_failure_tolerance(custom_monitor_tag, FailureTolerance::new(2, 3, 1))
+            .build();
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn health_monitor_builder_build_invalid_cycles() {
         let result = HealthMonitorBuilder::new()
@@ -429,14 +1079,112 @@ mod tests {
     }
 
     #[test]
-    fn health_monitor_builder_build_no_monitors() {
-        let result = HealthMonitorBuilder::new().build();
-        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    fn health_monitor_builder_build_invalid_cycle_overrun_limit() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .with_cycle_overrun_limit(0)
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
     }
 
     #[test]
-    fn health_monitor_get_deadline_monitor_available() {
-        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+    fn health_monitor_builder_build_invalid_failure_tolerance() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .with_failure_tolerance(MonitorTag::from("deadline_monitor"), FailureTolerance::new(5, 3, 1))
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_failure_tolerance_unknown_monitor() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .with_failure_tolerance(MonitorTag::from("unknown_monitor"), FailureTolerance::new(2, 3, 1))
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_deadline_without_instances() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(
+                MonitorTag::from("deadline_monitor"),
+                DeadlineMonitorBuilder::new().add_concurrent_deadline(
+                    DeadlineTag::from("deadline"),
+                    TimeRange::new(Duration::from_millis(0), Duration::from_millis(100)),
+                    0,
+                ),
+            )
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_worker_stall_timeout() {
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(100))
+            .with_worker_stall_timeout(Duration::from_millis(100))
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_sub_millisecond_time_resolution() {
+        let result = HealthMonitorBuilder::new()
+            .add_heartbeat_monitor(
+                MonitorTag::from("heartbeat_monitor"),
+                HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_micros(800), Duration::from_micros(1200))),
+            )
+            .with_supervisor_api_cycle(Duration::from_millis(1))
+            .with_internal_processing_cycle(Duration::from_millis(1))
+            .with_time_resolution(Duration::from_micros(10))
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn health_monitor_builder_build_invalid_time_resolution() {
+        for resolution in [Duration::ZERO, Duration::from_nanos(999), Duration::from_micros(1001)] {
+            let result = HealthMonitorBuilder::new()
+                .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+                .with_time_resolution(resolution)
+                .build();
+            assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+        }
+    }
+
+    #[test]
+    fn health_monitor_builder_build_no_monitors() {
+        let result = HealthMonitorBuilder::new().build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_builder_build_duplicate_tag_across_kinds() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let result = HealthMonitorBuilder::new()
+            .add_deadline_monitor(monitor_tag, DeadlineMonitorBuilder::new())
+            .add_heartbeat_monitor(monitor_tag, def_heartbeat_monitor_builder())
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+
+        let result = HealthMonitorBuilder::new()
+            .add_logic_monitor(monitor_tag, def_logic_monitor_builder())
+            .add_custom_monitor(
+                monitor_tag,
+                FlagMonitor {
+                    failed: AtomicBool::new(false),
+                },
+            )
+            .build();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn health_monitor_get_deadline_monitor_available() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
         let deadline_monitor_builder = DeadlineMonitorBuilder::new();
         let mut health_monitor = HealthMonitorBuilder::new()
             .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
@@ -583,6 +1331,52 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn health_monitor_get_typed_logic_monitor_available() {
+        crate::logic_states! {
+            enum States {
+                State1 => [State2],
+                State2 => [State1],
+            }
+        }
+
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_logic_monitor(logic_monitor_tag, LogicMonitorBuilder::from_states::<States>())
+            .build()
+            .unwrap();
+
+        let logic_monitor = health_monitor
+            .get_typed_logic_monitor::<States>(logic_monitor_tag)
+            .unwrap();
+        assert_eq!(logic_monitor.transition(States::State2), Ok(States::State2));
+        assert!(health_monitor
+            .get_typed_logic_monitor::<States>(logic_monitor_tag)
+            .is_none());
+    }
+
+    #[test]
+    fn health_monitor_get_typed_logic_monitor_mismatched_states() {
+        crate::logic_states! {
+            enum States {
+                State1 => [State3],
+                State3 => [],
+            }
+        }
+
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_logic_monitor(logic_monitor_tag, def_logic_monitor_builder())
+            .build()
+            .unwrap();
+
+        assert!(health_monitor
+            .get_typed_logic_monitor::<States>(logic_monitor_tag)
+            .is_none());
+        // Monitor is not taken.
+        assert!(health_monitor.get_logic_monitor(logic_monitor_tag).is_some());
+    }
+
     #[test]
     fn health_monitor_get_logic_monitor_invalid_state() {
         let logic_monitor_tag = MonitorTag::from("logic_monitor");
@@ -620,7 +1414,35 @@ mod tests {
         let _heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
         let _logic_monitor = health_monitor.get_logic_monitor(logic_monitor_tag).unwrap();
 
+        assert_eq!(health_monitor.status(), Status::Disabled);
         health_monitor.start();
+        assert_eq!(health_monitor.status(), Status::Running);
+    }
+
+    #[test]
+    fn health_monitor_monitor_health_healthy() {
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_logic_monitor(logic_monitor_tag, def_logic_monitor_builder())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            health_monitor.monitor_health(logic_monitor_tag),
+            Some(MonitorHealth::Healthy)
+        );
+    }
+
+    #[test]
+    fn health_monitor_monitor_health_unknown() {
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_logic_monitor(MonitorTag::from("logic_monitor"), def_logic_monitor_builder())
+            .build()
+            .unwrap();
+
+        assert!(health_monitor
+            .monitor_health(MonitorTag::from("undefined_monitor"))
+            .is_none());
     }
 
     #[test]
@@ -639,4 +1461,521 @@ mod tests {
 
         health_monitor.start();
     }
+
+    #[test]
+    fn health_monitor_into_driver_succeeds() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new();
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let logic_monitor_builder = def_logic_monitor_builder();
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_logic_monitor(logic_monitor_tag, logic_monitor_builder)
+            .with_internal_processing_cycle(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let _logic_monitor = health_monitor.get_logic_monitor(logic_monitor_tag).unwrap();
+
+        let mut driver = health_monitor.into_driver().unwrap();
+        assert_eq!(driver.internal_processing_cycle(), Duration::from_millis(50));
+        assert!(driver.tick(Instant::now()));
+        assert!(driver.tick(Instant::now()));
+    }
+
+    #[test]
+    fn health_monitor_into_driver_monitors_not_taken() {
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+
+        let result = health_monitor.into_driver();
+        assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_driver_tick_monitor_failed() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline(
+            deadline_tag,
+            TimeRange::new(Duration::from_secs(10), Duration::from_secs(20)),
+        );
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .build()
+            .unwrap();
+
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+        assert!(driver.tick(Instant::now()));
+
+        // Finish deadline too early.
+        let mut deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        let handle = deadline.start().unwrap();
+        drop(handle);
+
+        assert!(!driver.tick(Instant::now()));
+        // Driver remains failed.
+        assert!(!driver.tick(Instant::now()));
+        assert_eq!(driver.status(), Status::Failed);
+    }
+
+    #[test]
+    fn health_monitor_driver_virtual_time_source() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let heartbeat_monitor_tag = MonitorTag::from("heartbeat_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline(
+            deadline_tag,
+            TimeRange::new(Duration::from_millis(100), Duration::from_millis(150)),
+        );
+        let time_source = VirtualClock::new();
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .add_heartbeat_monitor(heartbeat_monitor_tag, def_heartbeat_monitor_builder())
+            .with_time_source(time_source.clone())
+            .build()
+            .unwrap();
+
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let heartbeat_monitor = health_monitor.get_heartbeat_monitor(heartbeat_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+        assert!(driver.tick(time_source.now()));
+
+        // Deadline finished and heartbeat performed exactly at the range start.
+        let mut deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        let handle = deadline.start().unwrap();
+        time_source.advance(Duration::from_millis(100));
+        drop(handle);
+        heartbeat_monitor.heartbeat();
+        assert!(driver.tick(time_source.now()));
+
+        // Next heartbeat is expected until the range end.
+        time_source.advance(Duration::from_millis(200));
+        assert!(driver.tick(time_source.now()));
+        time_source.advance(Duration::from_millis(1));
+        assert!(!driver.tick(time_source.now()));
+        assert_eq!(driver.status(), Status::Failed);
+        assert!(matches!(
+            driver.monitor_health(heartbeat_monitor_tag),
+            Some(MonitorHealth::Failed(_))
+        ));
+        assert_eq!(
+            driver.monitor_health(deadline_monitor_tag),
+            Some(MonitorHealth::Healthy)
+        );
+    }
+
+    #[test]
+    fn health_monitor_driver_monitor_health_logic_failed() {
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_logic_monitor(logic_monitor_tag, def_logic_monitor_builder())
+            .build()
+            .unwrap();
+
+        let logic_monitor = health_monitor.get_logic_monitor(logic_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+        assert_eq!(driver.status(), Status::Running);
+
+        // Transition to unknown state.
+        let _ = logic_monitor.transition(StateTag::from("unknown"));
+
+        let now = Instant::now();
+        assert!(!driver.tick(now));
+        match driver.monitor_health(logic_monitor_tag) {
+            Some(MonitorHealth::Failed(failure)) => {
+                assert_eq!(failure.error, LogicEvaluationError::InvalidTransition.into());
+                assert_eq!(failure.time, now);
+                assert_eq!(
+                    failure.origin,
+                    Some(FailureOrigin::Transition(
+                        StateTag::from("state1"),
+                        StateTag::from("unknown")
+                    ))
+                );
+            },
+            other => panic!("unexpected monitor health: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn health_monitor_driver_error_handler_called() {
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let reported_errors = Arc::new(Mutex::new(Vec::new()));
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_logic_monitor(logic_monitor_tag, def_logic_monitor_builder())
+            .with_error_handler({
+                let reported_errors = reported_errors.clone();
+                move |monitor_tag, error| reported_errors.lock().unwrap().push((*monitor_tag, error))
+            })
+            .build()
+            .unwrap();
+
+        let logic_monitor = health_monitor.get_logic_monitor(logic_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+        assert!(driver.tick(Instant::now()));
+        assert!(reported_errors.lock().unwrap().is_empty());
+
+        // Transition to unknown state.
+        let _ = logic_monitor.transition(StateTag::from("unknown"));
+
+        assert!(!driver.tick(Instant::now()));
+        assert_eq!(
+            *reported_errors.lock().unwrap(),
+            vec![(logic_monitor_tag, LogicEvaluationError::InvalidTransition.into())]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn health_monitor_stop_succeeds() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        health_monitor.start();
+
+        assert!(health_monitor.stop().is_ok());
+        assert_eq!(health_monitor.status(), Status::Stopped);
+
+        // Stopped is a final state.
+        assert_eq!(health_monitor.stop(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.pause(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.resume(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.rearm(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.start_internal(), Err(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_not_started_wrong_state() {
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(MonitorTag::from("deadline_monitor"), DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+
+        assert_eq!(health_monitor.stop(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.pause(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.resume(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.rearm(), Err(HealthMonitorError::WrongState));
+        assert_eq!(health_monitor.status(), Status::Disabled);
+
+        // Re-arm request is not left pending.
+        health_monitor.health_state.set_status(Status::Failed);
+        assert_eq!(health_monitor.rearm(), Err(HealthMonitorError::WrongState));
+        assert!(!health_monitor.health_state.take_rearm_request());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn health_monitor_pause_resume_succeeds() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        health_monitor.start();
+
+        assert!(health_monitor.pause().is_ok());
+        assert_eq!(health_monitor.status(), Status::Disabled);
+        assert_eq!(health_monitor.pause(), Err(HealthMonitorError::WrongState));
+
+        assert!(health_monitor.resume().is_ok());
+        assert_eq!(health_monitor.status(), Status::Running);
+        assert_eq!(health_monitor.resume(), Err(HealthMonitorError::WrongState));
+
+        // Re-arm is only allowed after failure.
+        assert_eq!(health_monitor.rearm(), Err(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_driver_pause_resume() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let deadline_monitor_builder = DeadlineMonitorBuilder::new().add_deadline(
+            deadline_tag,
+            TimeRange::new(Duration::from_secs(10), Duration::from_secs(20)),
+        );
+
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, deadline_monitor_builder)
+            .build()
+            .unwrap();
+
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+
+        assert!(driver.pause().is_ok());
+        assert_eq!(driver.status(), Status::Disabled);
+
+        // Finish deadline too early while paused.
+        let mut deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        drop(deadline.start().unwrap());
+        assert!(driver.tick(Instant::now()));
+
+        // Error is discarded on resume.
+        assert!(driver.resume().is_ok());
+        assert!(driver.tick(Instant::now()));
+        assert_eq!(driver.status(), Status::Running);
+        assert_eq!(
+            driver.monitor_health(deadline_monitor_tag),
+            Some(MonitorHealth::Healthy)
+        );
+    }
+
+    #[test]
+    fn health_monitor_driver_custom_monitor_failed() {
+        let custom_monitor_tag = MonitorTag::from("custom_monitor");
+        let monitor = Arc::new(FlagMonitor {
+            failed: AtomicBool::new(false),
+        });
+        let reported_errors = Arc::new(Mutex::new(Vec::new()));
+        let health_monitor = HealthMonitorBuilder::new()
+            .add_custom_monitor(custom_monitor_tag, monitor.clone())
+            .with_error_handler({
+                let reported_errors = reported_errors.clone();
+                move |monitor_tag, error| reported_errors.lock().unwrap().push((*monitor_tag, error))
+            })
+            .build()
+            .unwrap();
+
+        let mut driver = health_monitor.into_driver().unwrap();
+        assert!(driver.tick(Instant::now()));
+        assert_eq!(driver.monitor_health(custom_monitor_tag), Some(MonitorHealth::Healthy));
+
+        monitor.failed.store(true, Ordering::Relaxed);
+        let now = Instant::now();
+        assert!(!driver.tick(now));
+        assert_eq!(driver.status(), Status::Failed);
+        match driver.monitor_health(custom_monitor_tag) {
+            Some(MonitorHealth::Failed(failure)) => {
+                assert_eq!(failure.error, CustomEvaluationError::new(7).into());
+                assert_eq!(failure.time, now);
+                assert_eq!(failure.origin, None);
+            },
+            other => panic!("unexpected monitor health: {other:?}"),
+        }
+        assert_eq!(
+            *reported_errors.lock().unwrap(),
+            vec![(custom_monitor_tag, CustomEvaluationError::new(7).into())]
+        );
+
+        // Rearm is forwarded to the custom monitor.
+        assert!(driver.rearm().is_ok());
+        assert!(driver.tick(Instant::now()));
+        assert_eq!(driver.monitor_health(custom_monitor_tag), Some(MonitorHealth::Healthy));
+    }
+
+    #[test]
+    fn health_monitor_driver_rearm_after_failure() {
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_logic_monitor(logic_monitor_tag, def_logic_monitor_builder())
+            .build()
+            .unwrap();
+
+        let logic_monitor = health_monitor.get_logic_monitor(logic_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+        assert_eq!(driver.rearm(), Err(HealthMonitorError::WrongState));
+
+        let _ = logic_monitor.transition(StateTag::from("unknown"));
+        assert!(!driver.tick(Instant::now()));
+        assert_eq!(driver.status(), Status::Failed);
+        assert_eq!(driver.pause(), Err(HealthMonitorError::WrongState));
+
+        // Logic monitor stays in the last valid state.
+        assert!(driver.rearm().is_ok());
+        assert!(driver.tick(Instant::now()));
+        assert_eq!(driver.status(), Status::Running);
+        assert_eq!(driver.monitor_health(logic_monitor_tag), Some(MonitorHealth::Healthy));
+        assert_eq!(logic_monitor.state(), Ok(StateTag::from("state1")));
+        assert!(logic_monitor.transition(StateTag::from("state2")).is_ok());
+        assert!(driver.tick(Instant::now()));
+    }
+
+    #[test]
+    fn health_monitor_driver_stop() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+
+        assert!(driver.stop().is_ok());
+        assert_eq!(driver.status(), Status::Stopped);
+        assert!(!driver.tick(Instant::now()));
+        assert_eq!(driver.stop(), Err(HealthMonitorError::WrongState));
+        assert_eq!(driver.resume(), Err(HealthMonitorError::WrongState));
+    }
+
+    #[test]
+    fn health_monitor_driver_cycle_statistics() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        assert_eq!(health_monitor.cycle_statistics(), CycleStatistics::default());
+        let mut driver = health_monitor.into_driver().unwrap();
+
+        // First tick sets the schedule.
+        let start = Instant::now();
+        assert!(driver.tick(start));
+        assert!(driver.tick(start + Duration::from_millis(50)));
+        let statistics = driver.cycle_statistics();
+        assert_eq!(statistics.cycles, 2);
+        assert_eq!(statistics.max_jitter, Duration::ZERO);
+        assert_eq!(statistics.overruns, 0);
+
+        // Tick late - cycles scheduled at 100 ms, 150 ms and 200 ms are missed.
+        assert!(driver.tick(start + Duration::from_millis(250)));
+        let statistics = driver.cycle_statistics();
+        assert_eq!(statistics.cycles, 3);
+        assert_eq!(statistics.max_jitter, Duration::from_millis(150));
+        assert_eq!(statistics.overruns, 1);
+        assert_eq!(statistics.missed_cycles, 3);
+        assert_eq!(driver.status(), Status::Running);
+    }
+
+    #[test]
+    fn health_monitor_driver_cycle_overrun_limit() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(50))
+            .with_cycle_overrun_limit(2)
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+
+        let start = Instant::now();
+        assert!(driver.tick(start));
+        // Next cycle scheduled at 50 ms, overrun.
+        assert!(driver.tick(start + Duration::from_millis(120)));
+        // Next cycle scheduled at 150 ms, overrun again.
+        assert!(!driver.tick(start + Duration::from_millis(220)));
+        assert_eq!(driver.status(), Status::Failed);
+
+        // Re-arm resets consecutive overruns.
+        assert!(driver.rearm().is_ok());
+        assert!(driver.tick(start + Duration::from_millis(320)));
+        assert_eq!(driver.status(), Status::Running);
+    }
+
+    #[test]
+    fn health_monitor_driver_failure_tolerance() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let deadline_tag = DeadlineTag::from("deadline");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(
+                deadline_monitor_tag,
+                DeadlineMonitorBuilder::new().add_deadline(
+                    deadline_tag,
+                    TimeRange::new(Duration::from_secs(1), Duration::from_secs(2)),
+                ),
+            )
+            .with_failure_tolerance(deadline_monitor_tag, FailureTolerance::new(2, 5, 1))
+            .build()
+            .unwrap();
+
+        let deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let mut driver = health_monitor.into_driver().unwrap();
+
+        // Deadline finished too early, failure is reported on each cycle.
+        let mut deadline = deadline_monitor.get_deadline(deadline_tag).unwrap();
+        drop(deadline.start().unwrap());
+
+        let start = Instant::now();
+        assert!(driver.tick(start));
+        assert_eq!(driver.status(), Status::Running);
+        assert!(matches!(
+            driver.monitor_health(deadline_monitor_tag),
+            Some(MonitorHealth::Tolerated(_))
+        ));
+
+        assert!(!driver.tick(start + Duration::from_millis(100)));
+        assert_eq!(driver.status(), Status::Failed);
+        assert!(matches!(
+            driver.monitor_health(deadline_monitor_tag),
+            Some(MonitorHealth::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn health_monitor_driver_worker_watchdog_stall() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(50))
+            .with_worker_stall_timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        let watchdog = health_monitor.worker_watchdog();
+        assert_eq!(watchdog.stall_timeout(), Duration::from_millis(200));
+        // Not started yet.
+        assert!(watchdog.check_at(Instant::now() + Duration::from_secs(1)));
+
+        let mut driver = health_monitor.into_driver().unwrap();
+        let start = Instant::now();
+        assert!(driver.tick(start));
+        assert!(driver.worker_watchdog().check_at(start + Duration::from_millis(150)));
+
+        // Tick is not called within stall timeout.
+        let now = start + Duration::from_millis(300);
+        assert!(!watchdog.check_at(now));
+        assert_eq!(driver.status(), Status::Failed);
+        assert!(!driver.tick(now));
+
+        // Monitoring continues after re-arm.
+        assert!(driver.rearm().is_ok());
+        assert!(driver.tick(now + Duration::from_millis(50)));
+        assert!(watchdog.check_at(now + Duration::from_millis(100)));
+        assert_eq!(driver.status(), Status::Running);
+    }
+
+    #[test]
+    // Test is flaky for Miri.
+    #[cfg_attr(miri, ignore)]
+    fn health_monitor_worker_watchdog_started() {
+        let deadline_monitor_tag = MonitorTag::from("deadline_monitor");
+        let mut health_monitor = HealthMonitorBuilder::new()
+            .add_deadline_monitor(deadline_monitor_tag, DeadlineMonitorBuilder::new())
+            .with_internal_processing_cycle(Duration::from_millis(10))
+            .with_supervisor_api_cycle(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let _deadline_monitor = health_monitor.get_deadline_monitor(deadline_monitor_tag).unwrap();
+        health_monitor.start();
+
+        let watchdog = health_monitor.worker_watchdog();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(30));
+            watchdog.check()
+        });
+        assert!(handle.join().unwrap());
+        assert_eq!(health_monitor.status(), Status::Running);
+        assert!(health_monitor.stop().is_ok());
+    }
 }
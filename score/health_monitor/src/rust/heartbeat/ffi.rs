@@ -40,6 +40,31 @@ pub extern "C" fn heartbeat_monitor_builder_create(
     FFICode::Success
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn heartbeat_monitor_builder_create_with_indications(
+    reporting_cycle_ms: u32,
+    min_indications: u16,
+    max_indications: u16,
+    heartbeat_monitor_builder_handle_out: *mut FFIHandle,
+) -> FFICode {
+    if heartbeat_monitor_builder_handle_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    if reporting_cycle_ms == 0 || min_indications > max_indications {
+        return FFICode::InvalidArgument;
+    }
+
+    let reporting_cycle = Duration::from_millis(reporting_cycle_ms as u64);
+    let heartbeat_monitor_builder =
+        HeartbeatMonitorBuilder::with_indications(reporting_cycle, min_indications, max_indications);
+    unsafe {
+        *heartbeat_monitor_builder_handle_out = Box::into_raw(Box::new(heartbeat_monitor_builder)).cast();
+    }
+
+    FFICode::Success
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle: FFIHandle) -> FFICode {
     if heartbeat_monitor_builder_handle.is_null() {
@@ -97,10 +122,10 @@ mod tests {
         health_monitor_destroy, health_monitor_get_heartbeat_monitor, FFICode, FFIHandle,
     };
     use crate::heartbeat::ffi::{
-        heartbeat_monitor_builder_create, heartbeat_monitor_builder_destroy, heartbeat_monitor_destroy,
-        heartbeat_monitor_heartbeat,
+        heartbeat_monitor_builder_create, heartbeat_monitor_builder_create_with_indications,
+        heartbeat_monitor_builder_destroy, heartbeat_monitor_destroy, heartbeat_monitor_heartbeat,
     };
-    use crate::tag::MonitorTag;
+    use crate::tag::{FFITag, MonitorTag};
     use core::ptr::null_mut;
 
     #[test]
@@ -134,6 +159,46 @@ mod tests {
         assert_eq!(heartbeat_monitor_builder_create_result, FFICode::NullParameter);
     }
 
+    #[test]
+    fn heartbeat_monitor_builder_create_with_indications_succeeds() {
+        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+
+        let heartbeat_monitor_builder_create_result = heartbeat_monitor_builder_create_with_indications(
+            1000,
+            2,
+            4,
+            &mut heartbeat_monitor_builder_handle as *mut FFIHandle,
+        );
+        assert!(!heartbeat_monitor_builder_handle.is_null());
+        assert_eq!(heartbeat_monitor_builder_create_result, FFICode::Success);
+
+        // Clean-up.
+        heartbeat_monitor_builder_destroy(heartbeat_monitor_builder_handle);
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_create_with_indications_invalid_arguments() {
+        let mut heartbeat_monitor_builder_handle: FFIHandle = null_mut();
+
+        for (reporting_cycle_ms, min_indications, max_indications) in [(0, 2, 4), (1000, 4, 2)] {
+            let heartbeat_monitor_builder_create_result = heartbeat_monitor_builder_create_with_indications(
+                reporting_cycle_ms,
+                min_indications,
+                max_indications,
+                &mut heartbeat_monitor_builder_handle as *mut FFIHandle,
+            );
+            assert!(heartbeat_monitor_builder_handle.is_null());
+            assert_eq!(heartbeat_monitor_builder_create_result, FFICode::InvalidArgument);
+        }
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_create_with_indications_null_builder() {
+        let heartbeat_monitor_builder_create_result =
+            heartbeat_monitor_builder_create_with_indications(1000, 2, 4, null_mut());
+        assert_eq!(heartbeat_monitor_builder_create_result, FFICode::NullParameter);
+    }
+
     #[test]
     fn heartbeat_monitor_builder_destroy_null_builder() {
         let heartbeat_monitor_builder_destroy_result = heartbeat_monitor_builder_destroy(null_mut());
@@ -158,7 +223,7 @@ mod tests {
         let _ = heartbeat_monitor_builder_create(100, 200, &mut heartbeat_monitor_builder_handle as *mut FFIHandle);
         let _ = health_monitor_builder_add_heartbeat_monitor(
             health_monitor_builder_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             heartbeat_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -170,7 +235,7 @@ mod tests {
         );
         let _ = health_monitor_get_heartbeat_monitor(
             health_monitor_handle,
-            &heartbeat_monitor_tag as *const MonitorTag,
+            &FFITag::from(heartbeat_monitor_tag) as *const FFITag,
             &mut heartbeat_monitor_handle as *mut FFIHandle,
         );
 
@@ -11,14 +11,15 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use crate::common::{
-    duration_to_int, time_offset, Monitor, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator, TimeRange,
-};
+use crate::clock::Clock;
+use crate::common::{Monitor, MonitorEvalHandle, MonitorEvaluator, OnErrorFn, TimeRange};
 use crate::health_monitor::HealthMonitorError;
-use crate::heartbeat::heartbeat_state::HeartbeatState;
+use crate::heartbeat::heartbeat_state::{HeartbeatState, MAX_COUNTER};
 use crate::log::{error, warn};
-use crate::protected_memory::ProtectedMemoryAllocator;
+use crate::protected_memory::{ProtectedBox, ProtectedMemoryAllocator};
 use crate::tag::MonitorTag;
+use core::future::Future;
+use core::ops::RangeInclusive;
 use core::sync::atomic::{AtomicU64, Ordering};
 use core::time::Duration;
 use score_log::ScoreDebug;
@@ -26,21 +27,51 @@ use std::sync::Arc;
 use std::time::Instant;
 
 /// Heartbeat evaluation errors.
+#[repr(u8)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ScoreDebug)]
-pub(crate) enum HeartbeatEvaluationError {
+pub enum HeartbeatEvaluationError {
     /// Finished too early.
-    TooEarly,
+    TooEarly = 1,
     /// Finished too late.
     TooLate,
     /// Multiple heartbeats observed.
     MultipleHeartbeats,
+    /// Heartbeat state memory is corrupted.
+    MemoryCorrupted,
+    /// Fewer heartbeats than required observed in a reporting cycle.
+    TooFewHeartbeats,
+    /// More heartbeats than allowed observed in a reporting cycle.
+    TooManyHeartbeats,
+}
+
+impl From<HeartbeatEvaluationError> for u8 {
+    fn from(value: HeartbeatEvaluationError) -> Self {
+        value as u8
+    }
+}
+
+/// Max number of heartbeats allowed in a reporting cycle.
+/// Counter saturates above this value, so exceeding it is still detected.
+pub(crate) const MAX_INDICATIONS: u16 = MAX_COUNTER - 1;
+
+/// Heartbeat supervision mode.
+#[derive(Clone, Copy, Debug)]
+enum HeartbeatMode {
+    /// Single heartbeat expected in time range since previous heartbeat.
+    Range(TimeRange),
+    /// Number of heartbeats in each reporting cycle expected in `min_indications..=max_indications`.
+    IndicationWindow {
+        reporting_cycle: Duration,
+        min_indications: u16,
+        max_indications: u16,
+    },
 }
 
 /// Builder for [`HeartbeatMonitor`].
 #[derive(Debug)]
 pub struct HeartbeatMonitorBuilder {
-    /// Time range between heartbeats.
-    range: TimeRange,
+    /// Heartbeat supervision mode.
+    mode: HeartbeatMode,
 }
 
 impl HeartbeatMonitorBuilder {
@@ -48,32 +79,102 @@ impl HeartbeatMonitorBuilder {
     ///
     /// - `range` - time range between heartbeats.
     pub fn new(range: TimeRange) -> Self {
-        Self { range }
+        Self {
+            mode: HeartbeatMode::Range(range),
+        }
+    }
+
+    /// Create a new [`HeartbeatMonitorBuilder`] counting heartbeats in fixed reporting cycles.
+    ///
+    /// - `reporting_cycle` - duration of a reporting cycle.
+    /// - `min_indications` - minimum number of heartbeats in a reporting cycle.
+    /// - `max_indications` - maximum number of heartbeats in a reporting cycle, up to 4094.
+    ///
+    /// Reporting cycle is closed on first evaluation after it elapsed, so its boundaries are aligned to
+    /// internal processing cycle. Exceeding `max_indications` is reported without waiting for the end of the cycle.
+    pub fn with_indications(reporting_cycle: Duration, min_indications: u16, max_indications: u16) -> Self {
+        Self {
+            mode: HeartbeatMode::IndicationWindow {
+                reporting_cycle,
+                min_indications,
+                max_indications,
+            },
+        }
+    }
+
+    /// Check parameters against the health monitor processing cycle.
+    ///
+    /// - `internal_processing_cycle` - health monitor processing cycle.
+    pub(crate) fn validate(&self, internal_processing_cycle: Duration) -> Result<(), HealthMonitorError> {
+        match self.mode {
+            HeartbeatMode::Range(range) => {
+                // Check range is valid.
+                if range.min.saturating_mul(2) <= internal_processing_cycle {
+                    error!(
+                        "Internal processing cycle duration ({} us) must be shorter than two shortest allowed ranges ({} us).",
+                        internal_processing_cycle.as_micros() as u64,
+                        range.min.as_micros() as u64
+                    );
+                    return Err(HealthMonitorError::InvalidArgument);
+                }
+            },
+            HeartbeatMode::IndicationWindow {
+                reporting_cycle,
+                min_indications,
+                max_indications,
+            } => {
+                // Check reporting cycle is valid.
+                if reporting_cycle < internal_processing_cycle {
+                    error!(
+                        "Reporting cycle duration ({} us) must not be shorter than internal processing cycle duration ({} us).",
+                        reporting_cycle.as_micros() as u64,
+                        internal_processing_cycle.as_micros() as u64
+                    );
+                    return Err(HealthMonitorError::InvalidArgument);
+                }
+                // Check indications are valid.
+                if min_indications > max_indications || max_indications > MAX_INDICATIONS {
+                    error!(
+                        "Invalid indications range ({}..={}), max indications must not exceed {}.",
+                        min_indications, max_indications, MAX_INDICATIONS
+                    );
+                    return Err(HealthMonitorError::InvalidArgument);
+                }
+            },
+        }
+        Ok(())
     }
 
     /// Build the [`HeartbeatMonitor`].
     ///
     /// - `monitor_tag` - tag of this monitor.
     /// - `internal_processing_cycle` - health monitor processing cycle.
-    /// - `_allocator` - protected memory allocator.
+    /// - `clock` - clock shared by all monitors.
+    /// - `allocator` - protected memory allocator.
     pub(crate) fn build(
         self,
         monitor_tag: MonitorTag,
         internal_processing_cycle: Duration,
-        _allocator: &ProtectedMemoryAllocator,
+        clock: Clock,
+        allocator: &ProtectedMemoryAllocator,
     ) -> Result<HeartbeatMonitor, HealthMonitorError> {
-        // Check range is valid.
-        let range_min_ms = self.range.min.as_millis() as u64;
-        let internal_processing_cycle_ms = internal_processing_cycle.as_millis() as u64;
-        if range_min_ms * 2 <= internal_processing_cycle_ms {
-            error!(
-                "Internal processing cycle duration ({} ms) must be shorter than two shortest allowed ranges ({} ms).",
-                internal_processing_cycle_ms, range_min_ms
-            );
-            return Err(HealthMonitorError::InvalidArgument);
-        }
+        self.validate(internal_processing_cycle)?;
+        let mode = match self.mode {
+            HeartbeatMode::Range(range) => {
+                InternalMode::Range(InternalRange::new(clock.ticks(range.min), clock.ticks(range.max)))
+            },
+            HeartbeatMode::IndicationWindow {
+                reporting_cycle,
+                min_indications,
+                max_indications,
+            } => InternalMode::IndicationWindow {
+                reporting_cycle: clock.ticks(reporting_cycle),
+                min_indications,
+                max_indications,
+            },
+        };
 
-        let inner = Arc::new(HeartbeatMonitorInner::new(monitor_tag, self.range));
+        let inner = Arc::new(HeartbeatMonitorInner::new(monitor_tag, mode, clock, allocator));
         Ok(HeartbeatMonitor::new(inner))
     }
 }
@@ -93,6 +194,22 @@ impl HeartbeatMonitor {
     pub fn heartbeat(&self) {
         self.inner.heartbeat()
     }
+
+    /// Provide a heartbeat each time the future created by `tick` completes.
+    ///
+    /// Intended to be run as a periodic async task, `tick` is typically a runtime-specific timer,
+    /// e.g., `|| tokio::time::sleep(period)`. No specific async runtime is required.
+    /// Returned future never completes, drop it to stop providing heartbeats.
+    pub async fn run_heartbeats<F, Fut>(&self, mut tick: F)
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        loop {
+            tick().await;
+            self.heartbeat();
+        }
+    }
 }
 
 impl Monitor for HeartbeatMonitor {
@@ -102,15 +219,87 @@ impl Monitor for HeartbeatMonitor {
 }
 
 impl MonitorEvaluator for HeartbeatMonitorInner {
-    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError)) {
+    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut OnErrorFn<'_>) {
+        self.evaluate_at(self.clock.ticks_at(hmon_starting_point), self.clock.now(), on_error);
+    }
+
+    fn rearm(&self) {
+        self.rearm_at(self.clock.now());
+    }
+}
+
+impl HeartbeatMonitorInner {
+    fn new(monitor_tag: MonitorTag, mode: InternalMode, clock: Clock, allocator: &ProtectedMemoryAllocator) -> Self {
+        let heartbeat_state = allocator.allocate(HeartbeatState::new());
+        Self {
+            monitor_tag,
+            mode,
+            clock,
+            heartbeat_state,
+            start_timestamp: AtomicU64::new(0),
+        }
+    }
+
+    /// Provide a heartbeat.
+    fn heartbeat(&self) {
+        self.heartbeat_at(self.clock.now());
+    }
+
+    /// Provide a heartbeat at provided time point in clock ticks.
+    fn heartbeat_at(&self, monitor_now: u64) {
+        // Set heartbeat timestamp and update counter.
+        let _ = self.heartbeat_state.update(|mut current_state| {
+            current_state.set_heartbeat_timestamp(monitor_now);
+            current_state.increment_counter();
+            Some(current_state)
+        });
+    }
+
+    /// Evaluate heartbeat state.
+    ///
+    /// - `offset` - HMON starting point in clock ticks.
+    /// - `monitor_now` - current time point in clock ticks.
+    /// - `on_error` - error handling.
+    fn evaluate_at(&self, offset: u64, monitor_now: u64, on_error: &mut OnErrorFn<'_>) {
+        // Heartbeat state cannot be trusted if memory is corrupted.
+        if !self.heartbeat_state.is_intact() {
+            error!("Heartbeat monitor ({:?}) state memory is corrupted!", self.monitor_tag);
+            on_error(
+                &self.monitor_tag,
+                HeartbeatEvaluationError::MemoryCorrupted.into(),
+                None,
+            );
+            return;
+        }
+
         // Get cycle start timestamp.
         let start_timestamp = self.start_timestamp.load(Ordering::Acquire);
 
-        // Get current timestamp, with offset to HMON time.
-        let offset = time_offset(hmon_starting_point, self.monitor_starting_point)
-            .expect("HMON starting point is earlier than monitor starting point");
-        let monitor_now = offset + duration_to_int::<u64>(hmon_starting_point.elapsed());
+        match self.mode {
+            InternalMode::Range(ref range) => {
+                self.evaluate_range(range, start_timestamp, offset, monitor_now, on_error)
+            },
+            InternalMode::IndicationWindow {
+                reporting_cycle,
+                min_indications,
+                max_indications,
+            } => {
+                let start_timestamp = if start_timestamp > 0 { start_timestamp } else { offset };
+                let window_end = start_timestamp.saturating_add(reporting_cycle);
+                self.evaluate_window(window_end, min_indications..=max_indications, monitor_now, on_error)
+            },
+        }
+    }
 
+    /// Evaluate heartbeat state in [`InternalMode::Range`].
+    fn evaluate_range(
+        &self,
+        range: &InternalRange,
+        start_timestamp: u64,
+        offset: u64,
+        monitor_now: u64,
+        on_error: &mut OnErrorFn<'_>,
+    ) {
         // Load and reset current monitor state.
         let snapshot = self.heartbeat_state.reset();
 
@@ -123,14 +312,18 @@ impl MonitorEvaluator for HeartbeatMonitorInner {
         let heartbeat_timestamp = snapshot.heartbeat_timestamp();
 
         // Get allowed time range as absolute values.
-        let range = self.range.offset(start_timestamp);
+        let range = range.offset(start_timestamp);
 
         // Check current counter state.
         let counter = snapshot.counter();
         // Disallow multiple heartbeats in same heartbeat cycle.
         if counter > 1 {
             warn!("Multiple heartbeats detected");
-            on_error(&self.monitor_tag, HeartbeatEvaluationError::MultipleHeartbeats.into());
+            on_error(
+                &self.monitor_tag,
+                HeartbeatEvaluationError::MultipleHeartbeats.into(),
+                None,
+            );
             return;
         }
         // Handle no heartbeats.
@@ -140,7 +333,7 @@ impl MonitorEvaluator for HeartbeatMonitorInner {
             if monitor_now > range.max {
                 let offset = monitor_now - range.max;
                 warn!("No heartbeat detected, observed after range: {}", offset);
-                on_error(&self.monitor_tag, HeartbeatEvaluationError::TooLate.into());
+                on_error(&self.monitor_tag, HeartbeatEvaluationError::TooLate.into(), None);
             }
             // Either way - execution is stopped here.
             return;
@@ -151,13 +344,13 @@ impl MonitorEvaluator for HeartbeatMonitorInner {
         if heartbeat_timestamp < range.min {
             let offset = range.min - heartbeat_timestamp;
             warn!("Heartbeat occurred too early, offset to range: {}", offset);
-            on_error(&self.monitor_tag, HeartbeatEvaluationError::TooEarly.into());
+            on_error(&self.monitor_tag, HeartbeatEvaluationError::TooEarly.into(), None);
         }
         // Heartbeat after allowed range.
         else if heartbeat_timestamp > range.max {
             let offset = heartbeat_timestamp - range.max;
             warn!("Heartbeat occurred too late, offset to range: {}", offset);
-            on_error(&self.monitor_tag, HeartbeatEvaluationError::TooLate.into());
+            on_error(&self.monitor_tag, HeartbeatEvaluationError::TooLate.into(), None);
         }
         // Heartbeat in allowed state.
         else {
@@ -165,6 +358,72 @@ impl MonitorEvaluator for HeartbeatMonitorInner {
             self.start_timestamp.store(heartbeat_timestamp, Ordering::Release);
         }
     }
+
+    /// Evaluate heartbeat state in [`InternalMode::IndicationWindow`].
+    ///
+    /// - `window_end` - end of current reporting cycle in clock ticks.
+    /// - `indications` - allowed number of heartbeats in a reporting cycle.
+    fn evaluate_window(
+        &self,
+        window_end: u64,
+        indications: RangeInclusive<u16>,
+        monitor_now: u64,
+        on_error: &mut OnErrorFn<'_>,
+    ) {
+        // Reporting cycle is still ongoing - only too many heartbeats can be detected.
+        if monitor_now < window_end {
+            let counter = self.heartbeat_state.snapshot().counter();
+            if counter > *indications.end() {
+                warn!("Too many heartbeats detected in ongoing reporting cycle: {}", counter);
+                on_error(
+                    &self.monitor_tag,
+                    HeartbeatEvaluationError::TooManyHeartbeats.into(),
+                    None,
+                );
+                // Begin a new reporting cycle to avoid reporting same heartbeats again.
+                self.rearm_at(monitor_now);
+            }
+            return;
+        }
+
+        // Close reporting cycle and begin a new one.
+        let counter = self.heartbeat_state.reset().counter();
+        self.start_timestamp.store(monitor_now, Ordering::Release);
+
+        if counter < *indications.start() {
+            warn!("Too few heartbeats detected in reporting cycle: {}", counter);
+            on_error(
+                &self.monitor_tag,
+                HeartbeatEvaluationError::TooFewHeartbeats.into(),
+                None,
+            );
+        } else if counter > *indications.end() {
+            warn!("Too many heartbeats detected in reporting cycle: {}", counter);
+            on_error(
+                &self.monitor_tag,
+                HeartbeatEvaluationError::TooManyHeartbeats.into(),
+                None,
+            );
+        }
+    }
+
+    /// Discard current heartbeat state and begin a new cycle at provided time point in clock ticks.
+    fn rearm_at(&self, monitor_now: u64) {
+        let _ = self.heartbeat_state.reset();
+        self.start_timestamp.store(monitor_now, Ordering::Release);
+    }
+}
+
+/// Heartbeat supervision mode using clock ticks.
+enum InternalMode {
+    /// Refer to [`HeartbeatMode::Range`].
+    Range(InternalRange),
+    /// Refer to [`HeartbeatMode::IndicationWindow`].
+    IndicationWindow {
+        reporting_cycle: u64,
+        min_indications: u16,
+        max_indications: u16,
+    },
 }
 
 /// Time range using [`u64`].
@@ -182,23 +441,10 @@ impl InternalRange {
     }
 
     /// Create range with values offset by timestamp.
+    /// Values are saturated instead of overflowing.
     fn offset(&self, timestamp: u64) -> Self {
-        let min = self
-            .min
-            .checked_add(timestamp)
-            .expect("offset min overflow in InternalRange");
-        let max = self
-            .max
-            .checked_add(timestamp)
-            .expect("offset max overflow in InternalRange");
-        Self::new(min, max)
-    }
-}
-
-impl From<TimeRange> for InternalRange {
-    fn from(value: TimeRange) -> Self {
-        let min = duration_to_int(value.min);
-        let max = duration_to_int(value.max);
+        let min = self.min.saturating_add(timestamp);
+        let max = self.max.saturating_add(timestamp);
         Self::new(min, max)
     }
 }
@@ -207,15 +453,16 @@ pub(crate) struct HeartbeatMonitorInner {
     /// Tag of this monitor.
     monitor_tag: MonitorTag,
 
-    /// Time range between heartbeats.
-    range: InternalRange,
+    /// Heartbeat supervision mode.
+    mode: InternalMode,
 
-    /// Monitor starting point.
-    monitor_starting_point: Instant,
+    /// Clock shared by all monitors.
+    clock: Clock,
 
     /// Current heartbeat state.
-    /// Contains data in relation to [`Self::monitor_starting_point`].
-    heartbeat_state: HeartbeatState,
+    /// Contains timestamps in [`Self::clock`] ticks.
+    /// Placed in protected memory, integrity is checked on each evaluation.
+    heartbeat_state: ProtectedBox<HeartbeatState>,
 
     /// Current cycle start timestamp.
     ///
@@ -224,33 +471,6 @@ pub(crate) struct HeartbeatMonitorInner {
     start_timestamp: AtomicU64,
 }
 
-impl HeartbeatMonitorInner {
-    fn new(monitor_tag: MonitorTag, range: TimeRange) -> Self {
-        let monitor_starting_point = Instant::now();
-        let heartbeat_state = HeartbeatState::new();
-        Self {
-            monitor_tag,
-            range: InternalRange::from(range),
-            monitor_starting_point,
-            heartbeat_state,
-            start_timestamp: AtomicU64::new(0),
-        }
-    }
-
-    /// Provide a heartbeat.
-    fn heartbeat(&self) {
-        // Get current timestamp.
-        let monitor_now = duration_to_int(self.monitor_starting_point.elapsed());
-
-        // Set heartbeat timestamp and update counter.
-        let _ = self.heartbeat_state.update(|mut current_state| {
-            current_state.set_heartbeat_timestamp(monitor_now);
-            current_state.increment_counter();
-            Some(current_state)
-        });
-    }
-}
-
 #[cfg(test)]
 mod test_common {
     use crate::common::TimeRange;
@@ -274,13 +494,18 @@ mod test_common {
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::{Monitor, MonitorEvaluationError, MonitorEvaluator, TimeRange};
+    use crate::clock::{Clock, TimeSource, VirtualClock, DEFAULT_CLOCK_RESOLUTION};
+    use crate::common::{Monitor, MonitorEvaluationError, MonitorEvaluator, OnErrorFn, TimeRange};
     use crate::health_monitor::HealthMonitorError;
     use crate::heartbeat::heartbeat_monitor::test_common::{range_from_ms, sleep_until, TAG};
+    use crate::heartbeat::heartbeat_state::MAX_COUNTER;
     use crate::heartbeat::{HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder};
     use crate::protected_memory::ProtectedMemoryAllocator;
     use crate::tag::MonitorTag;
+    use crate::test_common::YieldOnce;
+    use core::future::Future;
     use core::sync::atomic::{AtomicBool, Ordering};
+    use core::task::{Context, Waker};
     use core::time::Duration;
     use std::sync::Arc;
     use std::thread::{sleep, spawn};
@@ -291,8 +516,13 @@ mod tests {
         let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
         let monitor_tag = MonitorTag::from("heartbeat_monitor");
         let internal_processing_cycle = Duration::from_millis(100);
-        let allocator = ProtectedMemoryAllocator {};
-        let result = HeartbeatMonitorBuilder::new(range).build(monitor_tag, internal_processing_cycle, &allocator);
+        let allocator = ProtectedMemoryAllocator::new();
+        let result = HeartbeatMonitorBuilder::new(range).build(
+            monitor_tag,
+            internal_processing_cycle,
+            Clock::default(),
+            &allocator,
+        );
         assert!(result.is_ok());
     }
 
@@ -301,48 +531,211 @@ mod tests {
         let range = TimeRange::new(Duration::from_millis(500), Duration::from_millis(1000));
         let monitor_tag = MonitorTag::from("heartbeat_monitor");
         let internal_processing_cycle = Duration::from_millis(1000);
-        let allocator = ProtectedMemoryAllocator {};
-        let result = HeartbeatMonitorBuilder::new(range).build(monitor_tag, internal_processing_cycle, &allocator);
+        let allocator = ProtectedMemoryAllocator::new();
+        let result = HeartbeatMonitorBuilder::new(range).build(
+            monitor_tag,
+            internal_processing_cycle,
+            Clock::default(),
+            &allocator,
+        );
         assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
     }
 
-    fn create_monitor_single_cycle(range: TimeRange) -> HeartbeatMonitor {
+    fn create_monitor_single_cycle(range: TimeRange) -> (HeartbeatMonitor, VirtualClock) {
         let monitor_tag = MonitorTag::from(TAG);
         let internal_processing_cycle = Duration::from_millis(1);
-        let allocator = ProtectedMemoryAllocator {};
-        HeartbeatMonitorBuilder::new(range)
-            .build(monitor_tag, internal_processing_cycle, &allocator)
-            .unwrap()
+        let allocator = ProtectedMemoryAllocator::new();
+        let time_source = VirtualClock::new();
+        let clock = Clock::new(DEFAULT_CLOCK_RESOLUTION, Arc::new(time_source.clone()));
+        let monitor = HeartbeatMonitorBuilder::new(range)
+            .build(monitor_tag, internal_processing_cycle, clock, &allocator)
+            .unwrap();
+        (monitor, time_source)
+    }
+
+    /// Advance `time_source` until `target` time since `start` is reached.
+    fn advance_until(time_source: &VirtualClock, target: Duration, start: Instant) {
+        let elapsed = time_source.now().saturating_duration_since(start);
+        time_source.advance(target.saturating_sub(elapsed));
+    }
+
+    #[test]
+    fn heartbeat_monitor_run_heartbeats() {
+        let range = range_from_ms(80, 120);
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut task = Box::pin(monitor.run_heartbeats(|| {
+            time_source.advance(Duration::from_millis(90));
+            YieldOnce::new(())
+        }));
+
+        // First poll only starts waiting for a tick.
+        assert!(task.as_mut().poll(&mut cx).is_pending());
+        for _ in 0..5 {
+            // Tick is completed and heartbeat is provided.
+            assert!(task.as_mut().poll(&mut cx).is_pending());
+            monitor
+                .get_eval_handle()
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                    panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+                });
+        }
+
+        // Heartbeats are no longer provided after the task is dropped.
+        drop(task);
+        time_source.advance(Duration::from_millis(100));
+        let mut errors = Vec::new();
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        assert_eq!(errors, vec![HeartbeatEvaluationError::TooLate.into()]);
+    }
+
+    fn create_monitor_with_indications(
+        reporting_cycle: Duration,
+        min_indications: u16,
+        max_indications: u16,
+    ) -> (HeartbeatMonitor, VirtualClock) {
+        let time_source = VirtualClock::new();
+        let clock = Clock::new(DEFAULT_CLOCK_RESOLUTION, Arc::new(time_source.clone()));
+        let monitor = HeartbeatMonitorBuilder::with_indications(reporting_cycle, min_indications, max_indications)
+            .build(
+                MonitorTag::from(TAG),
+                Duration::from_millis(10),
+                clock,
+                &ProtectedMemoryAllocator::new(),
+            )
+            .unwrap();
+        (monitor, time_source)
+    }
+
+    fn evaluate_errors_now(monitor: &HeartbeatMonitor, hmon_starting_point: Instant) -> Vec<MonitorEvaluationError> {
+        let mut errors = Vec::new();
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+        errors
+    }
+
+    #[test]
+    fn heartbeat_monitor_builder_with_indications_invalid() {
+        let internal_processing_cycle = Duration::from_millis(100);
+        let allocator = ProtectedMemoryAllocator::new();
+        for (reporting_cycle, min_indications, max_indications) in [
+            (Duration::from_millis(50), 1, 2),
+            (Duration::from_millis(500), 3, 2),
+            (Duration::from_millis(500), 1, MAX_COUNTER),
+        ] {
+            let result = HeartbeatMonitorBuilder::with_indications(reporting_cycle, min_indications, max_indications)
+                .build(
+                    MonitorTag::from(TAG),
+                    internal_processing_cycle,
+                    Clock::default(),
+                    &allocator,
+                );
+            assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+        }
+    }
+
+    #[test]
+    fn heartbeat_monitor_indications_in_range() {
+        let (monitor, time_source) = create_monitor_with_indications(Duration::from_millis(100), 2, 4);
+        let hmon_starting_point = time_source.now();
+
+        for beats in [2, 3, 4] {
+            for _ in 0..beats {
+                time_source.advance(Duration::from_millis(10));
+                monitor.heartbeat();
+                assert!(evaluate_errors_now(&monitor, hmon_starting_point).is_empty());
+            }
+            // Close reporting cycle.
+            time_source.advance(Duration::from_millis(100 - 10 * beats));
+            assert!(evaluate_errors_now(&monitor, hmon_starting_point).is_empty());
+        }
+    }
+
+    #[test]
+    fn heartbeat_monitor_indications_too_few() {
+        let (monitor, time_source) = create_monitor_with_indications(Duration::from_millis(100), 2, 4);
+        let hmon_starting_point = time_source.now();
+
+        monitor.heartbeat();
+        time_source.advance(Duration::from_millis(99));
+        assert!(evaluate_errors_now(&monitor, hmon_starting_point).is_empty());
+
+        time_source.advance(Duration::from_millis(1));
+        assert_eq!(
+            evaluate_errors_now(&monitor, hmon_starting_point),
+            vec![HeartbeatEvaluationError::TooFewHeartbeats.into()]
+        );
+    }
+
+    #[test]
+    fn heartbeat_monitor_indications_too_many_in_ongoing_cycle() {
+        let (monitor, time_source) = create_monitor_with_indications(Duration::from_millis(100), 2, 4);
+        let hmon_starting_point = time_source.now();
+
+        for _ in 0..5 {
+            monitor.heartbeat();
+        }
+        time_source.advance(Duration::from_millis(10));
+        assert_eq!(
+            evaluate_errors_now(&monitor, hmon_starting_point),
+            vec![HeartbeatEvaluationError::TooManyHeartbeats.into()]
+        );
+
+        // New reporting cycle is started.
+        for _ in 0..2 {
+            monitor.heartbeat();
+        }
+        time_source.advance(Duration::from_millis(100));
+        assert!(evaluate_errors_now(&monitor, hmon_starting_point).is_empty());
+    }
+
+    #[test]
+    fn heartbeat_monitor_indications_counter_saturated() {
+        let (monitor, time_source) = create_monitor_with_indications(Duration::from_millis(100), 0, MAX_COUNTER - 1);
+        let hmon_starting_point = time_source.now();
+
+        for _ in 0..=MAX_COUNTER as usize {
+            monitor.heartbeat();
+        }
+        time_source.advance(Duration::from_millis(100));
+        assert_eq!(
+            evaluate_errors_now(&monitor, hmon_starting_point),
+            vec![HeartbeatEvaluationError::TooManyHeartbeats.into()]
+        );
     }
 
     #[test]
     fn heartbeat_monitor_no_beat_evaluate_early() {
         let range = range_from_ms(80, 120);
-        let monitor = create_monitor_single_cycle(range);
-        let hmon_starting_point = Instant::now();
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
 
         // No beat happened, no error is expected.
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
     }
 
     #[test]
-    #[cfg_attr(miri, ignore)]
     fn heartbeat_monitor_no_beat_evaluate_in_range() {
         let range = range_from_ms(80, 120);
-        let monitor = create_monitor_single_cycle(range);
-        let hmon_starting_point = Instant::now();
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
 
         // Wait until middle of range.
-        sleep_until(Duration::from_millis(100), hmon_starting_point);
+        advance_until(&time_source, Duration::from_millis(100), hmon_starting_point);
 
         // No beat happened, no error is expected.
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
     }
@@ -350,41 +743,37 @@ mod tests {
     #[test]
     fn heartbeat_monitor_no_beat_evaluate_late() {
         let range = range_from_ms(80, 120);
-        let monitor = create_monitor_single_cycle(range);
-        let hmon_starting_point = Instant::now();
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
 
         // Wait until late.
-        sleep_until(Duration::from_millis(150), hmon_starting_point);
+        advance_until(&time_source, Duration::from_millis(150), hmon_starting_point);
 
         // No beat happened, too late error is expected.
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
             });
     }
 
-    fn beat_eval_test(
-        beat_time: Duration,
-        eval_time: Duration,
-        on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError),
-    ) {
+    fn beat_eval_test(beat_time: Duration, eval_time: Duration, on_error: &mut OnErrorFn<'_>) {
         let range = range_from_ms(80, 120);
-        let monitor = create_monitor_single_cycle(range);
-        let hmon_starting_point = Instant::now();
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
 
         // Wait and beat.
-        sleep_until(beat_time, hmon_starting_point);
+        advance_until(&time_source, beat_time, hmon_starting_point);
         monitor.heartbeat();
 
         // Wait and evaluate.
-        sleep_until(eval_time, hmon_starting_point);
+        advance_until(&time_source, eval_time, hmon_starting_point);
         monitor.get_eval_handle().evaluate(hmon_starting_point, on_error);
     }
 
     fn beat_early_test(eval_time: Duration) {
-        beat_eval_test(Duration::from_millis(25), eval_time, &mut |monitor_tag, error| {
+        beat_eval_test(Duration::from_millis(25), eval_time, &mut |monitor_tag, error, _| {
             assert_eq!(*monitor_tag, MonitorTag::from(TAG));
             assert_eq!(error, HeartbeatEvaluationError::TooEarly.into());
         });
@@ -406,7 +795,7 @@ mod tests {
     }
 
     fn beat_in_range_test(eval_time: Duration) {
-        beat_eval_test(Duration::from_millis(90), eval_time, &mut |monitor_tag, error| {
+        beat_eval_test(Duration::from_millis(90), eval_time, &mut |monitor_tag, error, _| {
             panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
         });
     }
@@ -426,7 +815,7 @@ mod tests {
         beat_eval_test(
             Duration::from_millis(150),
             Duration::from_millis(200),
-            &mut |monitor_tag, error| {
+            &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
             },
@@ -435,21 +824,21 @@ mod tests {
 
     fn multiple_beats_eval_test(beat_time: Duration, eval_time: Duration) {
         let range = range_from_ms(80, 120);
-        let monitor = create_monitor_single_cycle(range);
-        let hmon_starting_point = Instant::now();
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
 
         // Wait and beat.
-        sleep_until(beat_time, hmon_starting_point);
+        advance_until(&time_source, beat_time, hmon_starting_point);
         const NUM_BEATS: usize = 10;
         for _ in 0..NUM_BEATS {
             monitor.heartbeat();
         }
 
         // Wait and evaluate.
-        sleep_until(eval_time, hmon_starting_point);
+        advance_until(&time_source, eval_time, hmon_starting_point);
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::MultipleHeartbeats.into());
             });
@@ -488,9 +877,9 @@ mod tests {
     fn create_monitor_multiple_cycles(cycle: Duration) -> Arc<HeartbeatMonitor> {
         let range = range_from_ms(80, 120);
         let monitor_tag = MonitorTag::from(TAG);
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor = HeartbeatMonitorBuilder::new(range)
-            .build(monitor_tag, cycle, &allocator)
+            .build(monitor_tag, cycle, Clock::default(), &allocator)
             .unwrap();
         Arc::new(monitor)
     }
@@ -529,7 +918,7 @@ mod tests {
         while !heartbeat_finished.load(Ordering::Acquire) {
             sleep(cycle);
             // Too early error is expected.
-            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooEarly.into());
             });
@@ -564,7 +953,7 @@ mod tests {
         while !heartbeat_finished.load(Ordering::Acquire) {
             sleep(cycle);
             // No error is expected.
-            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
         }
@@ -606,7 +995,7 @@ mod tests {
         while !heartbeat_finished.load(Ordering::Acquire) {
             sleep(cycle);
             // No heartbeat or too late error is expected.
-            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            eval_handle.evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                 assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
             });
@@ -643,7 +1032,7 @@ mod tests {
             // It is normally used once before evaluation cycle starts.
             monitor
                 .get_eval_handle()
-                .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                     panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
                 });
         }
@@ -654,28 +1043,169 @@ mod tests {
     #[test]
     fn heartbeat_monitor_timestamp_offset() {
         let range = range_from_ms(80, 120);
-        let monitor = create_monitor_single_cycle(range);
+        let (monitor, time_source) = create_monitor_single_cycle(range);
 
         // Move away monitor creation and HMON starting point.
-        sleep(Duration::from_millis(300));
-        let hmon_starting_point = Instant::now();
+        time_source.advance(Duration::from_millis(300));
+        let hmon_starting_point = time_source.now();
 
         // Wait and beat.
-        sleep_until(Duration::from_millis(90), hmon_starting_point);
+        advance_until(&time_source, Duration::from_millis(90), hmon_starting_point);
         monitor.heartbeat();
 
         // Wait and evaluate.
-        sleep_until(Duration::from_millis(100), hmon_starting_point);
+        advance_until(&time_source, Duration::from_millis(100), hmon_starting_point);
         monitor
             .get_eval_handle()
-            .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                 panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
             });
     }
+
+    #[test]
+    fn heartbeat_monitor_memory_corrupted() {
+        let range = range_from_ms(80, 120);
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
+        monitor.inner.heartbeat_state.corrupt_canary();
+
+        let mut reported = false;
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |_, error, _| {
+                assert_eq!(error, HeartbeatEvaluationError::MemoryCorrupted.into());
+                reported = true;
+            });
+        assert!(reported);
+    }
+
+    #[test]
+    fn heartbeat_monitor_rearm_after_late() {
+        let range = range_from_ms(80, 120);
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
+
+        // Wait until late, then re-arm.
+        advance_until(&time_source, Duration::from_millis(150), hmon_starting_point);
+        monitor.get_eval_handle().rearm();
+
+        // New cycle begins at re-arm, no error is expected.
+        advance_until(&time_source, Duration::from_millis(160), hmon_starting_point);
+        monitor
+            .get_eval_handle()
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
+    }
+
+    #[test]
+    fn heartbeat_monitor_exact_boundaries() {
+        let range = range_from_ms(80, 120);
+
+        // Heartbeat at range start is accepted, one tick earlier is too early.
+        for (beat_time, expected) in [(79, vec![HeartbeatEvaluationError::TooEarly.into()]), (80, vec![])] {
+            let (monitor, time_source) = create_monitor_single_cycle(range);
+            let hmon_starting_point = time_source.now();
+            time_source.advance(Duration::from_millis(beat_time));
+            monitor.heartbeat();
+
+            let mut errors = vec![];
+            monitor
+                .get_eval_handle()
+                .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+            assert_eq!(errors, expected);
+        }
+
+        // Missing heartbeat is accepted until range end, one tick later is too late.
+        let (monitor, time_source) = create_monitor_single_cycle(range);
+        let hmon_starting_point = time_source.now();
+        for (eval_time, expected) in [(120, vec![]), (121, vec![HeartbeatEvaluationError::TooLate.into()])] {
+            advance_until(&time_source, Duration::from_millis(eval_time), hmon_starting_point);
+            let mut errors = vec![];
+            monitor
+                .get_eval_handle()
+                .evaluate(hmon_starting_point, &mut |_, error, _| errors.push(error));
+            assert_eq!(errors, expected);
+        }
+    }
+
+    /// HMON starting point after 100 days of uptime in milliseconds, does not fit into 32 bits.
+    const LONG_UPTIME: u64 = 100 * 24 * 60 * 60 * 1000;
+
+    fn evaluate_errors(monitor: &HeartbeatMonitor, offset: u64, now: u64) -> Vec<MonitorEvaluationError> {
+        let mut errors = vec![];
+        monitor
+            .inner
+            .evaluate_at(offset, now, &mut |_, error, _| errors.push(error));
+        errors
+    }
+
+    #[test]
+    fn heartbeat_monitor_long_uptime_in_range() {
+        let (monitor, _) = create_monitor_single_cycle(range_from_ms(80, 120));
+
+        // Consecutive cycles start at previous heartbeats.
+        for beat in 1..=3 {
+            monitor.inner.heartbeat_at(LONG_UPTIME + beat * 100);
+            assert!(evaluate_errors(&monitor, LONG_UPTIME, LONG_UPTIME + beat * 100 + 10).is_empty());
+        }
+    }
+
+    #[test]
+    fn heartbeat_monitor_long_uptime_early() {
+        let (monitor, _) = create_monitor_single_cycle(range_from_ms(80, 120));
+
+        monitor.inner.heartbeat_at(LONG_UPTIME + 50);
+        assert_eq!(
+            evaluate_errors(&monitor, LONG_UPTIME, LONG_UPTIME + 60),
+            vec![HeartbeatEvaluationError::TooEarly.into()]
+        );
+    }
+
+    #[test]
+    fn heartbeat_monitor_long_uptime_late() {
+        let (monitor, _) = create_monitor_single_cycle(range_from_ms(80, 120));
+
+        assert!(evaluate_errors(&monitor, LONG_UPTIME, LONG_UPTIME + 120).is_empty());
+        assert_eq!(
+            evaluate_errors(&monitor, LONG_UPTIME, LONG_UPTIME + 121),
+            vec![HeartbeatEvaluationError::TooLate.into()]
+        );
+
+        // New cycle begins at re-arm.
+        monitor.inner.rearm_at(LONG_UPTIME + 200);
+        monitor.inner.heartbeat_at(LONG_UPTIME + 300);
+        assert!(evaluate_errors(&monitor, LONG_UPTIME, LONG_UPTIME + 310).is_empty());
+    }
+
+    #[test]
+    fn heartbeat_monitor_sub_millisecond_resolution() {
+        let monitor_tag = MonitorTag::from(TAG);
+        let allocator = ProtectedMemoryAllocator::new();
+        let monitor =
+            HeartbeatMonitorBuilder::new(TimeRange::new(Duration::from_micros(800), Duration::from_micros(1200)))
+                .build(
+                    monitor_tag,
+                    Duration::from_millis(1),
+                    Clock::new(Duration::from_micros(1), Arc::new(VirtualClock::new())),
+                    &allocator,
+                )
+                .unwrap();
+
+        // Heartbeat within a single millisecond of the range end.
+        monitor.inner.heartbeat_at(LONG_UPTIME + 1_100);
+        assert!(evaluate_errors(&monitor, LONG_UPTIME, LONG_UPTIME + 1_150).is_empty());
+        monitor.inner.heartbeat_at(LONG_UPTIME + 2_350);
+        assert_eq!(
+            evaluate_errors(&monitor, LONG_UPTIME, LONG_UPTIME + 2_400),
+            vec![HeartbeatEvaluationError::TooLate.into()]
+        );
+    }
 }
 
 #[cfg(all(test, loom))]
 mod loom_tests {
+    use crate::clock::Clock;
     use crate::common::{Monitor, MonitorEvaluator, TimeRange};
     use crate::heartbeat::heartbeat_monitor::test_common::{range_from_ms, sleep_until, TAG};
     use crate::heartbeat::{HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder};
@@ -689,9 +1219,9 @@ mod loom_tests {
     fn create_monitor_single_cycle(range: TimeRange) -> Arc<HeartbeatMonitor> {
         let monitor_tag = MonitorTag::from(TAG);
         let internal_processing_cycle = Duration::from_millis(1);
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor = HeartbeatMonitorBuilder::new(range)
-            .build(monitor_tag, internal_processing_cycle, &allocator)
+            .build(monitor_tag, internal_processing_cycle, Clock::default(), &allocator)
             .unwrap();
         Arc::new(monitor)
     }
@@ -710,7 +1240,7 @@ mod loom_tests {
             // Evaluate.
             monitor
                 .get_eval_handle()
-                .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                     assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                     assert_eq!(error, HeartbeatEvaluationError::TooEarly.into());
                 });
@@ -736,7 +1266,7 @@ mod loom_tests {
             // Evaluate.
             monitor
                 .get_eval_handle()
-                .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                     panic!("error happened, tag: {monitor_tag:?}, error: {error:?}");
                 });
 
@@ -762,7 +1292,7 @@ mod loom_tests {
             let mut error_detected = false;
             monitor
                 .get_eval_handle()
-                .evaluate(hmon_starting_point, &mut |monitor_tag, error| {
+                .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
                     assert_eq!(*monitor_tag, MonitorTag::from(TAG));
                     assert_eq!(error, HeartbeatEvaluationError::TooLate.into());
                     error_detected = true;
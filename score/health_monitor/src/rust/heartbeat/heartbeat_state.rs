@@ -15,13 +15,16 @@ use crate::common::{AtomicU64, Ordering};
 use core::cmp::min;
 
 /// Snapshot of a heartbeat state.
-/// Layout (u64) = | heartbeat timestamp: 62 bits | heartbeat counter: 2 bits |
+/// Layout (u64) = | heartbeat timestamp: 52 bits | heartbeat counter: 12 bits |
 #[derive(Clone, Copy, Default)]
 pub struct HeartbeatStateSnapshot(u64);
 
-const BEAT_MASK: u64 = 0xFFFFFFFF_FFFFFFFC;
-const BEAT_OFFSET: u32 = 2;
-const COUNT_MASK: u64 = 0b0011;
+const BEAT_MASK: u64 = 0xFFFFFFFF_FFFFF000;
+const BEAT_OFFSET: u32 = 12;
+const COUNT_MASK: u64 = 0x0FFF;
+
+/// Max value of heartbeat counter, larger values are saturated.
+pub const MAX_COUNTER: u16 = COUNT_MASK as u16;
 
 impl HeartbeatStateSnapshot {
     /// Create a new snapshot.
@@ -40,21 +43,21 @@ impl HeartbeatStateSnapshot {
     }
 
     /// Set heartbeat timestamp.
-    /// Value is 62-bit, max accepted value is 0x3FFFFFFF_FFFFFFFF.
+    /// Value is 52-bit, max accepted value is 0x000FFFFF_FFFFFFFF.
     pub fn set_heartbeat_timestamp(&mut self, value: u64) {
-        assert!(value < 1 << 62, "provided heartbeat offset is out of range");
+        assert!(value < 1 << 52, "provided heartbeat offset is out of range");
         self.0 = (value << BEAT_OFFSET) | (self.0 & !BEAT_MASK);
     }
 
     /// Heartbeat counter.
-    pub fn counter(&self) -> u8 {
-        (self.0 & COUNT_MASK) as u8
+    pub fn counter(&self) -> u16 {
+        (self.0 & COUNT_MASK) as u16
     }
 
     /// Increment heartbeat counter.
-    /// Value is 2-bit, larger values are saturated to max value (3).
+    /// Value is 12-bit, larger values are saturated to max value ([`MAX_COUNTER`]).
     pub fn increment_counter(&mut self) {
-        let value = min(self.counter() + 1, 3);
+        let value = min(self.counter() + 1, MAX_COUNTER);
         self.0 = (value as u64) | (self.0 & !COUNT_MASK);
     }
 }
@@ -76,7 +79,6 @@ impl HeartbeatState {
     }
 
     /// Return a snapshot of the current heartbeat state.
-    #[allow(dead_code)]
     pub fn snapshot(&self) -> HeartbeatStateSnapshot {
         HeartbeatStateSnapshot::from(self.0.load(Ordering::Acquire))
     }
@@ -107,7 +109,7 @@ impl HeartbeatState {
 
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::heartbeat::heartbeat_state::{HeartbeatState, HeartbeatStateSnapshot, BEAT_OFFSET};
+    use crate::heartbeat::heartbeat_state::{HeartbeatState, HeartbeatStateSnapshot, BEAT_OFFSET, MAX_COUNTER};
     use core::cmp::min;
     use core::sync::atomic::Ordering;
 
@@ -135,7 +137,7 @@ mod tests {
 
         assert_eq!(state.as_u64(), 0xDEADBEEF_DEADBEEF);
         assert_eq!(state.heartbeat_timestamp(), 0xDEADBEEF_DEADBEEF >> BEAT_OFFSET);
-        assert_eq!(state.counter(), 3);
+        assert_eq!(state.counter(), 0xEEF);
     }
 
     #[test]
@@ -144,7 +146,7 @@ mod tests {
 
         assert_eq!(state.as_u64(), u64::MAX);
         assert_eq!(state.heartbeat_timestamp(), u64::MAX >> BEAT_OFFSET);
-        assert_eq!(state.counter(), 3);
+        assert_eq!(state.counter(), MAX_COUNTER);
     }
 
     #[test]
@@ -159,33 +161,33 @@ mod tests {
     #[test]
     fn snapshot_set_heartbeat_timestamp_valid() {
         let mut state = HeartbeatStateSnapshot::from(0xDEADBEEF_DEADBEEF);
-        state.set_heartbeat_timestamp(0x3CAFEBAD_CAFEBAAD);
+        state.set_heartbeat_timestamp(0x000CAFEB_ADCAFEBA);
 
-        assert_eq!(state.heartbeat_timestamp(), 0x3CAFEBAD_CAFEBAAD);
+        assert_eq!(state.heartbeat_timestamp(), 0x000CAFEB_ADCAFEBA);
 
         // Check other parameters unchanged.
-        assert_eq!(state.counter(), 3);
+        assert_eq!(state.counter(), 0xEEF);
     }
 
     #[test]
     #[should_panic(expected = "provided heartbeat offset is out of range")]
     fn snapshot_set_heartbeat_timestamp_out_of_range() {
         let mut state = HeartbeatStateSnapshot::from(0xDEADBEEF_DEADBEEF);
-        state.set_heartbeat_timestamp(0x40000000_00000000);
+        state.set_heartbeat_timestamp(0x00100000_00000000);
     }
 
     #[test]
     fn snapshot_counter_increment() {
-        let mut state = HeartbeatStateSnapshot::from(0xDEADBEEF_DEADBEEC);
+        let mut state = HeartbeatStateSnapshot::from(0xDEADBEEF_DEADB000);
 
-        // Max value is 3, check if saturates.
-        for i in 1..=4 {
+        // Check if saturates at max value.
+        for i in 1..=MAX_COUNTER + 1 {
             state.increment_counter();
-            assert_eq!(state.counter(), min(i, 3));
+            assert_eq!(state.counter(), min(i, MAX_COUNTER));
         }
 
         // Check other parameters unchanged.
-        assert_eq!(state.heartbeat_timestamp(), 0xDEADBEEF_DEADBEEC >> BEAT_OFFSET);
+        assert_eq!(state.heartbeat_timestamp(), 0xDEADBEEF_DEADB000 >> BEAT_OFFSET);
     }
 
     #[test]
@@ -223,7 +225,7 @@ mod tests {
             // Make sure state is as expected.
             assert_eq!(prev_snapshot.as_u64(), 0xDEADBEEF_DEADBEEF);
             assert_eq!(prev_snapshot.heartbeat_timestamp(), 0xDEADBEEF_DEADBEEF >> BEAT_OFFSET);
-            assert_eq!(prev_snapshot.counter(), 3);
+            assert_eq!(prev_snapshot.counter(), 0xEEF);
 
             Some(HeartbeatStateSnapshot::from(0))
         });
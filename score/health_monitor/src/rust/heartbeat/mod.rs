@@ -16,8 +16,7 @@
 mod heartbeat_monitor;
 mod heartbeat_state;
 
-pub(crate) use heartbeat_monitor::HeartbeatEvaluationError;
-pub use heartbeat_monitor::{HeartbeatMonitor, HeartbeatMonitorBuilder};
+pub use heartbeat_monitor::{HeartbeatEvaluationError, HeartbeatMonitor, HeartbeatMonitorBuilder};
 
 // FFI bindings
 pub(super) mod ffi;
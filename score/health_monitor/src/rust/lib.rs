@@ -13,20 +13,36 @@
 
 //! Health monitoring library.
 
+mod clock;
 mod common;
+mod config;
+mod custom;
+mod cycle_timing;
 mod ffi;
 mod health_monitor;
 mod log;
 mod protected_memory;
+mod status;
 mod supervisor_api_client;
 mod tag;
+#[cfg(all(test, not(loom)))]
+mod test_common;
 mod thread_ffi;
+mod tolerance;
+mod watchdog;
 mod worker;
 
 pub mod deadline;
 pub mod heartbeat;
 pub mod logic;
 
-pub use common::TimeRange;
-pub use health_monitor::{HealthMonitor, HealthMonitorBuilder, HealthMonitorError};
-pub use tag::{DeadlineTag, MonitorTag, StateTag};
+pub use clock::{SystemClock, TimeSource, VirtualClock};
+pub use common::{MonitorEvaluationError, MonitorKind, TimeRange};
+pub use config::ConfigError;
+pub use custom::{CustomEvaluationError, CustomMonitor};
+pub use cycle_timing::CycleStatistics;
+pub use health_monitor::{HealthMonitor, HealthMonitorBuilder, HealthMonitorDriver, HealthMonitorError};
+pub use status::{FailureOrigin, MonitorFailure, MonitorHealth, Status};
+pub use tag::{DeadlineTag, EventTag, MonitorTag, StateTag};
+pub use tolerance::FailureTolerance;
+pub use watchdog::WorkerWatchdog;
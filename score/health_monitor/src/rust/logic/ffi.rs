@@ -11,13 +11,51 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+use crate::common::TimeRange;
 use crate::ffi::{FFIBorrowed, FFICode, FFIHandle};
-use crate::logic::{LogicMonitor, LogicMonitorBuilder};
-use crate::tag::StateTag;
+use crate::logic::{LogicMonitor, LogicMonitorBuilder, ResetPolicy, TransitionRecord};
+use crate::tag::{EventTag, FFITag, StateTag};
+use core::time::Duration;
+use std::time::Instant;
+
+/// Transition record provided over FFI.
+/// Must be aligned with `score::mw::health::logic::TransitionRecord`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransitionRecordCpp {
+    source: FFITag,
+    target: FFITag,
+    /// Event which triggered the request, empty if `has_event` is not set.
+    event: FFITag,
+    has_event: bool,
+    /// Time elapsed since the request, in nanoseconds.
+    age_ns: u64,
+    reset: bool,
+    /// Zero on success, otherwise logic evaluation error code.
+    error_code: u8,
+}
+
+impl TransitionRecordCpp {
+    fn new(record: &TransitionRecord, now: Instant) -> Self {
+        let age = now.saturating_duration_since(record.time);
+        Self {
+            source: FFITag::from(record.source),
+            target: FFITag::from(record.target),
+            event: FFITag::from(record.event.unwrap_or(EventTag::new(""))),
+            has_event: record.event.is_some(),
+            age_ns: u64::try_from(age.as_nanos()).unwrap_or(u64::MAX),
+            reset: record.reset,
+            error_code: match record.result {
+                Ok(()) => 0,
+                Err(error) => error.into(),
+            },
+        }
+    }
+}
 
 #[unsafe(no_mangle)]
 pub extern "C" fn logic_monitor_builder_create(
-    initial_state: *const StateTag,
+    initial_state: *const FFITag,
     logic_monitor_builder_handle_out: *mut FFIHandle,
 ) -> FFICode {
     if initial_state.is_null() || logic_monitor_builder_handle_out.is_null() {
@@ -26,8 +64,11 @@ pub extern "C" fn logic_monitor_builder_create(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `StateTag` type must be compatible between C++ and Rust.
-    let initial_state = unsafe { *initial_state };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(initial_state) = (unsafe { StateTag::from_ffi(&*initial_state) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     let logic_monitor_builder = LogicMonitorBuilder::new(initial_state);
     unsafe {
@@ -56,9 +97,44 @@ pub extern "C" fn logic_monitor_builder_destroy(logic_monitor_builder_handle: FF
 #[unsafe(no_mangle)]
 pub extern "C" fn logic_monitor_builder_add_state(
     logic_monitor_builder_handle: FFIHandle,
-    state: *const StateTag,
-    allowed_states: *const StateTag,
+    state: *const FFITag,
+    allowed_states: *const FFITag,
+    num_allowed_states: usize,
+) -> FFICode {
+    add_state(
+        logic_monitor_builder_handle,
+        state,
+        allowed_states,
+        num_allowed_states,
+        None,
+    )
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_builder_add_timed_state(
+    logic_monitor_builder_handle: FFIHandle,
+    state: *const FFITag,
+    allowed_states: *const FFITag,
+    num_allowed_states: usize,
+    min_ms: u32,
+    max_ms: u32,
+) -> FFICode {
+    add_state(
+        logic_monitor_builder_handle,
+        state,
+        allowed_states,
+        num_allowed_states,
+        Some((min_ms, max_ms)),
+    )
+}
+
+/// Add state to the logic monitor builder, time-bounded if `dwell_ms` is provided.
+fn add_state(
+    logic_monitor_builder_handle: FFIHandle,
+    state: *const FFITag,
+    allowed_states: *const FFITag,
     num_allowed_states: usize,
+    dwell_ms: Option<(u32, u32)>,
 ) -> FFICode {
     if logic_monitor_builder_handle.is_null() || state.is_null() {
         return FFICode::NullParameter;
@@ -70,8 +146,11 @@ pub extern "C" fn logic_monitor_builder_add_state(
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `StateTag` type must be compatible between C++ and Rust.
-    let state = unsafe { *state };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(state) = (unsafe { StateTag::from_ffi(&*state) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // `allowed_states` must contain a valid continuous array.
@@ -83,6 +162,110 @@ pub extern "C" fn logic_monitor_builder_add_state(
         &[]
     };
 
+    // SAFETY:
+    // Tag data must be valid for the duration of the call.
+    let Some(allowed_states) = allowed_states
+        .iter()
+        .map(|allowed_state| unsafe { StateTag::from_ffi(allowed_state) })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return FFICode::InvalidArgument;
+    };
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `logic_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_builder_destroy`.
+    let mut logic_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_builder_handle as *mut LogicMonitorBuilder) });
+
+    match dwell_ms {
+        Some((min_ms, max_ms)) => {
+            let range_min = Duration::from_millis(min_ms as u64);
+            let range_max = Duration::from_millis(max_ms as u64);
+            let dwell = match TimeRange::new_internal(range_min, range_max) {
+                Some(range) => range,
+                None => return FFICode::InvalidArgument,
+            };
+            logic_monitor_builder.add_timed_state_internal(state, &allowed_states, dwell);
+        },
+        None => logic_monitor_builder.add_state_internal(state, &allowed_states),
+    }
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_builder_add_event(
+    logic_monitor_builder_handle: FFIHandle,
+    state: *const FFITag,
+    event: *const FFITag,
+    target_state: *const FFITag,
+) -> FFICode {
+    if logic_monitor_builder_handle.is_null() || state.is_null() || event.is_null() || target_state.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointers is ensured.
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let (Some(state), Some(event), Some(target_state)) = (unsafe {
+        (
+            StateTag::from_ffi(&*state),
+            EventTag::from_ffi(&*event),
+            StateTag::from_ffi(&*target_state),
+        )
+    }) else {
+        return FFICode::InvalidArgument;
+    };
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `logic_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_builder_destroy`.
+    let mut logic_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_builder_handle as *mut LogicMonitorBuilder) });
+
+    logic_monitor_builder.add_event_internal(state, event, target_state);
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_builder_with_reset_policy(
+    logic_monitor_builder_handle: FFIHandle,
+    reset_policy: u8,
+) -> FFICode {
+    if logic_monitor_builder_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    let Ok(reset_policy) = ResetPolicy::try_from(reset_policy) else {
+        return FFICode::InvalidArgument;
+    };
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `logic_monitor_builder_create`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_builder_destroy`.
+    let mut logic_monitor_builder =
+        FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_builder_handle as *mut LogicMonitorBuilder) });
+
+    logic_monitor_builder.with_reset_policy_internal(reset_policy);
+
+    FFICode::Success
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_builder_with_history_capacity(
+    logic_monitor_builder_handle: FFIHandle,
+    capacity: usize,
+) -> FFICode {
+    if logic_monitor_builder_handle.is_null() {
+        return FFICode::NullParameter;
+    }
+
     // SAFETY:
     // Validity of this pointer is ensured.
     // It is assumed that the pointer was created by a call to `logic_monitor_builder_create`.
@@ -90,7 +273,7 @@ pub extern "C" fn logic_monitor_builder_add_state(
     let mut logic_monitor_builder =
         FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_builder_handle as *mut LogicMonitorBuilder) });
 
-    logic_monitor_builder.add_state_internal(state, allowed_states);
+    logic_monitor_builder.with_history_capacity_internal(capacity);
 
     FFICode::Success
 }
@@ -112,15 +295,18 @@ pub extern "C" fn logic_monitor_destroy(logic_monitor_handle: FFIHandle) -> FFIC
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn logic_monitor_transition(logic_monitor_handle: FFIHandle, target_state: *const StateTag) -> FFICode {
+pub extern "C" fn logic_monitor_transition(logic_monitor_handle: FFIHandle, target_state: *const FFITag) -> FFICode {
     if logic_monitor_handle.is_null() || target_state.is_null() {
         return FFICode::NullParameter;
     }
 
     // SAFETY:
     // Validity of the pointer is ensured.
-    // `StateTag` type must be compatible between C++ and Rust.
-    let target_state = unsafe { *target_state };
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(target_state) = (unsafe { StateTag::from_ffi(&*target_state) }) else {
+        return FFICode::InvalidArgument;
+    };
 
     // SAFETY:
     // Validity of this pointer is ensured.
@@ -137,7 +323,42 @@ pub extern "C" fn logic_monitor_transition(logic_monitor_handle: FFIHandle, targ
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn logic_monitor_state(logic_monitor_handle: FFIHandle, state_out: *mut StateTag) -> FFICode {
+pub extern "C" fn logic_monitor_event(
+    logic_monitor_handle: FFIHandle,
+    event: *const FFITag,
+    state_out: *mut FFITag,
+) -> FFICode {
+    if logic_monitor_handle.is_null() || event.is_null() || state_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(event) = (unsafe { EventTag::from_ffi(&*event) }) else {
+        return FFICode::InvalidArgument;
+    };
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_get_logic_monitor`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_destroy`.
+    let monitor = FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_handle as *mut LogicMonitor) });
+
+    match monitor.event(event) {
+        Ok(state) => {
+            unsafe {
+                *state_out = FFITag::from(state);
+            }
+            FFICode::Success
+        },
+        Err(_) => FFICode::Failed,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_state(logic_monitor_handle: FFIHandle, state_out: *mut FFITag) -> FFICode {
     if logic_monitor_handle.is_null() || state_out.is_null() {
         return FFICode::NullParameter;
     }
@@ -151,7 +372,7 @@ pub extern "C" fn logic_monitor_state(logic_monitor_handle: FFIHandle, state_out
     match monitor.state() {
         Ok(state) => {
             unsafe {
-                *state_out = state;
+                *state_out = FFITag::from(state);
             }
             FFICode::Success
         },
@@ -159,6 +380,132 @@ pub extern "C" fn logic_monitor_state(logic_monitor_handle: FFIHandle, state_out
     }
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_reset_to(logic_monitor_handle: FFIHandle, state: *const FFITag) -> FFICode {
+    if logic_monitor_handle.is_null() || state.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of the pointer is ensured.
+    // `FFITag` type must be compatible between C++ and Rust.
+    // Tag data must be valid for the duration of the call.
+    let Some(state) = (unsafe { StateTag::from_ffi(&*state) }) else {
+        return FFICode::InvalidArgument;
+    };
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_get_logic_monitor`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_destroy`.
+    let monitor = FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_handle as *mut LogicMonitor) });
+
+    match monitor.reset_to(state) {
+        Ok(_) => FFICode::Success,
+        Err(error) => error.into(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_invalid_transition(
+    logic_monitor_handle: FFIHandle,
+    source_out: *mut FFITag,
+    target_out: *mut FFITag,
+) -> FFICode {
+    if logic_monitor_handle.is_null() || source_out.is_null() || target_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_get_logic_monitor`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_destroy`.
+    let monitor = FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_handle as *mut LogicMonitor) });
+
+    match monitor.invalid_transition() {
+        Some((source, target)) => {
+            unsafe {
+                *source_out = FFITag::from(source);
+                *target_out = FFITag::from(target);
+            }
+            FFICode::Success
+        },
+        None => FFICode::NotFound,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_unexpected_event(
+    logic_monitor_handle: FFIHandle,
+    state_out: *mut FFITag,
+    event_out: *mut FFITag,
+) -> FFICode {
+    if logic_monitor_handle.is_null() || state_out.is_null() || event_out.is_null() {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_get_logic_monitor`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_destroy`.
+    let monitor = FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_handle as *mut LogicMonitor) });
+
+    match monitor.unexpected_event() {
+        Some((state, event)) => {
+            unsafe {
+                *state_out = FFITag::from(state);
+                *event_out = FFITag::from(event);
+            }
+            FFICode::Success
+        },
+        None => FFICode::NotFound,
+    }
+}
+
+/// Provide last transitions, starting from the oldest one.
+/// Up to `capacity` most recent records are written to `records_out`.
+/// Number of all available records is written to `num_records_out`.
+#[unsafe(no_mangle)]
+pub extern "C" fn logic_monitor_history(
+    logic_monitor_handle: FFIHandle,
+    records_out: *mut TransitionRecordCpp,
+    capacity: usize,
+    num_records_out: *mut usize,
+) -> FFICode {
+    if logic_monitor_handle.is_null() || num_records_out.is_null() {
+        return FFICode::NullParameter;
+    }
+    // Null is only allowed when `capacity` equals 0!
+    if records_out.is_null() && capacity > 0 {
+        return FFICode::NullParameter;
+    }
+
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // It is assumed that the pointer was created by a call to `health_monitor_get_logic_monitor`.
+    // It is assumed that the pointer was not consumed by a call to `logic_monitor_destroy`.
+    let monitor = FFIBorrowed::new(unsafe { Box::from_raw(logic_monitor_handle as *mut LogicMonitor) });
+
+    let now = Instant::now();
+    let history = monitor.history();
+    let skipped = history.len().saturating_sub(capacity);
+    for (index, record) in history.iter().skip(skipped).enumerate() {
+        // SAFETY:
+        // `records_out` must contain a valid continuous array of `capacity` elements.
+        unsafe {
+            *records_out.add(index) = TransitionRecordCpp::new(record, now);
+        }
+    }
+    // SAFETY:
+    // Validity of this pointer is ensured.
+    // `num_records_out` must point to a valid `usize`.
+    unsafe {
+        *num_records_out = history.len();
+    }
+
+    FFICode::Success
+}
+
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
@@ -167,10 +514,14 @@ mod tests {
         health_monitor_destroy, health_monitor_get_logic_monitor, FFICode, FFIHandle,
     };
     use crate::logic::ffi::{
-        logic_monitor_builder_add_state, logic_monitor_builder_create, logic_monitor_builder_destroy,
-        logic_monitor_destroy, logic_monitor_state, logic_monitor_transition,
+        logic_monitor_builder_add_event, logic_monitor_builder_add_state, logic_monitor_builder_add_timed_state,
+        logic_monitor_builder_create, logic_monitor_builder_destroy, logic_monitor_builder_with_history_capacity,
+        logic_monitor_builder_with_reset_policy, logic_monitor_destroy, logic_monitor_event, logic_monitor_history,
+        logic_monitor_invalid_transition, logic_monitor_reset_to, logic_monitor_state, logic_monitor_transition,
+        logic_monitor_unexpected_event, TransitionRecordCpp,
     };
-    use crate::tag::{MonitorTag, StateTag};
+    use crate::logic::{LogicEvaluationError, ResetPolicy};
+    use crate::tag::{EventTag, FFITag, MonitorTag, StateTag};
     use core::mem::MaybeUninit;
     use core::ptr::null_mut;
 
@@ -180,7 +531,7 @@ mod tests {
 
         let initial_state = StateTag::from("initial_state");
         let logic_monitor_builder_create_result = logic_monitor_builder_create(
-            &initial_state as *const StateTag,
+            &FFITag::from(initial_state) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
         assert!(!logic_monitor_builder_handle.is_null());
@@ -196,10 +547,23 @@ mod tests {
     fn logic_monitor_builder_create_null_builder() {
         let initial_state = StateTag::from("initial_state");
         let logic_monitor_builder_create_result =
-            logic_monitor_builder_create(&initial_state as *const StateTag, null_mut());
+            logic_monitor_builder_create(&FFITag::from(initial_state) as *const FFITag, null_mut());
         assert_eq!(logic_monitor_builder_create_result, FFICode::NullParameter);
     }
 
+    #[test]
+    fn logic_monitor_builder_create_invalid_initial_state() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let initial_state = FFITag::from_bytes(b"initial_\xffstate");
+        let logic_monitor_builder_create_result = logic_monitor_builder_create(
+            &initial_state as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+        assert!(logic_monitor_builder_handle.is_null());
+        assert_eq!(logic_monitor_builder_create_result, FFICode::InvalidArgument);
+    }
+
     #[test]
     fn logic_monitor_builder_create_null_initial_state() {
         let mut logic_monitor_builder_handle: FFIHandle = null_mut();
@@ -224,15 +588,15 @@ mod tests {
 
         let state1 = StateTag::from("state1");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
 
         let state2 = StateTag::from("state2");
-        let allowed_states = [state2];
+        let allowed_states = [FFITag::from(state2)];
         let result = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             allowed_states.as_ptr(),
             allowed_states.len(),
         );
@@ -248,7 +612,7 @@ mod tests {
         let allowed_states = [];
         let result = logic_monitor_builder_add_state(
             null_mut(),
-            &state as *const StateTag,
+            &FFITag::from(state) as *const FFITag,
             allowed_states.as_ptr(),
             allowed_states.len(),
         );
@@ -261,7 +625,7 @@ mod tests {
 
         let state1 = StateTag::from("state1");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
 
@@ -284,12 +648,16 @@ mod tests {
 
         let state1 = StateTag::from("state1");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
 
-        let result =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state1 as *const StateTag, null_mut(), 0);
+        let result = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state1) as *const FFITag,
+            null_mut(),
+            0,
+        );
         assert_eq!(result, FFICode::Success);
 
         // Clean-up.
@@ -302,18 +670,87 @@ mod tests {
 
         let state1 = StateTag::from("state1");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
 
-        let result =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state1 as *const StateTag, null_mut(), 2);
+        let result = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state1) as *const FFITag,
+            null_mut(),
+            2,
+        );
         assert_eq!(result, FFICode::NullParameter);
 
         // Clean-up.
         logic_monitor_builder_destroy(logic_monitor_builder_handle);
     }
 
+    #[test]
+    fn logic_monitor_builder_add_timed_state_succeeds() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let state1 = StateTag::from("state1");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(state1) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let state2 = StateTag::from("state2");
+        let allowed_states = [FFITag::from(state2)];
+        let result = logic_monitor_builder_add_timed_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state1) as *const FFITag,
+            allowed_states.as_ptr(),
+            allowed_states.len(),
+            100,
+            200,
+        );
+        assert_eq!(result, FFICode::Success);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn logic_monitor_builder_add_timed_state_invalid_range() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let state1 = StateTag::from("state1");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(state1) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let result = logic_monitor_builder_add_timed_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state1) as *const FFITag,
+            null_mut(),
+            0,
+            200,
+            100,
+        );
+        assert_eq!(result, FFICode::InvalidArgument);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn logic_monitor_builder_add_timed_state_null_builder() {
+        let state = StateTag::from("state");
+        let allowed_states = [];
+        let result = logic_monitor_builder_add_timed_state(
+            null_mut(),
+            &FFITag::from(state) as *const FFITag,
+            allowed_states.as_ptr(),
+            allowed_states.len(),
+            100,
+            200,
+        );
+        assert_eq!(result, FFICode::NullParameter);
+    }
+
     #[test]
     fn logic_monitor_destroy_null_monitor() {
         let logic_monitor_destroy_result = logic_monitor_destroy(null_mut());
@@ -332,20 +769,24 @@ mod tests {
         let state1 = StateTag::from("state1");
         let state2 = StateTag::from("state2");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
         let _ = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
-            [state2].as_ptr(),
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
             1,
         );
-        let _ =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state2 as *const StateTag, [].as_ptr(), 0);
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -357,11 +798,11 @@ mod tests {
         );
         let _ = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
 
-        let result = logic_monitor_transition(logic_monitor_handle, &state2 as *const StateTag);
+        let result = logic_monitor_transition(logic_monitor_handle, &FFITag::from(state2) as *const FFITag);
         assert_eq!(result, FFICode::Success);
 
         // Clean-up.
@@ -381,20 +822,24 @@ mod tests {
         let state1 = StateTag::from("state1");
         let state2 = StateTag::from("state2");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
         let _ = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
-            [state2].as_ptr(),
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
             1,
         );
-        let _ =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state2 as *const StateTag, [].as_ptr(), 0);
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -406,12 +851,12 @@ mod tests {
         );
         let _ = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
 
         let state3 = StateTag::from("state3");
-        let result = logic_monitor_transition(logic_monitor_handle, &state3 as *const StateTag);
+        let result = logic_monitor_transition(logic_monitor_handle, &FFITag::from(state3) as *const FFITag);
         assert_eq!(result, FFICode::Failed);
 
         // Clean-up.
@@ -422,7 +867,7 @@ mod tests {
     #[test]
     fn logic_monitor_transition_null_monitor() {
         let state1 = StateTag::from("state1");
-        let result = logic_monitor_transition(null_mut(), &state1 as *const StateTag);
+        let result = logic_monitor_transition(null_mut(), &FFITag::from(state1) as *const FFITag);
         assert_eq!(result, FFICode::NullParameter);
     }
 
@@ -438,20 +883,24 @@ mod tests {
         let state1 = StateTag::from("state1");
         let state2 = StateTag::from("state2");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
         let _ = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
-            [state2].as_ptr(),
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
             1,
         );
-        let _ =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state2 as *const StateTag, [].as_ptr(), 0);
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -463,7 +912,7 @@ mod tests {
         );
         let _ = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
 
@@ -487,20 +936,24 @@ mod tests {
         let state1 = StateTag::from("state1");
         let state2 = StateTag::from("state2");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
         let _ = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
-            [state2].as_ptr(),
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
             1,
         );
-        let _ =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state2 as *const StateTag, [].as_ptr(), 0);
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -512,14 +965,17 @@ mod tests {
         );
         let _ = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
 
         let mut current_state = MaybeUninit::uninit();
         let result = logic_monitor_state(logic_monitor_handle, current_state.as_mut_ptr());
         assert_eq!(result, FFICode::Success);
-        assert_eq!(unsafe { current_state.assume_init() }, state1);
+        assert_eq!(
+            unsafe { StateTag::from_ffi(current_state.assume_init_ref()) },
+            Some(state1)
+        );
 
         // Clean-up.
         logic_monitor_destroy(logic_monitor_handle);
@@ -538,20 +994,24 @@ mod tests {
         let state1 = StateTag::from("state1");
         let state2 = StateTag::from("state2");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
         let _ = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
-            [state2].as_ptr(),
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
             1,
         );
-        let _ =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state2 as *const StateTag, [].as_ptr(), 0);
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -563,12 +1023,12 @@ mod tests {
         );
         let _ = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
 
         let state3 = StateTag::from("state3");
-        let _ = logic_monitor_transition(logic_monitor_handle, &state3 as *const StateTag);
+        let _ = logic_monitor_transition(logic_monitor_handle, &FFITag::from(state3) as *const FFITag);
 
         let mut current_state = MaybeUninit::uninit();
         let result = logic_monitor_state(logic_monitor_handle, current_state.as_mut_ptr());
@@ -598,20 +1058,24 @@ mod tests {
         let state1 = StateTag::from("state1");
         let state2 = StateTag::from("state2");
         let _ = logic_monitor_builder_create(
-            &state1 as *const StateTag,
+            &FFITag::from(state1) as *const FFITag,
             &mut logic_monitor_builder_handle as *mut FFIHandle,
         );
         let _ = logic_monitor_builder_add_state(
             logic_monitor_builder_handle,
-            &state1 as *const StateTag,
-            [state2].as_ptr(),
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
             1,
         );
-        let _ =
-            logic_monitor_builder_add_state(logic_monitor_builder_handle, &state2 as *const StateTag, [].as_ptr(), 0);
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
         let _ = health_monitor_builder_add_logic_monitor(
             health_monitor_builder_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             logic_monitor_builder_handle,
         );
         let _ = health_monitor_builder_build(
@@ -623,7 +1087,7 @@ mod tests {
         );
         let _ = health_monitor_get_logic_monitor(
             health_monitor_handle,
-            &logic_monitor_tag as *const MonitorTag,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
             &mut logic_monitor_handle as *mut FFIHandle,
         );
 
@@ -634,4 +1098,505 @@ mod tests {
         logic_monitor_destroy(logic_monitor_handle);
         health_monitor_destroy(health_monitor_handle);
     }
+
+    /// Create health monitor with a `state1 -> state2` logic monitor, transition is also triggered by `next` event.
+    /// Returns health monitor and logic monitor handles.
+    fn create_logic_monitor(reset_policy: ResetPolicy) -> (FFIHandle, FFIHandle) {
+        let mut health_monitor_builder_handle: FFIHandle = null_mut();
+        let mut health_monitor_handle: FFIHandle = null_mut();
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+        let mut logic_monitor_handle: FFIHandle = null_mut();
+
+        let logic_monitor_tag = MonitorTag::from("logic_monitor");
+        let _ = health_monitor_builder_create(&mut health_monitor_builder_handle as *mut FFIHandle);
+        let state1 = StateTag::from("state1");
+        let state2 = StateTag::from("state2");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(state1) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state1) as *const FFITag,
+            [FFITag::from(state2)].as_ptr(),
+            1,
+        );
+        let _ = logic_monitor_builder_add_state(
+            logic_monitor_builder_handle,
+            &FFITag::from(state2) as *const FFITag,
+            [].as_ptr(),
+            0,
+        );
+        let _ = logic_monitor_builder_add_event(
+            logic_monitor_builder_handle,
+            &FFITag::from(state1) as *const FFITag,
+            &FFITag::from(EventTag::from("next")) as *const FFITag,
+            &FFITag::from(state2) as *const FFITag,
+        );
+        let _ = logic_monitor_builder_with_reset_policy(logic_monitor_builder_handle, reset_policy as u8);
+        let _ = health_monitor_builder_add_logic_monitor(
+            health_monitor_builder_handle,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
+            logic_monitor_builder_handle,
+        );
+        let _ = health_monitor_builder_build(
+            health_monitor_builder_handle,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut health_monitor_handle as *mut FFIHandle,
+        );
+        let _ = health_monitor_get_logic_monitor(
+            health_monitor_handle,
+            &FFITag::from(logic_monitor_tag) as *const FFITag,
+            &mut logic_monitor_handle as *mut FFIHandle,
+        );
+        (health_monitor_handle, logic_monitor_handle)
+    }
+
+    #[test]
+    fn logic_monitor_builder_with_reset_policy_succeeds() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let state1 = StateTag::from("state1");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(state1) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let result = logic_monitor_builder_with_reset_policy(logic_monitor_builder_handle, ResetPolicy::AnyState as u8);
+        assert_eq!(result, FFICode::Success);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn logic_monitor_builder_with_reset_policy_invalid_policy() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let state1 = StateTag::from("state1");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(state1) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let result = logic_monitor_builder_with_reset_policy(logic_monitor_builder_handle, 42);
+        assert_eq!(result, FFICode::InvalidArgument);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn logic_monitor_builder_with_reset_policy_null_builder() {
+        let result = logic_monitor_builder_with_reset_policy(null_mut(), ResetPolicy::AnyState as u8);
+        assert_eq!(result, FFICode::NullParameter);
+    }
+
+    #[test]
+    fn logic_monitor_builder_with_history_capacity_succeeds() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let state1 = StateTag::from("state1");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(state1) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let result = logic_monitor_builder_with_history_capacity(logic_monitor_builder_handle, 4);
+        assert_eq!(result, FFICode::Success);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn logic_monitor_builder_with_history_capacity_null_builder() {
+        let result = logic_monitor_builder_with_history_capacity(null_mut(), 4);
+        assert_eq!(result, FFICode::NullParameter);
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_succeeds() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::AnyState);
+
+        let state1 = StateTag::from("state1");
+        let state2 = StateTag::from("state2");
+        let _ = logic_monitor_transition(logic_monitor_handle, &FFITag::from(state2) as *const FFITag);
+        let result = logic_monitor_reset_to(logic_monitor_handle, &FFITag::from(state1) as *const FFITag);
+        assert_eq!(result, FFICode::Success);
+
+        let mut state_out = MaybeUninit::uninit();
+        let _ = logic_monitor_state(logic_monitor_handle, state_out.as_mut_ptr());
+        // SAFETY: `state_out` is initialized on success.
+        let state = unsafe { StateTag::from_ffi(&state_out.assume_init()) };
+        assert_eq!(state, Some(state1));
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_disabled() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let state1 = StateTag::from("state1");
+        let result = logic_monitor_reset_to(logic_monitor_handle, &FFITag::from(state1) as *const FFITag);
+        assert_eq!(result, FFICode::WrongState);
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_unknown_state() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::AnyState);
+
+        let state3 = StateTag::from("state3");
+        let result = logic_monitor_reset_to(logic_monitor_handle, &FFITag::from(state3) as *const FFITag);
+        assert_eq!(result, FFICode::NotFound);
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_null_monitor() {
+        let state1 = StateTag::from("state1");
+        let result = logic_monitor_reset_to(null_mut(), &FFITag::from(state1) as *const FFITag);
+        assert_eq!(result, FFICode::NullParameter);
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_null_state() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::AnyState);
+
+        let result = logic_monitor_reset_to(logic_monitor_handle, null_mut());
+        assert_eq!(result, FFICode::NullParameter);
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_invalid_transition_succeeds() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let state3 = StateTag::from("state3");
+        let _ = logic_monitor_transition(logic_monitor_handle, &FFITag::from(state3) as *const FFITag);
+
+        let mut source_out = MaybeUninit::uninit();
+        let mut target_out = MaybeUninit::uninit();
+        let result =
+            logic_monitor_invalid_transition(logic_monitor_handle, source_out.as_mut_ptr(), target_out.as_mut_ptr());
+        assert_eq!(result, FFICode::Success);
+        // SAFETY: `source_out` and `target_out` are initialized on success.
+        unsafe {
+            assert_eq!(
+                StateTag::from_ffi(&source_out.assume_init()),
+                Some(StateTag::from("state1"))
+            );
+            assert_eq!(StateTag::from_ffi(&target_out.assume_init()), Some(state3));
+        }
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_invalid_transition_not_found() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let mut source_out = MaybeUninit::uninit();
+        let mut target_out = MaybeUninit::uninit();
+        let result =
+            logic_monitor_invalid_transition(logic_monitor_handle, source_out.as_mut_ptr(), target_out.as_mut_ptr());
+        assert_eq!(result, FFICode::NotFound);
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_invalid_transition_null_parameters() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let mut tag_out = MaybeUninit::uninit();
+        assert_eq!(
+            logic_monitor_invalid_transition(null_mut(), tag_out.as_mut_ptr(), tag_out.as_mut_ptr()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_invalid_transition(logic_monitor_handle, null_mut(), tag_out.as_mut_ptr()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_invalid_transition(logic_monitor_handle, tag_out.as_mut_ptr(), null_mut()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_builder_add_event_succeeds() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let state1 = StateTag::from("state1");
+        let event = EventTag::from("event");
+        let _ = logic_monitor_builder_create(
+            &FFITag::from(state1) as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        let result = logic_monitor_builder_add_event(
+            logic_monitor_builder_handle,
+            &FFITag::from(state1) as *const FFITag,
+            &FFITag::from(event) as *const FFITag,
+            &FFITag::from(state1) as *const FFITag,
+        );
+        assert_eq!(result, FFICode::Success);
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn logic_monitor_builder_add_event_null_parameters() {
+        let mut logic_monitor_builder_handle: FFIHandle = null_mut();
+
+        let tag = FFITag::from(StateTag::from("state1"));
+        let _ = logic_monitor_builder_create(
+            &tag as *const FFITag,
+            &mut logic_monitor_builder_handle as *mut FFIHandle,
+        );
+
+        assert_eq!(
+            logic_monitor_builder_add_event(null_mut(), &tag, &tag, &tag),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_builder_add_event(logic_monitor_builder_handle, null_mut(), &tag, &tag),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_builder_add_event(logic_monitor_builder_handle, &tag, null_mut(), &tag),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_builder_add_event(logic_monitor_builder_handle, &tag, &tag, null_mut()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        logic_monitor_builder_destroy(logic_monitor_builder_handle);
+    }
+
+    #[test]
+    fn logic_monitor_event_succeeds() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let mut state_out = MaybeUninit::uninit();
+        let result = logic_monitor_event(
+            logic_monitor_handle,
+            &FFITag::from(EventTag::from("next")) as *const FFITag,
+            state_out.as_mut_ptr(),
+        );
+        assert_eq!(result, FFICode::Success);
+        // SAFETY: `state_out` is initialized on success.
+        let state = unsafe { StateTag::from_ffi(&state_out.assume_init()) };
+        assert_eq!(state, Some(StateTag::from("state2")));
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_event_fails() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let event = EventTag::from("unknown");
+        let mut state_out = MaybeUninit::uninit();
+        let result = logic_monitor_event(
+            logic_monitor_handle,
+            &FFITag::from(event) as *const FFITag,
+            state_out.as_mut_ptr(),
+        );
+        assert_eq!(result, FFICode::Failed);
+
+        let mut event_out = MaybeUninit::uninit();
+        let result =
+            logic_monitor_unexpected_event(logic_monitor_handle, state_out.as_mut_ptr(), event_out.as_mut_ptr());
+        assert_eq!(result, FFICode::Success);
+        // SAFETY: `state_out` and `event_out` are initialized on success.
+        unsafe {
+            assert_eq!(
+                StateTag::from_ffi(&state_out.assume_init()),
+                Some(StateTag::from("state1"))
+            );
+            assert_eq!(EventTag::from_ffi(&event_out.assume_init()), Some(event));
+        }
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_event_null_parameters() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let event = FFITag::from(EventTag::from("next"));
+        let mut state_out = MaybeUninit::uninit();
+        assert_eq!(
+            logic_monitor_event(null_mut(), &event, state_out.as_mut_ptr()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_event(logic_monitor_handle, null_mut(), state_out.as_mut_ptr()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_event(logic_monitor_handle, &event, null_mut()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_unexpected_event_not_found() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let mut state_out = MaybeUninit::uninit();
+        let mut event_out = MaybeUninit::uninit();
+        let result =
+            logic_monitor_unexpected_event(logic_monitor_handle, state_out.as_mut_ptr(), event_out.as_mut_ptr());
+        assert_eq!(result, FFICode::NotFound);
+        assert_eq!(
+            logic_monitor_unexpected_event(null_mut(), state_out.as_mut_ptr(), event_out.as_mut_ptr()),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_history_succeeds() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::AnyState);
+
+        let state1 = StateTag::from("state1");
+        let state2 = StateTag::from("state2");
+        let _ = logic_monitor_transition(logic_monitor_handle, &FFITag::from(state2) as *const FFITag);
+        let _ = logic_monitor_transition(logic_monitor_handle, &FFITag::from(state1) as *const FFITag);
+        let _ = logic_monitor_reset_to(logic_monitor_handle, &FFITag::from(state1) as *const FFITag);
+
+        // Query number of records.
+        let mut num_records: usize = 0;
+        let result = logic_monitor_history(logic_monitor_handle, null_mut(), 0, &mut num_records as *mut usize);
+        assert_eq!(result, FFICode::Success);
+        assert_eq!(num_records, 3);
+
+        // Only the most recent records are provided.
+        let mut records: [MaybeUninit<TransitionRecordCpp>; 2] = [MaybeUninit::uninit(); 2];
+        let result = logic_monitor_history(
+            logic_monitor_handle,
+            records.as_mut_ptr().cast(),
+            records.len(),
+            &mut num_records as *mut usize,
+        );
+        assert_eq!(result, FFICode::Success);
+        assert_eq!(num_records, 3);
+        // SAFETY: records are initialized on success, up to the provided capacity.
+        let records = records.map(|record| unsafe { record.assume_init() });
+        unsafe {
+            assert_eq!(StateTag::from_ffi(&records[0].source), Some(state2));
+            assert_eq!(StateTag::from_ffi(&records[0].target), Some(state1));
+            assert_eq!(StateTag::from_ffi(&records[1].target), Some(state1));
+        }
+        assert!(!records[0].has_event);
+        assert!(!records[1].has_event);
+        assert!(!records[0].reset);
+        assert_eq!(records[0].error_code, LogicEvaluationError::InvalidTransition as u8);
+        assert!(records[1].reset);
+        assert_eq!(records[1].error_code, 0);
+        assert!(records[0].age_ns >= records[1].age_ns);
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_history_event() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let event = EventTag::from("next");
+        let mut state_out = MaybeUninit::uninit();
+        let _ = logic_monitor_event(
+            logic_monitor_handle,
+            &FFITag::from(event) as *const FFITag,
+            state_out.as_mut_ptr(),
+        );
+
+        let mut num_records: usize = 0;
+        let mut records: [MaybeUninit<TransitionRecordCpp>; 1] = [MaybeUninit::uninit(); 1];
+        let result = logic_monitor_history(
+            logic_monitor_handle,
+            records.as_mut_ptr().cast(),
+            records.len(),
+            &mut num_records as *mut usize,
+        );
+        assert_eq!(result, FFICode::Success);
+        assert_eq!(num_records, 1);
+        // SAFETY: records are initialized on success, up to the provided capacity.
+        let records = records.map(|record| unsafe { record.assume_init() });
+        assert!(records[0].has_event);
+        unsafe {
+            assert_eq!(EventTag::from_ffi(&records[0].event), Some(event));
+            assert_eq!(StateTag::from_ffi(&records[0].target), Some(StateTag::from("state2")));
+        }
+        assert_eq!(records[0].error_code, 0);
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
+
+    #[test]
+    fn logic_monitor_history_null_parameters() {
+        let (health_monitor_handle, logic_monitor_handle) = create_logic_monitor(ResetPolicy::Disabled);
+
+        let mut num_records: usize = 0;
+        assert_eq!(
+            logic_monitor_history(null_mut(), null_mut(), 0, &mut num_records as *mut usize),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_history(logic_monitor_handle, null_mut(), 0, null_mut()),
+            FFICode::NullParameter
+        );
+        assert_eq!(
+            logic_monitor_history(logic_monitor_handle, null_mut(), 2, &mut num_records as *mut usize),
+            FFICode::NullParameter
+        );
+
+        // Clean-up.
+        logic_monitor_destroy(logic_monitor_handle);
+        health_monitor_destroy(health_monitor_handle);
+    }
 }
@@ -0,0 +1,302 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::common::TimeRange;
+use crate::tag::StateTag;
+use core::fmt::Write;
+use std::collections::HashSet;
+
+/// Findings of a logic monitor state graph analysis.
+/// States are ordered by tag.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GraphAnalysis {
+    /// States not reachable from the initial state.
+    pub unreachable_states: Vec<StateTag>,
+    /// States without transitions to other states.
+    pub dead_end_states: Vec<StateTag>,
+    /// States with a transition to themselves.
+    pub self_loops: Vec<StateTag>,
+    /// Transitions to states which are not defined, as source and target pairs.
+    pub undefined_targets: Vec<(StateTag, StateTag)>,
+}
+
+impl GraphAnalysis {
+    /// No findings were reported.
+    pub fn is_empty(&self) -> bool {
+        self.unreachable_states.is_empty()
+            && self.dead_end_states.is_empty()
+            && self.self_loops.is_empty()
+            && self.undefined_targets.is_empty()
+    }
+}
+
+/// State of a [`StateGraph`].
+pub(super) struct GraphNode<'a> {
+    pub(super) tag: StateTag,
+    pub(super) allowed_targets: &'a [StateTag],
+    pub(super) dwell: Option<TimeRange>,
+}
+
+/// Read-only view of a state graph, shared by the builder and the monitor.
+pub(super) struct StateGraph<'a> {
+    initial_state: StateTag,
+    nodes: Vec<GraphNode<'a>>,
+}
+
+impl<'a> StateGraph<'a> {
+    /// Create a graph view, nodes are ordered by tag for a deterministic output.
+    pub(super) fn new(initial_state: StateTag, nodes: impl Iterator<Item = GraphNode<'a>>) -> Self {
+        let mut nodes: Vec<GraphNode<'a>> = nodes.collect();
+        nodes.sort_by(|a, b| a.tag.as_str().cmp(b.tag.as_str()));
+        Self { initial_state, nodes }
+    }
+
+    fn find_node(&self, tag: StateTag) -> Option<&GraphNode<'a>> {
+        self.nodes.iter().find(|node| node.tag == tag)
+    }
+
+    /// Analyze the graph.
+    pub(super) fn analyze(&self) -> GraphAnalysis {
+        let mut analysis = GraphAnalysis::default();
+
+        // Traverse the graph, starting from the initial state.
+        let mut reached = HashSet::new();
+        let mut pending = Vec::new();
+        if self.find_node(self.initial_state).is_some() {
+            reached.insert(self.initial_state);
+            pending.push(self.initial_state);
+        }
+        while let Some(state) = pending.pop() {
+            let Some(node) = self.find_node(state) else {
+                continue;
+            };
+            for target in node.allowed_targets.iter() {
+                if self.find_node(*target).is_some() && reached.insert(*target) {
+                    pending.push(*target);
+                }
+            }
+        }
+
+        for node in self.nodes.iter() {
+            if !reached.contains(&node.tag) {
+                analysis.unreachable_states.push(node.tag);
+            }
+            if node.allowed_targets.iter().all(|target| *target == node.tag) {
+                analysis.dead_end_states.push(node.tag);
+            }
+            if node.allowed_targets.contains(&node.tag) {
+                analysis.self_loops.push(node.tag);
+            }
+            for target in node.allowed_targets.iter() {
+                if self.find_node(*target).is_none() {
+                    analysis.undefined_targets.push((node.tag, *target));
+                }
+            }
+        }
+
+        analysis
+    }
+
+    /// Export the graph in Graphviz DOT format.
+    pub(super) fn to_dot(&self, name: &str) -> String {
+        let mut output = String::new();
+        let _ = writeln!(output, "digraph {} {{", dot_quote(name));
+        let _ = writeln!(output, "    __initial [shape=point];");
+        let _ = writeln!(output, "    __initial -> {};", dot_quote(self.initial_state.as_str()));
+        for node in self.nodes.iter() {
+            match node.dwell {
+                Some(dwell) => {
+                    let label = format!("{}\n{}", node.tag.as_str(), dwell_label(dwell));
+                    let _ = writeln!(
+                        output,
+                        "    {} [label={}];",
+                        dot_quote(node.tag.as_str()),
+                        dot_quote(&label)
+                    );
+                },
+                None => {
+                    let _ = writeln!(output, "    {};", dot_quote(node.tag.as_str()));
+                },
+            }
+        }
+        for node in self.nodes.iter() {
+            for target in node.allowed_targets.iter() {
+                let _ = writeln!(
+                    output,
+                    "    {} -> {};",
+                    dot_quote(node.tag.as_str()),
+                    dot_quote(target.as_str())
+                );
+            }
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// Export the graph as a PlantUML state diagram.
+    pub(super) fn to_plantuml(&self) -> String {
+        // States are referred to by aliases, tags are not required to be valid PlantUML identifiers.
+        let mut aliases: Vec<StateTag> = self.nodes.iter().map(|node| node.tag).collect();
+        for node in self.nodes.iter() {
+            for target in node.allowed_targets.iter() {
+                if !aliases.contains(target) {
+                    aliases.push(*target);
+                }
+            }
+        }
+        if !aliases.contains(&self.initial_state) {
+            aliases.push(self.initial_state);
+        }
+        let alias = |tag: StateTag| format!("s{}", aliases.iter().position(|other| *other == tag).unwrap_or(0));
+
+        let mut output = String::from("@startuml\n");
+        for tag in aliases.iter() {
+            let _ = writeln!(
+                output,
+                "state \"{}\" as {}",
+                tag.as_str().replace('"', "'"),
+                alias(*tag)
+            );
+        }
+        for node in self.nodes.iter() {
+            if let Some(dwell) = node.dwell {
+                let _ = writeln!(output, "{} : {}", alias(node.tag), dwell_label(dwell));
+            }
+        }
+        let _ = writeln!(output, "[*] --> {}", alias(self.initial_state));
+        for node in self.nodes.iter() {
+            for target in node.allowed_targets.iter() {
+                let _ = writeln!(output, "{} --> {}", alias(node.tag), alias(*target));
+            }
+        }
+        output.push_str("@enduml\n");
+        output
+    }
+}
+
+/// Quote and escape an identifier for DOT format.
+fn dot_quote(value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+/// Dwell time description.
+fn dwell_label(dwell: TimeRange) -> String {
+    format!("dwell {}..{} ms", dwell.min.as_millis(), dwell.max.as_millis())
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::TimeRange;
+    use crate::logic::graph::{dot_quote, GraphAnalysis, GraphNode, StateGraph};
+    use crate::tag::StateTag;
+    use core::time::Duration;
+
+    fn graph<'a>(initial_state: &str, nodes: &'a [(StateTag, Vec<StateTag>)]) -> StateGraph<'a> {
+        StateGraph::new(
+            StateTag::from(initial_state),
+            nodes.iter().map(|(tag, allowed_targets)| GraphNode {
+                tag: *tag,
+                allowed_targets,
+                dwell: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn analyze_clean_graph() {
+        let (off, on) = (StateTag::from("off"), StateTag::from("on"));
+        let nodes = [(off, vec![on]), (on, vec![off])];
+        let analysis = graph("off", &nodes).analyze();
+        assert!(analysis.is_empty());
+        assert_eq!(analysis, GraphAnalysis::default());
+    }
+
+    #[test]
+    fn analyze_reports_findings() {
+        let (off, on, idle, broken, unknown) = (
+            StateTag::from("off"),
+            StateTag::from("on"),
+            StateTag::from("idle"),
+            StateTag::from("broken"),
+            StateTag::from("unknown"),
+        );
+        let nodes = [
+            (off, vec![on]),
+            (on, vec![on, idle, unknown]),
+            (idle, vec![idle]),
+            (broken, vec![off]),
+        ];
+        let analysis = graph("off", &nodes).analyze();
+        assert!(!analysis.is_empty());
+        assert_eq!(analysis.unreachable_states, vec![broken]);
+        assert_eq!(analysis.dead_end_states, vec![idle]);
+        assert_eq!(analysis.self_loops, vec![idle, on]);
+        assert_eq!(analysis.undefined_targets, vec![(on, unknown)]);
+    }
+
+    #[test]
+    fn analyze_undefined_initial_state() {
+        let off = StateTag::from("off");
+        let nodes = [(off, vec![off])];
+        let analysis = graph("undefined", &nodes).analyze();
+        assert_eq!(analysis.unreachable_states, vec![off]);
+    }
+
+    #[test]
+    fn to_dot_succeeds() {
+        let (off, on) = (StateTag::from("off"), StateTag::from("on"));
+        let nodes = [(off, vec![on]), (on, vec![off])];
+        let graph = StateGraph::new(
+            off,
+            nodes.iter().map(|(tag, allowed_targets)| GraphNode {
+                tag: *tag,
+                allowed_targets,
+                dwell: (*tag == on).then(|| TimeRange::new(Duration::from_millis(100), Duration::from_millis(200))),
+            }),
+        );
+        let expected = concat!(
+            "digraph \"engine\" {\n",
+            "    __initial [shape=point];\n",
+            "    __initial -> \"off\";\n",
+            "    \"off\";\n",
+            "    \"on\" [label=\"on\\ndwell 100..200 ms\"];\n",
+            "    \"off\" -> \"on\";\n",
+            "    \"on\" -> \"off\";\n",
+            "}\n",
+        );
+        assert_eq!(graph.to_dot("engine"), expected);
+    }
+
+    #[test]
+    fn to_plantuml_succeeds() {
+        let (off, on) = (StateTag::from("off"), StateTag::from("on"));
+        let nodes = [(off, vec![on]), (on, vec![off, on])];
+        let expected = concat!(
+            "@startuml\n",
+            "state \"off\" as s0\n",
+            "state \"on\" as s1\n",
+            "[*] --> s0\n",
+            "s0 --> s1\n",
+            "s1 --> s0\n",
+            "s1 --> s1\n",
+            "@enduml\n",
+        );
+        assert_eq!(graph("off", &nodes).to_plantuml(), expected);
+    }
+
+    #[test]
+    fn dot_quote_escapes() {
+        assert_eq!(dot_quote("a\"b\\c"), "\"a\\\"b\\\\c\"");
+    }
+}
@@ -11,12 +11,21 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use crate::common::{Monitor, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator, PhantomUnsync};
+use crate::clock::Clock;
+use crate::common::{
+    Monitor, MonitorEvalHandle, MonitorEvaluator, Mutex, MutexGuard, OnErrorFn, PhantomUnsync, TimeRange,
+};
 use crate::health_monitor::HealthMonitorError;
-use crate::log::{error, warn, ScoreDebug};
-use crate::logic::logic_state::LogicState;
-use crate::protected_memory::ProtectedMemoryAllocator;
-use crate::tag::{MonitorTag, StateTag};
+use crate::log::{error, info, warn, ScoreDebug};
+use crate::logic::graph::{GraphAnalysis, GraphNode, StateGraph};
+use crate::logic::logic_state::{LogicState, LogicStateSnapshot, MAX_STATES, MAX_TIME_IN_STATE};
+use crate::logic::transition_history::{RawRecord, TransitionHistory, TransitionRecord};
+use crate::logic::transition_table::TransitionTable;
+use crate::logic::LogicStates;
+use crate::protected_memory::{ProtectedBox, ProtectedMemoryAllocator};
+use crate::status::FailureOrigin;
+use crate::tag::{EventTag, MonitorTag, StateTag};
+use core::cmp::min;
 use core::hash::Hash;
 use core::marker::PhantomData;
 use std::collections::HashMap;
@@ -26,6 +35,38 @@ use std::time::Instant;
 /// Internal OK state representation.
 pub(super) const OK_STATE: u8 = 0;
 
+/// Default number of transitions kept in the history.
+const DEFAULT_HISTORY_CAPACITY: usize = 16;
+
+/// States to which [`LogicMonitor::reset_to`] is allowed.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum ResetPolicy {
+    /// Reset is not allowed.
+    #[default]
+    Disabled,
+    /// Reset is allowed only to the initial state.
+    InitialState,
+    /// Reset is allowed to any defined state.
+    AnyState,
+}
+
+impl TryFrom<u8> for ResetPolicy {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        const DISABLED: u8 = ResetPolicy::Disabled as u8;
+        const INITIAL_STATE: u8 = ResetPolicy::InitialState as u8;
+        const ANY_STATE: u8 = ResetPolicy::AnyState as u8;
+        match value {
+            DISABLED => Ok(ResetPolicy::Disabled),
+            INITIAL_STATE => Ok(ResetPolicy::InitialState),
+            ANY_STATE => Ok(ResetPolicy::AnyState),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Logic evaluation errors.
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ScoreDebug)]
@@ -36,6 +77,12 @@ pub enum LogicEvaluationError {
     InvalidTransition,
     /// Unknown error.
     UnmappedError,
+    /// Logic state memory is corrupted.
+    MemoryCorrupted,
+    /// State was left before its minimal dwell time.
+    TooEarly,
+    /// State was held longer than its maximal dwell time.
+    TooLate,
 }
 
 impl From<LogicEvaluationError> for u8 {
@@ -51,19 +98,51 @@ impl TryFrom<u8> for LogicEvaluationError {
         const INVALID_STATE: u8 = LogicEvaluationError::InvalidState as u8;
         const INVALID_TRANSITION: u8 = LogicEvaluationError::InvalidTransition as u8;
         const UNMAPPED_ERROR: u8 = LogicEvaluationError::UnmappedError as u8;
+        const MEMORY_CORRUPTED: u8 = LogicEvaluationError::MemoryCorrupted as u8;
+        const TOO_EARLY: u8 = LogicEvaluationError::TooEarly as u8;
+        const TOO_LATE: u8 = LogicEvaluationError::TooLate as u8;
         match value {
             INVALID_STATE => Ok(LogicEvaluationError::InvalidState),
             INVALID_TRANSITION => Ok(LogicEvaluationError::InvalidTransition),
             UNMAPPED_ERROR => Ok(LogicEvaluationError::UnmappedError),
+            MEMORY_CORRUPTED => Ok(LogicEvaluationError::MemoryCorrupted),
+            TOO_EARLY => Ok(LogicEvaluationError::TooEarly),
+            TOO_LATE => Ok(LogicEvaluationError::TooLate),
             _ => Err(()),
         }
     }
 }
 
+/// Precomputed handle of a logic monitor state, refer to [`LogicMonitor::state_index`].
+/// Handle is only valid for the monitor which provided it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StateIndex {
+    index: usize,
+    tag: StateTag,
+}
+
+impl StateIndex {
+    /// Tag of the state.
+    pub fn tag(&self) -> StateTag {
+        self.tag
+    }
+}
+
 /// Node containing state data.
 struct StateNode {
     tag: StateTag,
     allowed_targets: Vec<StateTag>,
+    dwell: Option<TimeRange>,
+    events: Vec<(EventTag, StateTag)>,
+}
+
+/// Target of a transition request.
+#[derive(Clone, Copy)]
+enum TransitionTarget {
+    /// Requested state, index is [`None`] for unknown states.
+    State(Option<usize>, StateTag),
+    /// State to which the event leads from the current state.
+    Event(EventTag),
 }
 
 /// Builder for [`LogicMonitor`].
@@ -75,6 +154,19 @@ pub struct LogicMonitorBuilder {
     /// State graph.
     /// Contains state as a key and allowed transition targets as value.
     state_graph: HashMap<StateTag, Vec<StateTag>>,
+
+    /// Allowed time spent in a state, only for time-bounded states.
+    dwell_ranges: HashMap<StateTag, TimeRange>,
+
+    /// Event-triggered transitions.
+    /// Contains state and event as a key and target state as value.
+    events: HashMap<(StateTag, EventTag), StateTag>,
+
+    /// States to which reset is allowed.
+    reset_policy: ResetPolicy,
+
+    /// Number of transitions kept in the history.
+    history_capacity: usize,
 }
 
 impl LogicMonitorBuilder {
@@ -85,6 +177,10 @@ impl LogicMonitorBuilder {
         Self {
             initial_state,
             state_graph: HashMap::new(),
+            dwell_ranges: HashMap::new(),
+            events: HashMap::new(),
+            reset_policy: ResetPolicy::default(),
+            history_capacity: DEFAULT_HISTORY_CAPACITY,
         }
     }
 
@@ -95,20 +191,93 @@ impl LogicMonitorBuilder {
         self
     }
 
+    /// Add time-bounded state along with allowed transitions.
+    /// If state already exists - it is overwritten.
+    ///
+    /// - `dwell` - allowed time spent in the state.
+    ///   Leaving the state earlier results in [`LogicEvaluationError::TooEarly`].
+    ///   Holding the state longer results in [`LogicEvaluationError::TooLate`], also reported by background evaluation.
+    ///
+    /// Time spent in the initial state is measured from the start of monitoring.
+    pub fn add_timed_state(mut self, state: StateTag, allowed_targets: &[StateTag], dwell: TimeRange) -> Self {
+        self.add_timed_state_internal(state, allowed_targets, dwell);
+        self
+    }
+
+    /// Add transition triggered by an event, refer to [`LogicMonitor::event`].
+    /// If event already exists for the state - it is overwritten.
+    ///
+    /// - `state` - state in which the event is expected.
+    /// - `event` - event triggering the transition.
+    /// - `target` - state entered on the event, must be an allowed target of `state`.
+    pub fn add_event(mut self, state: StateTag, event: EventTag, target: StateTag) -> Self {
+        self.add_event_internal(state, event, target);
+        self
+    }
+
+    /// Create a new [`LogicMonitorBuilder`] with states and transitions defined by `E`.
+    /// The first state of `E` is the initial state, refer to [`crate::logic_states`].
+    pub fn from_states<E: LogicStates>() -> Self {
+        let mut builder = Self::new(E::STATES[0].tag());
+        for state in E::STATES.iter() {
+            let allowed_targets: Vec<StateTag> = state.allowed_targets().iter().map(|target| target.tag()).collect();
+            builder.add_state_internal(state.tag(), &allowed_targets);
+        }
+        builder
+    }
+
+    /// Set states to which [`LogicMonitor::reset_to`] is allowed.
+    /// By default, reset is disabled.
+    pub fn with_reset_policy(mut self, reset_policy: ResetPolicy) -> Self {
+        self.with_reset_policy_internal(reset_policy);
+        self
+    }
+
+    /// Set number of transitions kept in the history, refer to [`LogicMonitor::history`].
+    /// By default, 16 last transitions are kept. Zero disables the history.
+    pub fn with_history_capacity(mut self, capacity: usize) -> Self {
+        self.with_history_capacity_internal(capacity);
+        self
+    }
+
+    /// Analyze the state graph.
+    /// Reports unreachable states, dead-end states, self-loops and undefined targets.
+    pub fn analyze(&self) -> GraphAnalysis {
+        self.graph().analyze()
+    }
+
+    /// Export the state graph in Graphviz DOT format.
+    ///
+    /// - `name` - name of the graph.
+    pub fn to_dot(&self, name: &str) -> String {
+        self.graph().to_dot(name)
+    }
+
+    /// Export the state graph as a PlantUML state diagram.
+    pub fn to_plantuml(&self) -> String {
+        self.graph().to_plantuml()
+    }
+
     /// Build the [`LogicMonitor`].
     ///
     /// - `monitor_tag` - tag of this monitor.
-    /// - `_allocator` - protected memory allocator.
+    /// - `clock` - clock used to measure time spent in states.
+    /// - `allocator` - protected memory allocator.
     pub(crate) fn build(
         self,
         monitor_tag: MonitorTag,
-        _allocator: &ProtectedMemoryAllocator,
+        clock: Clock,
+        allocator: &ProtectedMemoryAllocator,
     ) -> Result<LogicMonitor, HealthMonitorError> {
         // Check number of states.
         if self.state_graph.is_empty() {
             error!("No states have been added. LogicMonitor cannot be created.");
             return Err(HealthMonitorError::WrongState);
         }
+        if self.state_graph.len() > MAX_STATES {
+            error!("Too many states have been added: {:?}", self.state_graph.len());
+            return Err(HealthMonitorError::InvalidArgument);
+        }
 
         // Check transitions are between defined states.
         for (state, allowed_targets) in self.state_graph.iter() {
@@ -123,12 +292,43 @@ impl LogicMonitorBuilder {
             }
         }
 
+        // Check events lead to allowed targets.
+        for ((state, event), target) in self.events.iter() {
+            let allowed = self
+                .state_graph
+                .get(state)
+                .is_some_and(|allowed_targets| allowed_targets.contains(target));
+            if !allowed {
+                error!(
+                    "Event leads to a disallowed transition. Event: {:?}, origin: {:?}, target: {:?}",
+                    event, state, target
+                );
+                return Err(HealthMonitorError::InvalidArgument);
+            }
+        }
+
+        // Check time spent in time-bounded states can be measured.
+        for (state, dwell) in self.dwell_ranges.iter() {
+            if clock.ticks(dwell.max) > MAX_TIME_IN_STATE {
+                error!("Dwell time of state ({:?}) exceeds measurable range", state);
+                return Err(HealthMonitorError::InvalidArgument);
+            }
+        }
+
         // Convert builder-internal representation into monitor-internal representation.
         let mut state_graph_vec = Vec::new();
         for (state, allowed_targets) in self.state_graph.into_iter() {
+            let events = self
+                .events
+                .iter()
+                .filter(|((source, _), _)| *source == state)
+                .map(|((_, event), target)| (*event, *target))
+                .collect();
             state_graph_vec.push(StateNode {
                 tag: state,
                 allowed_targets,
+                dwell: self.dwell_ranges.get(&state).copied(),
+                events,
             });
         }
 
@@ -150,8 +350,12 @@ impl LogicMonitorBuilder {
 
         let inner = Arc::new(LogicMonitorInner::new(
             monitor_tag,
+            clock,
             initial_state_index,
             state_graph_vec,
+            self.reset_policy,
+            self.history_capacity,
+            allocator,
         ));
         Ok(LogicMonitor::new(inner))
     }
@@ -160,6 +364,35 @@ impl LogicMonitorBuilder {
 
     pub(crate) fn add_state_internal(&mut self, state: StateTag, allowed_targets: &[StateTag]) {
         self.state_graph.insert(state, allowed_targets.to_vec());
+        self.dwell_ranges.remove(&state);
+    }
+
+    pub(crate) fn add_timed_state_internal(&mut self, state: StateTag, allowed_targets: &[StateTag], dwell: TimeRange) {
+        self.state_graph.insert(state, allowed_targets.to_vec());
+        self.dwell_ranges.insert(state, dwell);
+    }
+
+    pub(crate) fn add_event_internal(&mut self, state: StateTag, event: EventTag, target: StateTag) {
+        self.events.insert((state, event), target);
+    }
+
+    fn graph(&self) -> StateGraph<'_> {
+        StateGraph::new(
+            self.initial_state,
+            self.state_graph.iter().map(|(state, allowed_targets)| GraphNode {
+                tag: *state,
+                allowed_targets,
+                dwell: self.dwell_ranges.get(state).copied(),
+            }),
+        )
+    }
+
+    pub(crate) fn with_reset_policy_internal(&mut self, reset_policy: ResetPolicy) {
+        self.reset_policy = reset_policy;
+    }
+
+    pub(crate) fn with_history_capacity_internal(&mut self, capacity: usize) {
+        self.history_capacity = capacity;
     }
 }
 
@@ -188,6 +421,151 @@ impl LogicMonitor {
     pub fn state(&self) -> Result<StateTag, LogicEvaluationError> {
         self.inner.state()
     }
+
+    /// Report an event, performing transition defined with [`LogicMonitorBuilder::add_event`].
+    /// Event not expected in the current state is an [`LogicEvaluationError::InvalidTransition`].
+    /// On success, current state is returned.
+    pub fn event(&self, event: EventTag) -> Result<StateTag, LogicEvaluationError> {
+        self.inner.event(event)
+    }
+
+    /// Handle of a given state, allowing transitions without tag lookup.
+    /// Returns [`None`] if the state is not defined.
+    pub fn state_index(&self, state: StateTag) -> Option<StateIndex> {
+        self.inner.state_index(state)
+    }
+
+    /// Perform transition to a new state using its handle, refer to [`LogicMonitor::state_index`].
+    /// Handle of another monitor is treated as an unknown state.
+    /// On success, current state is returned.
+    ///
+    /// Successful transition never locks. Clock is read only for time-bounded states or if the history is enabled.
+    pub fn transition_by_index(&self, state: StateIndex) -> Result<StateIndex, LogicEvaluationError> {
+        self.inner.transition_by_index(state)
+    }
+
+    /// Handle of the current monitor state.
+    pub fn current_state_index(&self) -> Result<StateIndex, LogicEvaluationError> {
+        self.inner.current_state_index()
+    }
+
+    /// Force the monitor into a given state, discarding its error status.
+    /// Time spent in the state is measured from the reset.
+    ///
+    /// Reset must be allowed by the [`ResetPolicy`] set with [`LogicMonitorBuilder::with_reset_policy`]:
+    /// - [`HealthMonitorError::WrongState`] is returned if reset is disabled.
+    /// - [`HealthMonitorError::InvalidArgument`] is returned if reset to the given state is not allowed.
+    /// - [`HealthMonitorError::NotFound`] is returned if the state is not defined.
+    ///
+    /// Failures already reported to the health monitor are not affected.
+    pub fn reset_to(&self, state: StateTag) -> Result<StateTag, HealthMonitorError> {
+        self.inner.reset_to(state)
+    }
+
+    /// Last transitions, starting from the oldest one.
+    /// Rejected transitions and resets are also included.
+    pub fn history(&self) -> Vec<TransitionRecord> {
+        self.inner.history()
+    }
+
+    /// Source and target state of the last invalid transition, until the error is discarded.
+    pub fn invalid_transition(&self) -> Option<(StateTag, StateTag)> {
+        self.inner.invalid_transition()
+    }
+
+    /// State in which the last unexpected event was reported and the event, until the error is discarded.
+    pub fn unexpected_event(&self) -> Option<(StateTag, EventTag)> {
+        self.inner.unexpected_event()
+    }
+
+    /// Export the state graph in Graphviz DOT format, named after the monitor tag.
+    pub fn to_dot(&self) -> String {
+        self.inner.graph().to_dot(self.inner.monitor_tag.as_str())
+    }
+
+    /// Export the state graph as a PlantUML state diagram.
+    pub fn to_plantuml(&self) -> String {
+        self.inner.graph().to_plantuml()
+    }
+
+    /// Check whether the state is defined.
+    pub(crate) fn is_defined(&self, state: StateTag) -> bool {
+        self.inner.state_indices.contains_key(&state)
+    }
+
+    /// Convert into a [`SharedLogicMonitor`], allowing transitions from multiple threads.
+    pub fn into_shared(self) -> SharedLogicMonitor {
+        SharedLogicMonitor { inner: self.inner }
+    }
+}
+
+/// Logic monitor allowing transitions from multiple threads, e.g., shared using [`Arc`].
+///
+/// Transitions are performed atomically, concurrent transitions are applied one after another.
+/// Transitions never wait for each other - transition racing with another one is retried
+/// and validated against the state resulting from the other one.
+/// Order of concurrent transitions in the history might differ from the order in which they were applied.
+pub struct SharedLogicMonitor {
+    inner: Arc<LogicMonitorInner>,
+}
+
+impl SharedLogicMonitor {
+    /// Perform transition to a new state.
+    /// On success, current state is returned.
+    pub fn transition(&self, state: StateTag) -> Result<StateTag, LogicEvaluationError> {
+        self.inner.transition(state)
+    }
+
+    /// Current monitor state.
+    pub fn state(&self) -> Result<StateTag, LogicEvaluationError> {
+        self.inner.state()
+    }
+
+    /// Report an event, refer to [`LogicMonitor::event`].
+    pub fn event(&self, event: EventTag) -> Result<StateTag, LogicEvaluationError> {
+        self.inner.event(event)
+    }
+
+    /// Handle of a given state, refer to [`LogicMonitor::state_index`].
+    pub fn state_index(&self, state: StateTag) -> Option<StateIndex> {
+        self.inner.state_index(state)
+    }
+
+    /// Perform transition to a new state using its handle, refer to [`LogicMonitor::transition_by_index`].
+    pub fn transition_by_index(&self, state: StateIndex) -> Result<StateIndex, LogicEvaluationError> {
+        self.inner.transition_by_index(state)
+    }
+
+    /// Handle of the current monitor state.
+    pub fn current_state_index(&self) -> Result<StateIndex, LogicEvaluationError> {
+        self.inner.current_state_index()
+    }
+
+    /// Force the monitor into a given state, refer to [`LogicMonitor::reset_to`].
+    pub fn reset_to(&self, state: StateTag) -> Result<StateTag, HealthMonitorError> {
+        self.inner.reset_to(state)
+    }
+
+    /// Last transitions, starting from the oldest one.
+    pub fn history(&self) -> Vec<TransitionRecord> {
+        self.inner.history()
+    }
+
+    /// Source and target state of the last invalid transition, until the error is discarded.
+    pub fn invalid_transition(&self) -> Option<(StateTag, StateTag)> {
+        self.inner.invalid_transition()
+    }
+
+    /// State in which the last unexpected event was reported and the event, until the error is discarded.
+    pub fn unexpected_event(&self) -> Option<(StateTag, EventTag)> {
+        self.inner.unexpected_event()
+    }
+}
+
+impl Monitor for SharedLogicMonitor {
+    fn get_eval_handle(&self) -> MonitorEvalHandle {
+        MonitorEvalHandle::new(Arc::clone(&self.inner))
+    }
 }
 
 impl Monitor for LogicMonitor {
@@ -200,34 +578,283 @@ struct LogicMonitorInner {
     /// Tag of this monitor.
     monitor_tag: MonitorTag,
 
+    /// Clock used to measure time spent in states.
+    clock: Clock,
+
     /// Current logic state.
-    logic_state: LogicState,
+    /// Placed in protected memory, integrity is checked on each evaluation.
+    logic_state: ProtectedBox<LogicState>,
 
     /// State graph.
     /// Contains state and allowed targets.
     state_graph: Vec<StateNode>,
+
+    /// Index of the starting state.
+    initial_state_index: usize,
+
+    /// Index of each state in the state graph.
+    state_indices: HashMap<StateTag, usize>,
+
+    /// Allowed transitions between state indices.
+    transitions: TransitionTable,
+
+    /// Target state index of each event, for each source state index.
+    events: HashMap<(usize, EventTag), usize>,
+
+    /// States to which reset is allowed.
+    reset_policy: ResetPolicy,
+
+    /// Last transitions, recorded without locking.
+    history: TransitionHistory,
+
+    /// Origin of the last invalid transition or unexpected event.
+    /// Locked only when an error is set or discarded.
+    invalid_origin: Mutex<Option<FailureOrigin>>,
 }
 
 impl MonitorEvaluator for LogicMonitorInner {
-    fn evaluate(&self, _hmon_starting_point: Instant, on_error: &mut dyn FnMut(&MonitorTag, MonitorEvaluationError)) {
+    fn evaluate(&self, hmon_starting_point: Instant, on_error: &mut OnErrorFn<'_>) {
+        // Logic state cannot be trusted if memory is corrupted.
+        if !self.logic_state.is_intact() {
+            error!("Logic monitor ({:?}) state memory is corrupted!", self.monitor_tag);
+            on_error(&self.monitor_tag, LogicEvaluationError::MemoryCorrupted.into(), None);
+            return;
+        }
+
         let snapshot = self.logic_state.snapshot();
         if let Err(error) = snapshot.monitor_status() {
             warn!("Logic monitor error observed: {:?}", error);
-            on_error(&self.monitor_tag, error.into());
+            let invalid_origin = match error {
+                LogicEvaluationError::InvalidTransition => *self.invalid_origin(),
+                _ => None,
+            };
+            let origin = match invalid_origin {
+                Some(origin) => Some(origin),
+                None => self
+                    .find_node_by_index(snapshot.current_state_index())
+                    .ok()
+                    .map(|node| FailureOrigin::State(node.tag)),
+            };
+            on_error(&self.monitor_tag, error.into(), origin);
+            return;
         }
+
+        // Check time spent in the current state.
+        let Ok(node) = self.find_node_by_index(snapshot.current_state_index()) else {
+            return;
+        };
+        if let Some(dwell) = node.dwell {
+            // Time spent before the start of monitoring is not taken into account.
+            let now = self.clock.now();
+            let elapsed = min(
+                snapshot.time_in_state(now),
+                now.saturating_sub(self.clock.ticks_at(hmon_starting_point)),
+            );
+            if elapsed > self.clock.ticks(dwell.max) {
+                warn!("State ({:?}) held too long!", node.tag);
+                on_error(
+                    &self.monitor_tag,
+                    LogicEvaluationError::TooLate.into(),
+                    Some(FailureOrigin::State(node.tag)),
+                );
+            }
+        }
+    }
+
+    fn rearm(&self) {
+        // Current state is kept, error is discarded and time spent in the state is measured again.
+        self.logic_state.restart(self.clock.now());
+        *self.invalid_origin() = None;
     }
 }
 
 impl LogicMonitorInner {
-    fn new(monitor_tag: MonitorTag, initial_state_index: usize, state_graph: Vec<StateNode>) -> Self {
-        let logic_state = LogicState::new(initial_state_index);
+    fn new(
+        monitor_tag: MonitorTag,
+        clock: Clock,
+        initial_state_index: usize,
+        state_graph: Vec<StateNode>,
+        reset_policy: ResetPolicy,
+        history_capacity: usize,
+        allocator: &ProtectedMemoryAllocator,
+    ) -> Self {
+        let logic_state = allocator.allocate(LogicState::new(initial_state_index));
+
+        // Compile the graph into index-based lookup tables.
+        let state_indices: HashMap<StateTag, usize> = state_graph
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.tag, index))
+            .collect();
+        let mut transitions = TransitionTable::new(state_graph.len());
+        let mut events = HashMap::new();
+        for (source, node) in state_graph.iter().enumerate() {
+            for target in node.allowed_targets.iter() {
+                if let Some(target) = state_indices.get(target) {
+                    transitions.allow(source, *target);
+                }
+            }
+            for (event, target) in node.events.iter() {
+                if let Some(target) = state_indices.get(target) {
+                    events.insert((source, *event), *target);
+                }
+            }
+        }
+
         LogicMonitorInner {
             monitor_tag,
+            clock,
             logic_state,
             state_graph,
+            initial_state_index,
+            state_indices,
+            transitions,
+            events,
+            reset_policy,
+            history: TransitionHistory::new(history_capacity),
+            invalid_origin: Mutex::new(None),
+        }
+    }
+
+    fn graph(&self) -> StateGraph<'_> {
+        let initial_state = self.state_graph[self.initial_state_index].tag;
+        StateGraph::new(
+            initial_state,
+            self.state_graph.iter().map(|node| GraphNode {
+                tag: node.tag,
+                allowed_targets: &node.allowed_targets,
+                dwell: node.dwell,
+            }),
+        )
+    }
+
+    fn invalid_origin(&self) -> MutexGuard<'_, Option<FailureOrigin>> {
+        self.invalid_origin.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn history(&self) -> Vec<TransitionRecord> {
+        self.history.records(&self.clock)
+    }
+
+    /// Tag of the current state, regardless of monitor status.
+    fn current_state_tag(&self) -> Option<StateTag> {
+        let snapshot = self.logic_state.snapshot();
+        self.find_node_by_index(snapshot.current_state_index())
+            .ok()
+            .map(|node| node.tag)
+    }
+
+    fn invalid_transition(&self) -> Option<(StateTag, StateTag)> {
+        match *self.invalid_origin() {
+            Some(FailureOrigin::Transition(source, target)) => Some((source, target)),
+            _ => None,
+        }
+    }
+
+    fn unexpected_event(&self) -> Option<(StateTag, EventTag)> {
+        match *self.invalid_origin() {
+            Some(FailureOrigin::Event(state, event)) => Some((state, event)),
+            _ => None,
+        }
+    }
+
+    /// Record a transition request, requested at `time` in clock ticks, in the history.
+    fn record(
+        &self,
+        source: Option<StateTag>,
+        target: StateTag,
+        event: Option<EventTag>,
+        time: u64,
+        reset: bool,
+        result: Result<(), LogicEvaluationError>,
+    ) {
+        if let Some(source) = source {
+            self.history.push(RawRecord {
+                source,
+                target,
+                event,
+                time,
+                reset,
+                result,
+            });
+        }
+    }
+
+    fn state_index(&self, state: StateTag) -> Option<StateIndex> {
+        self.state_indices.get(&state).map(|index| StateIndex {
+            index: *index,
+            tag: state,
+        })
+    }
+
+    fn transition(&self, target_state: StateTag) -> Result<StateTag, LogicEvaluationError> {
+        let target_state_index = self.state_indices.get(&target_state).copied();
+        self.transition_to(TransitionTarget::State(target_state_index, target_state))
+            .map(|_| target_state)
+    }
+
+    fn event(&self, event: EventTag) -> Result<StateTag, LogicEvaluationError> {
+        let target_state_index = self.transition_to(TransitionTarget::Event(event))?;
+        self.find_node_by_index(target_state_index).map(|node| node.tag)
+    }
+
+    fn transition_by_index(&self, target_state: StateIndex) -> Result<StateIndex, LogicEvaluationError> {
+        // Handle is only accepted if it matches this monitor.
+        let target_state_index = self
+            .find_node_by_index(target_state.index)
+            .ok()
+            .filter(|node| node.tag == target_state.tag)
+            .map(|_| target_state.index);
+        self.transition_to(TransitionTarget::State(target_state_index, target_state.tag))
+            .map(|_| target_state)
+    }
+
+    /// Perform and record transition.
+    /// Transition is retried if logic state was changed concurrently - another transition succeeded in the meantime.
+    /// On success, index of the current state is returned.
+    fn transition_to(&self, target: TransitionTarget) -> Result<usize, LogicEvaluationError> {
+        loop {
+            if let Some(result) = self.transition_internal(target) {
+                return result;
+            }
         }
     }
 
+    fn reset_to(&self, target_state: StateTag) -> Result<StateTag, HealthMonitorError> {
+        let target_state_index = match self.reset_policy {
+            ResetPolicy::Disabled => {
+                warn!("Logic monitor ({:?}) reset is disabled", self.monitor_tag);
+                return Err(HealthMonitorError::WrongState);
+            },
+            ResetPolicy::InitialState => {
+                let initial_state = self.find_node_by_index(self.initial_state_index).map(|node| node.tag);
+                if initial_state != Ok(target_state) {
+                    warn!(
+                        "Logic monitor ({:?}) reset is only allowed to the initial state, requested: {:?}",
+                        self.monitor_tag, target_state
+                    );
+                    return Err(HealthMonitorError::InvalidArgument);
+                }
+                self.initial_state_index
+            },
+            ResetPolicy::AnyState => self
+                .find_index_by_tag(target_state)
+                .map_err(|_| HealthMonitorError::NotFound)?,
+        };
+
+        let source = self.current_state_tag();
+        info!("Logic monitor ({:?}) reset to {:?}", self.monitor_tag, target_state);
+        let now = self.clock.now();
+        let mut snapshot = LogicStateSnapshot::new(target_state_index);
+        snapshot.set_entry_timestamp(now);
+        let mut invalid_origin = self.invalid_origin();
+        let _ = self.logic_state.swap(snapshot);
+        *invalid_origin = None;
+        drop(invalid_origin);
+        self.record(source, target_state, None, now, true, Ok(()));
+        Ok(target_state)
+    }
+
     fn find_node_by_index(&self, state_index: usize) -> Result<&StateNode, LogicEvaluationError> {
         match self.state_graph.get(state_index) {
             Some(node) => Ok(node),
@@ -236,49 +863,115 @@ impl LogicMonitorInner {
     }
 
     fn find_index_by_tag(&self, state_tag: StateTag) -> Result<usize, LogicEvaluationError> {
-        for (index, state_node) in self.state_graph.iter().enumerate() {
-            if state_node.tag == state_tag {
-                return Ok(index);
-            }
-        }
-
-        Err(LogicEvaluationError::InvalidState)
+        self.state_indices
+            .get(&state_tag)
+            .copied()
+            .ok_or(LogicEvaluationError::InvalidState)
     }
 
-    fn transition(&self, target_state: StateTag) -> Result<StateTag, LogicEvaluationError> {
+    /// Single transition attempt.
+    /// Returns [`None`] if logic state was changed concurrently and transition must be retried.
+    fn transition_internal(&self, target: TransitionTarget) -> Option<Result<usize, LogicEvaluationError>> {
         // Load current monitor state.
-        let mut snapshot = self.logic_state.snapshot();
+        let snapshot = self.logic_state.snapshot();
+
+        // Get name and allowed targets of current state.
+        let current_state_index = snapshot.current_state_index();
+        let current_state_node = match self.find_node_by_index(current_state_index) {
+            Ok(node) => node,
+            Err(error) => return Some(Err(error)),
+        };
+        let source = Some(current_state_node.tag);
+
+        // Determine target state, unexpected event leaves the current state unchanged.
+        let (target_state_index, target_state, event) = match target {
+            TransitionTarget::State(index, tag) => (index, tag, None),
+            TransitionTarget::Event(event) => match self.events.get(&(current_state_index, event)) {
+                Some(index) => (Some(*index), self.state_graph[*index].tag, Some(event)),
+                None => (None, current_state_node.tag, Some(event)),
+            },
+        };
+
+        // Clock is read only if time spent in states is measured or the transition is recorded.
+        // Entry timestamp of a state without dwell time is not used.
+        let target_dwell = target_state_index.and_then(|index| self.state_graph.get(index)?.dwell);
+        let now = if current_state_node.dwell.is_some() || target_dwell.is_some() || self.history.is_enabled() {
+            self.clock.now()
+        } else {
+            0
+        };
 
         // Disallow operation in erroneous state.
         if snapshot.monitor_status().is_err() {
             warn!("Current logic monitor state cannot be determined");
-            return Err(LogicEvaluationError::InvalidState);
+            let error = LogicEvaluationError::InvalidState;
+            self.record(source, target_state, event, now, false, Err(error));
+            return Some(Err(error));
         }
 
-        // Get name and allowed targets of current state.
-        let current_state_index = snapshot.current_state_index();
-        let current_state_node = self.find_node_by_index(current_state_index)?;
-
         // Check transition to a target state is valid.
-        if !current_state_node.allowed_targets.contains(&target_state) {
+        let checked_index = match (target_state_index, current_state_node.dwell) {
             // Move to `InvalidTransition` if requested target state is not known.
-            warn!(
-                "Requested state transition is invalid: {:?} -> {:?}",
-                current_state_node.tag, target_state
-            );
+            (Some(index), _) if !self.transitions.is_allowed(current_state_index, index) => {
+                Err(LogicEvaluationError::InvalidTransition)
+            },
+            (None, _) => Err(LogicEvaluationError::InvalidTransition),
+            // Check time spent in the current state.
+            (Some(index), Some(dwell)) => {
+                let elapsed = snapshot.time_in_state(now);
+                if elapsed < self.clock.ticks(dwell.min) {
+                    Err(LogicEvaluationError::TooEarly)
+                } else if elapsed > self.clock.ticks(dwell.max) {
+                    Err(LogicEvaluationError::TooLate)
+                } else {
+                    Ok(index)
+                }
+            },
+            (Some(index), None) => Ok(index),
+        };
 
-            let error = LogicEvaluationError::InvalidTransition;
-            snapshot.set_monitor_status(error);
-            let _ = self.logic_state.swap(snapshot);
-            return Err(error);
-        }
+        let target_state_index = match checked_index {
+            Ok(index) => index,
+            Err(error) => {
+                // Error origin is locked first, so the error is never observed without it.
+                let mut invalid_origin = self.invalid_origin();
+                let mut failed_snapshot = snapshot;
+                failed_snapshot.set_monitor_status(error);
+                self.logic_state.compare_exchange(snapshot, failed_snapshot).ok()?;
+                if error == LogicEvaluationError::InvalidTransition {
+                    *invalid_origin = Some(match event {
+                        Some(event) => FailureOrigin::Event(current_state_node.tag, event),
+                        None => FailureOrigin::Transition(current_state_node.tag, target_state),
+                    });
+                }
+                drop(invalid_origin);
+
+                if let (LogicEvaluationError::InvalidTransition, Some(event)) = (error, event) {
+                    warn!("Unexpected event in state ({:?}): {:?}", current_state_node.tag, event);
+                } else if error == LogicEvaluationError::InvalidTransition {
+                    warn!(
+                        "Requested state transition is invalid: {:?} -> {:?}",
+                        current_state_node.tag, target_state
+                    );
+                } else {
+                    warn!(
+                        "State ({:?}) left outside of its dwell time: {:?}",
+                        current_state_node.tag, error
+                    );
+                }
+                self.record(source, target_state, event, now, false, Err(error));
+                return Some(Err(error));
+            },
+        };
 
-        // Find index of target state, then change current state.
-        let target_state_index = self.find_index_by_tag(target_state)?;
-        snapshot.set_current_state_index(target_state_index);
-        let _ = self.logic_state.swap(snapshot);
+        // Change current state, along with the time of entering it.
+        let mut new_snapshot = snapshot;
+        new_snapshot.set_current_state_index(target_state_index);
+        new_snapshot.set_entry_timestamp(now);
+        self.logic_state.compare_exchange(snapshot, new_snapshot).ok()?;
 
-        Ok(target_state)
+        self.record(source, target_state, event, now, false, Ok(()));
+        Some(Ok(target_state_index))
     }
 
     fn state(&self) -> Result<StateTag, LogicEvaluationError> {
@@ -295,18 +988,82 @@ impl LogicMonitorInner {
         self.find_node_by_index(snapshot.current_state_index())
             .map(|node| node.tag)
     }
+
+    fn current_state_index(&self) -> Result<StateIndex, LogicEvaluationError> {
+        // Load current monitor state.
+        let snapshot = self.logic_state.snapshot();
+
+        // Disallow operation in erroneous state.
+        if snapshot.monitor_status().is_err() {
+            warn!("Current logic monitor state cannot be determined");
+            return Err(LogicEvaluationError::InvalidState);
+        }
+
+        let index = snapshot.current_state_index();
+        self.find_node_by_index(index)
+            .map(|node| StateIndex { index, tag: node.tag })
+    }
 }
 
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::MonitorEvaluator;
+    use crate::clock::{Clock, TimeSource, VirtualClock, DEFAULT_CLOCK_RESOLUTION};
+    use crate::common::{Monitor, MonitorEvaluationError, MonitorEvaluator, TimeRange};
     use crate::health_monitor::HealthMonitorError;
-    use crate::logic::{LogicEvaluationError, LogicMonitorBuilder};
+    use crate::logic::{LogicEvaluationError, LogicMonitor, LogicMonitorBuilder, ResetPolicy, SharedLogicMonitor};
     use crate::protected_memory::ProtectedMemoryAllocator;
-    use crate::tag::{MonitorTag, StateTag};
+    use crate::status::FailureOrigin;
+    use crate::tag::{EventTag, MonitorTag, StateTag};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::time::Duration;
+    use std::sync::Arc;
     use std::time::Instant;
 
+    /// Time source counting reads.
+    #[derive(Default)]
+    struct CountingTimeSource {
+        reads: AtomicUsize,
+    }
+
+    impl TimeSource for CountingTimeSource {
+        fn now(&self) -> Instant {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            Instant::now()
+        }
+    }
+
+    /// Create a monitor with time-bounded `from` state, dwell time is between 100 and 200 ms.
+    fn create_timed_monitor() -> (LogicMonitor, VirtualClock) {
+        let time_source = VirtualClock::new();
+        let clock = Clock::new(DEFAULT_CLOCK_RESOLUTION, Arc::new(time_source.clone()));
+        let allocator = ProtectedMemoryAllocator::new();
+        let from_state = StateTag::from("from");
+        let to_state = StateTag::from("to");
+        let monitor = LogicMonitorBuilder::new(from_state)
+            .add_timed_state(
+                from_state,
+                &[to_state],
+                TimeRange::new(Duration::from_millis(100), Duration::from_millis(200)),
+            )
+            .add_state(to_state, &[from_state])
+            .build(MonitorTag::from("logic_monitor"), clock, &allocator)
+            .unwrap();
+        (monitor, time_source)
+    }
+
+    fn evaluate_errors(monitor: &LogicMonitor, hmon_starting_point: Instant) -> Vec<LogicEvaluationError> {
+        let mut errors = Vec::new();
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, origin| {
+            assert_eq!(origin, Some(FailureOrigin::State(StateTag::from("from"))));
+            errors.push(match error {
+                MonitorEvaluationError::Logic(error) => error,
+                _ => panic!("unexpected error kind: {error:?}"),
+            });
+        });
+        errors
+    }
+
     #[test]
     fn logic_monitor_builder_new_succeeds() {
         let initial_state = StateTag::from("initial");
@@ -317,41 +1074,41 @@ mod tests {
 
     #[test]
     fn logic_monitor_builder_build_succeeds() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let result = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator);
+            .build(monitor_tag, Clock::default(), &allocator);
         assert!(result.is_ok());
     }
 
     #[test]
     fn logic_monitor_builder_build_no_states() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let initial_state = StateTag::from("initial");
-        let result = LogicMonitorBuilder::new(initial_state).build(monitor_tag, &allocator);
+        let result = LogicMonitorBuilder::new(initial_state).build(monitor_tag, Clock::default(), &allocator);
         assert!(result.is_err_and(|e| e == HealthMonitorError::WrongState));
     }
 
     #[test]
     fn logic_monitor_builder_build_undefined_target() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let result = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
-            .build(monitor_tag, &allocator);
+            .build(monitor_tag, Clock::default(), &allocator);
         assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
     }
 
     #[test]
     fn logic_monitor_builder_build_undefined_initial_state() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let initial_state = StateTag::from("initial");
         let from_state = StateTag::from("from");
@@ -359,20 +1116,126 @@ mod tests {
         let result = LogicMonitorBuilder::new(initial_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator);
+            .build(monitor_tag, Clock::default(), &allocator);
         assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
     }
 
+    #[test]
+    fn logic_monitor_builder_build_dwell_out_of_range() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let state = StateTag::from("state");
+        let result = LogicMonitorBuilder::new(state)
+            .add_timed_state(
+                state,
+                &[],
+                TimeRange::new(Duration::from_millis(100), Duration::from_secs(u64::MAX / 2)),
+            )
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn logic_monitor_builder_add_state_overwrites_timed_state() {
+        let state = StateTag::from("state");
+        let builder = LogicMonitorBuilder::new(state)
+            .add_timed_state(
+                state,
+                &[],
+                TimeRange::new(Duration::from_millis(100), Duration::from_millis(200)),
+            )
+            .add_state(state, &[]);
+        assert!(builder.dwell_ranges.is_empty());
+    }
+
+    #[test]
+    fn logic_monitor_timed_state_transition_within_range() {
+        let (monitor, time_source) = create_timed_monitor();
+
+        time_source.advance(Duration::from_millis(150));
+        assert!(monitor.transition(StateTag::from("to")).is_ok());
+
+        // Dwell time is measured again after re-entering the state.
+        assert!(monitor.transition(StateTag::from("from")).is_ok());
+        time_source.advance(Duration::from_millis(100));
+        assert!(monitor.transition(StateTag::from("to")).is_ok());
+    }
+
+    #[test]
+    fn logic_monitor_timed_state_left_too_early() {
+        let (monitor, time_source) = create_timed_monitor();
+
+        time_source.advance(Duration::from_millis(50));
+        let result = monitor.transition(StateTag::from("to"));
+        assert!(result.is_err_and(|e| e == LogicEvaluationError::TooEarly));
+        assert!(monitor.state().is_err_and(|e| e == LogicEvaluationError::InvalidState));
+        assert_eq!(
+            evaluate_errors(&monitor, Instant::now()),
+            vec![LogicEvaluationError::TooEarly]
+        );
+    }
+
+    #[test]
+    fn logic_monitor_timed_state_left_too_late() {
+        let (monitor, time_source) = create_timed_monitor();
+
+        time_source.advance(Duration::from_millis(250));
+        let result = monitor.transition(StateTag::from("to"));
+        assert!(result.is_err_and(|e| e == LogicEvaluationError::TooLate));
+    }
+
+    #[test]
+    fn logic_monitor_evaluate_timed_state_held_too_long() {
+        let (monitor, time_source) = create_timed_monitor();
+        let hmon_starting_point = time_source.now();
+
+        time_source.advance(Duration::from_millis(200));
+        assert!(evaluate_errors(&monitor, hmon_starting_point).is_empty());
+
+        time_source.advance(Duration::from_millis(1));
+        assert_eq!(
+            evaluate_errors(&monitor, hmon_starting_point),
+            vec![LogicEvaluationError::TooLate]
+        );
+    }
+
+    #[test]
+    fn logic_monitor_evaluate_timed_state_measured_from_monitoring_start() {
+        let (monitor, time_source) = create_timed_monitor();
+
+        // Monitoring started long after the monitor was created.
+        time_source.advance(Duration::from_secs(10));
+        let hmon_starting_point = time_source.now();
+        time_source.advance(Duration::from_millis(150));
+        assert!(evaluate_errors(&monitor, hmon_starting_point).is_empty());
+    }
+
+    #[test]
+    fn logic_monitor_rearm_restarts_dwell_time() {
+        let (monitor, time_source) = create_timed_monitor();
+        let hmon_starting_point = time_source.now();
+
+        time_source.advance(Duration::from_millis(300));
+        assert_eq!(
+            evaluate_errors(&monitor, hmon_starting_point),
+            vec![LogicEvaluationError::TooLate]
+        );
+
+        monitor.inner.rearm();
+        assert!(evaluate_errors(&monitor, hmon_starting_point).is_empty());
+        time_source.advance(Duration::from_millis(150));
+        assert!(monitor.transition(StateTag::from("to")).is_ok());
+    }
+
     #[test]
     fn logic_monitor_transition_succeeds() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let monitor = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
 
         let result = monitor.transition(to_state);
@@ -381,14 +1244,14 @@ mod tests {
 
     #[test]
     fn logic_monitor_transition_unknown_node() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let monitor = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
 
         let result = monitor.transition(StateTag::from("unknown"));
@@ -397,14 +1260,14 @@ mod tests {
 
     #[test]
     fn logic_monitor_transition_indeterminate_current_state() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let monitor = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
 
         // Trying to transition into unknown state causes monitor to move into indeterminate state.
@@ -417,7 +1280,7 @@ mod tests {
 
     #[test]
     fn logic_monitor_transition_invalid_transition() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let state1 = StateTag::from("state1");
         let state2: StateTag = StateTag::from("state2");
@@ -426,7 +1289,7 @@ mod tests {
             .add_state(state1, &[state2])
             .add_state(state2, &[state3])
             .add_state(state3, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
 
         let result = monitor.transition(state3);
@@ -435,7 +1298,7 @@ mod tests {
 
     #[test]
     fn logic_monitor_state_succeeds() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let state1 = StateTag::from("state1");
         let state2: StateTag = StateTag::from("state2");
@@ -444,7 +1307,7 @@ mod tests {
             .add_state(state1, &[state2])
             .add_state(state2, &[state3])
             .add_state(state3, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
 
         // Check state, perform transition to the next one.
@@ -462,14 +1325,14 @@ mod tests {
 
     #[test]
     fn logic_monitor_state_indeterminate_current_state() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let monitor = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
 
         // Trying to transition into unknown state causes monitor to move into indeterminate state.
@@ -482,51 +1345,57 @@ mod tests {
 
     #[test]
     fn logic_monitor_evaluate_succeeds() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let monitor = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
         let hmon_starting_point = Instant::now();
 
-        monitor.inner.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
-            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
-        });
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
 
         let _ = monitor.transition(to_state);
 
-        monitor.inner.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
-            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
-        });
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
     }
 
     #[test]
     fn logic_monitor_evaluate_invalid_state() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let from_state = StateTag::from("from");
         let to_state = StateTag::from("to");
         let monitor = LogicMonitorBuilder::new(from_state)
             .add_state(from_state, &[to_state])
             .add_state(to_state, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
         let hmon_starting_point = Instant::now();
 
-        monitor.inner.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
-            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
-        });
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
 
         let _ = monitor.transition(StateTag::from("unknown"));
 
         let mut error_happened = false;
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag_internal, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag_internal, error, _| {
                 error_happened = true;
                 assert_eq!(*monitor_tag_internal, monitor_tag);
                 assert_eq!(error, LogicEvaluationError::InvalidTransition.into())
@@ -536,7 +1405,7 @@ mod tests {
 
     #[test]
     fn logic_monitor_evaluate_invalid_transition() {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("logic_monitor");
         let state1 = StateTag::from("state1");
         let state2: StateTag = StateTag::from("state2");
@@ -545,24 +1414,616 @@ mod tests {
             .add_state(state1, &[state2])
             .add_state(state2, &[state3])
             .add_state(state3, &[])
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
             .unwrap();
         let hmon_starting_point = Instant::now();
 
-        monitor.inner.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
-            panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
-        });
+        monitor
+            .inner
+            .evaluate(hmon_starting_point, &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
 
         let _ = monitor.transition(state3);
 
         let mut error_happened = false;
         monitor
             .inner
-            .evaluate(hmon_starting_point, &mut |monitor_tag_internal, error| {
+            .evaluate(hmon_starting_point, &mut |monitor_tag_internal, error, _| {
                 error_happened = true;
                 assert_eq!(*monitor_tag_internal, monitor_tag);
                 assert_eq!(error, LogicEvaluationError::InvalidTransition.into())
             });
         assert!(error_happened);
     }
+
+    #[test]
+    fn logic_monitor_evaluate_memory_corrupted() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let monitor_tag = MonitorTag::from("logic_monitor");
+        let state1 = StateTag::from("state1");
+        let monitor = LogicMonitorBuilder::new(state1)
+            .add_state(state1, &[])
+            .build(monitor_tag, Clock::default(), &allocator)
+            .unwrap();
+        let hmon_starting_point = Instant::now();
+        monitor.inner.logic_state.corrupt_canary();
+
+        let mut error_happened = false;
+        monitor.inner.evaluate(hmon_starting_point, &mut |_, error, origin| {
+            error_happened = true;
+            assert_eq!(error, LogicEvaluationError::MemoryCorrupted.into());
+            assert!(origin.is_none());
+        });
+        assert!(error_happened);
+    }
+
+    /// Create a chain `state1 -> state2 -> state3` monitor with given reset policy.
+    fn create_resettable_monitor(reset_policy: ResetPolicy, history_capacity: usize) -> LogicMonitor {
+        let allocator = ProtectedMemoryAllocator::new();
+        let state1 = StateTag::from("state1");
+        let state2 = StateTag::from("state2");
+        let state3 = StateTag::from("state3");
+        LogicMonitorBuilder::new(state1)
+            .add_state(state1, &[state2])
+            .add_state(state2, &[state3])
+            .add_state(state3, &[])
+            .with_reset_policy(reset_policy)
+            .with_history_capacity(history_capacity)
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap()
+    }
+
+    #[test]
+    fn reset_policy_try_from_u8() {
+        assert_eq!(ResetPolicy::try_from(0), Ok(ResetPolicy::Disabled));
+        assert_eq!(ResetPolicy::try_from(1), Ok(ResetPolicy::InitialState));
+        assert_eq!(ResetPolicy::try_from(2), Ok(ResetPolicy::AnyState));
+        assert!(ResetPolicy::try_from(3).is_err());
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_disabled() {
+        let monitor = create_resettable_monitor(ResetPolicy::Disabled, 16);
+        assert_eq!(
+            monitor.reset_to(StateTag::from("state1")),
+            Err(HealthMonitorError::WrongState)
+        );
+        assert!(monitor.history().is_empty());
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_initial_state() {
+        let monitor = create_resettable_monitor(ResetPolicy::InitialState, 16);
+        let _ = monitor.transition(StateTag::from("state3"));
+        assert!(monitor.state().is_err());
+
+        assert_eq!(
+            monitor.reset_to(StateTag::from("state2")),
+            Err(HealthMonitorError::InvalidArgument)
+        );
+        assert_eq!(monitor.reset_to(StateTag::from("state1")), Ok(StateTag::from("state1")));
+        assert_eq!(monitor.state(), Ok(StateTag::from("state1")));
+        assert_eq!(
+            monitor.transition(StateTag::from("state2")),
+            Ok(StateTag::from("state2"))
+        );
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_any_state() {
+        let monitor = create_resettable_monitor(ResetPolicy::AnyState, 16);
+        let _ = monitor.transition(StateTag::from("state3"));
+        assert!(monitor.invalid_transition().is_some());
+
+        assert_eq!(monitor.reset_to(StateTag::from("state2")), Ok(StateTag::from("state2")));
+        assert_eq!(monitor.state(), Ok(StateTag::from("state2")));
+        assert!(monitor.invalid_transition().is_none());
+        assert_eq!(
+            monitor.transition(StateTag::from("state3")),
+            Ok(StateTag::from("state3"))
+        );
+    }
+
+    #[test]
+    fn logic_monitor_reset_to_unknown_state() {
+        let monitor = create_resettable_monitor(ResetPolicy::AnyState, 16);
+        assert_eq!(
+            monitor.reset_to(StateTag::from("unknown")),
+            Err(HealthMonitorError::NotFound)
+        );
+        assert_eq!(monitor.state(), Ok(StateTag::from("state1")));
+    }
+
+    #[test]
+    fn logic_monitor_history_records_transitions() {
+        let monitor = create_resettable_monitor(ResetPolicy::AnyState, 16);
+        let state1 = StateTag::from("state1");
+        let state2 = StateTag::from("state2");
+        let state3 = StateTag::from("state3");
+        let _ = monitor.transition(state2);
+        let _ = monitor.transition(state1);
+        let _ = monitor.reset_to(state1);
+
+        let history = monitor.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!((history[0].source, history[0].target), (state1, state2));
+        assert_eq!(history[0].result, Ok(()));
+        assert!(!history[0].reset);
+        assert_eq!((history[1].source, history[1].target), (state2, state1));
+        assert_eq!(history[1].result, Err(LogicEvaluationError::InvalidTransition));
+        assert_eq!((history[2].source, history[2].target), (state2, state1));
+        assert_eq!(history[2].result, Ok(()));
+        assert!(history[2].reset);
+        assert!(history[0].time <= history[1].time && history[1].time <= history[2].time);
+
+        let _ = monitor.transition(state3);
+        assert_eq!(monitor.history().len(), 4);
+    }
+
+    #[test]
+    fn logic_monitor_history_limited_capacity() {
+        let monitor = create_resettable_monitor(ResetPolicy::AnyState, 2);
+        let _ = monitor.transition(StateTag::from("state2"));
+        let _ = monitor.transition(StateTag::from("state3"));
+        let _ = monitor.reset_to(StateTag::from("state1"));
+
+        let targets: Vec<StateTag> = monitor.history().iter().map(|record| record.target).collect();
+        assert_eq!(targets, vec![StateTag::from("state3"), StateTag::from("state1")]);
+    }
+
+    #[test]
+    fn logic_monitor_history_disabled() {
+        let monitor = create_resettable_monitor(ResetPolicy::Disabled, 0);
+        let _ = monitor.transition(StateTag::from("state2"));
+        assert!(monitor.history().is_empty());
+    }
+
+    #[test]
+    fn logic_monitor_invalid_transition_reported() {
+        let monitor = create_resettable_monitor(ResetPolicy::Disabled, 16);
+        let state1 = StateTag::from("state1");
+        let state3 = StateTag::from("state3");
+        assert!(monitor.invalid_transition().is_none());
+
+        let _ = monitor.transition(state3);
+        assert_eq!(monitor.invalid_transition(), Some((state1, state3)));
+
+        let mut error_happened = false;
+        monitor.inner.evaluate(Instant::now(), &mut |_, error, origin| {
+            error_happened = true;
+            assert_eq!(error, LogicEvaluationError::InvalidTransition.into());
+            assert_eq!(origin, Some(FailureOrigin::Transition(state1, state3)));
+        });
+        assert!(error_happened);
+
+        monitor.inner.rearm();
+        assert!(monitor.invalid_transition().is_none());
+        assert_eq!(monitor.state(), Ok(state1));
+    }
+
+    #[test]
+    fn logic_monitor_builder_analyze() {
+        let state1 = StateTag::from("state1");
+        let state2 = StateTag::from("state2");
+        let state3 = StateTag::from("state3");
+        let builder = LogicMonitorBuilder::new(state1)
+            .add_state(state1, &[state2])
+            .add_state(state2, &[state2])
+            .add_state(state3, &[state1]);
+
+        let analysis = builder.analyze();
+        assert_eq!(analysis.unreachable_states, vec![state3]);
+        assert_eq!(analysis.dead_end_states, vec![state2]);
+        assert_eq!(analysis.self_loops, vec![state2]);
+        assert!(analysis.undefined_targets.is_empty());
+    }
+
+    #[test]
+    fn logic_monitor_export_matches_builder() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let state1 = StateTag::from("state1");
+        let state2 = StateTag::from("state2");
+        let builder = LogicMonitorBuilder::new(state2)
+            .add_state(state1, &[state2])
+            .add_timed_state(
+                state2,
+                &[state1],
+                TimeRange::new(Duration::from_millis(100), Duration::from_millis(200)),
+            );
+        let builder_dot = builder.to_dot("logic_monitor");
+        let builder_plantuml = builder.to_plantuml();
+        assert!(builder_dot.contains("__initial -> \"state2\";"));
+        assert!(builder_plantuml.contains("s1 : dwell 100..200 ms"));
+
+        let monitor = builder
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap();
+        assert_eq!(monitor.to_dot(), builder_dot);
+        assert_eq!(monitor.to_plantuml(), builder_plantuml);
+    }
+
+    #[test]
+    fn logic_monitor_transition_by_index_succeeds() {
+        let monitor = create_resettable_monitor(ResetPolicy::Disabled, 16);
+        let state2 = monitor.state_index(StateTag::from("state2")).unwrap();
+        let state3 = monitor.state_index(StateTag::from("state3")).unwrap();
+        assert!(monitor.state_index(StateTag::from("unknown")).is_none());
+
+        assert_eq!(monitor.transition_by_index(state2), Ok(state2));
+        assert_eq!(monitor.transition_by_index(state3), Ok(state3));
+        assert_eq!(monitor.current_state_index(), Ok(state3));
+        assert_eq!(monitor.state(), Ok(state3.tag()));
+        assert_eq!(monitor.history().len(), 2);
+    }
+
+    #[test]
+    fn logic_monitor_transition_by_index_without_clock() {
+        let time_source = Arc::new(CountingTimeSource::default());
+        let clock = Clock::new(DEFAULT_CLOCK_RESOLUTION, time_source.clone());
+        let allocator = ProtectedMemoryAllocator::new();
+        let (from_state, to_state) = (StateTag::from("from"), StateTag::from("to"));
+        let monitor = LogicMonitorBuilder::new(from_state)
+            .add_state(from_state, &[to_state])
+            .add_state(to_state, &[from_state])
+            .with_history_capacity(0)
+            .build(MonitorTag::from("logic_monitor"), clock, &allocator)
+            .unwrap();
+        let from_state = monitor.state_index(from_state).unwrap();
+        let to_state = monitor.state_index(to_state).unwrap();
+
+        let reads = time_source.reads.load(Ordering::Relaxed);
+        assert_eq!(monitor.transition_by_index(to_state), Ok(to_state));
+        assert_eq!(monitor.transition_by_index(from_state), Ok(from_state));
+        assert_eq!(time_source.reads.load(Ordering::Relaxed), reads);
+        assert!(monitor.history().is_empty());
+    }
+
+    #[test]
+    fn logic_monitor_transition_by_index_invalid_transition() {
+        let monitor = create_resettable_monitor(ResetPolicy::Disabled, 16);
+        let state3 = monitor.state_index(StateTag::from("state3")).unwrap();
+
+        assert_eq!(
+            monitor.transition_by_index(state3),
+            Err(LogicEvaluationError::InvalidTransition)
+        );
+        assert_eq!(
+            monitor.invalid_transition(),
+            Some((StateTag::from("state1"), StateTag::from("state3")))
+        );
+        assert_eq!(monitor.current_state_index(), Err(LogicEvaluationError::InvalidState));
+    }
+
+    #[test]
+    fn logic_monitor_transition_by_index_foreign_handle() {
+        let monitor = create_resettable_monitor(ResetPolicy::Disabled, 16);
+        let allocator = ProtectedMemoryAllocator::new();
+        let other_state = StateTag::from("other_state");
+        let other_monitor = LogicMonitorBuilder::new(other_state)
+            .add_state(other_state, &[])
+            .build(MonitorTag::from("other_monitor"), Clock::default(), &allocator)
+            .unwrap();
+        let foreign_index = other_monitor.state_index(other_state).unwrap();
+
+        assert_eq!(
+            monitor.transition_by_index(foreign_index),
+            Err(LogicEvaluationError::InvalidTransition)
+        );
+    }
+
+    #[test]
+    fn logic_monitor_transition_many_states() {
+        const NUM_STATES: usize = 150;
+        let allocator = ProtectedMemoryAllocator::new();
+        let states: Vec<StateTag> = (0..NUM_STATES)
+            .map(|index| StateTag::from(format!("state{index}")))
+            .collect();
+        let mut builder = LogicMonitorBuilder::new(states[0]);
+        for (index, state) in states.iter().enumerate() {
+            builder = builder.add_state(*state, &[states[(index + 1) % NUM_STATES]]);
+        }
+        let monitor = builder
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap();
+
+        for state in states.iter().skip(1).chain(states.first()) {
+            assert_eq!(monitor.transition(*state), Ok(*state));
+        }
+        assert_eq!(
+            monitor.transition(states[2]),
+            Err(LogicEvaluationError::InvalidTransition)
+        );
+    }
+
+    /// Create a monitor with `off` and `on` states, switched by `start` and `stop` events.
+    fn create_event_monitor() -> LogicMonitor {
+        let allocator = ProtectedMemoryAllocator::new();
+        let (off, on) = (StateTag::from("off"), StateTag::from("on"));
+        LogicMonitorBuilder::new(off)
+            .add_state(off, &[on])
+            .add_state(on, &[off])
+            .add_event(off, EventTag::from("start"), on)
+            .add_event(on, EventTag::from("stop"), off)
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap()
+    }
+
+    #[test]
+    fn logic_monitor_builder_build_event_disallowed_target() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let (off, on) = (StateTag::from("off"), StateTag::from("on"));
+        let result = LogicMonitorBuilder::new(off)
+            .add_state(off, &[on])
+            .add_state(on, &[])
+            .add_event(on, EventTag::from("stop"), off)
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn logic_monitor_builder_build_event_undefined_state() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let (off, on) = (StateTag::from("off"), StateTag::from("on"));
+        let result = LogicMonitorBuilder::new(off)
+            .add_state(off, &[])
+            .add_event(on, EventTag::from("stop"), off)
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator);
+        assert!(result.is_err_and(|e| e == HealthMonitorError::InvalidArgument));
+    }
+
+    #[test]
+    fn logic_monitor_event_succeeds() {
+        let monitor = create_event_monitor();
+        let (off, on) = (StateTag::from("off"), StateTag::from("on"));
+        let (start, stop) = (EventTag::from("start"), EventTag::from("stop"));
+
+        assert_eq!(monitor.event(start), Ok(on));
+        assert_eq!(monitor.event(stop), Ok(off));
+        assert_eq!(monitor.state(), Ok(off));
+        // Event-triggered transitions are still allowed to be requested directly.
+        assert_eq!(monitor.transition(on), Ok(on));
+
+        let history = monitor.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            (history[0].source, history[0].target, history[0].event),
+            (off, on, Some(start))
+        );
+        assert_eq!(history[2].event, None);
+    }
+
+    #[test]
+    fn logic_monitor_event_unexpected() {
+        let monitor = create_event_monitor();
+        let off = StateTag::from("off");
+        let stop = EventTag::from("stop");
+
+        assert_eq!(monitor.event(stop), Err(LogicEvaluationError::InvalidTransition));
+        assert_eq!(monitor.unexpected_event(), Some((off, stop)));
+        assert!(monitor.invalid_transition().is_none());
+        assert_eq!(monitor.state(), Err(LogicEvaluationError::InvalidState));
+        assert_eq!(
+            monitor.event(EventTag::from("start")),
+            Err(LogicEvaluationError::InvalidState)
+        );
+
+        let history = monitor.history();
+        assert_eq!(
+            (history[0].source, history[0].target, history[0].event),
+            (off, off, Some(stop))
+        );
+        assert_eq!(history[0].result, Err(LogicEvaluationError::InvalidTransition));
+
+        let mut error_happened = false;
+        monitor.inner.evaluate(Instant::now(), &mut |_, error, origin| {
+            error_happened = true;
+            assert_eq!(error, LogicEvaluationError::InvalidTransition.into());
+            assert_eq!(origin, Some(FailureOrigin::Event(off, stop)));
+        });
+        assert!(error_happened);
+
+        monitor.inner.rearm();
+        assert!(monitor.unexpected_event().is_none());
+    }
+
+    #[test]
+    fn logic_monitor_event_unknown() {
+        let monitor = create_event_monitor();
+        let unknown = EventTag::from("unknown");
+        assert_eq!(monitor.event(unknown), Err(LogicEvaluationError::InvalidTransition));
+        assert_eq!(monitor.unexpected_event(), Some((StateTag::from("off"), unknown)));
+    }
+
+    #[test]
+    fn shared_logic_monitor_event_succeeds() {
+        let monitor = create_event_monitor().into_shared();
+        assert_eq!(monitor.event(EventTag::from("start")), Ok(StateTag::from("on")));
+        assert_eq!(
+            monitor.event(EventTag::from("start")),
+            Err(LogicEvaluationError::InvalidTransition)
+        );
+        assert_eq!(
+            monitor.unexpected_event(),
+            Some((StateTag::from("on"), EventTag::from("start")))
+        );
+    }
+
+    /// Create a monitor with `start` state, from which `left` and `right` dead-end states are reachable.
+    fn create_fork_monitor() -> SharedLogicMonitor {
+        let allocator = ProtectedMemoryAllocator::new();
+        let (start, left, right) = (StateTag::from("start"), StateTag::from("left"), StateTag::from("right"));
+        LogicMonitorBuilder::new(start)
+            .add_state(start, &[left, right])
+            .add_state(left, &[])
+            .add_state(right, &[])
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap()
+            .into_shared()
+    }
+
+    #[test]
+    fn shared_logic_monitor_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedLogicMonitor>();
+    }
+
+    #[test]
+    fn shared_logic_monitor_transition_succeeds() {
+        let monitor = create_resettable_monitor(ResetPolicy::AnyState, 16).into_shared();
+        let state2 = StateTag::from("state2");
+        let state3 = monitor.state_index(StateTag::from("state3")).unwrap();
+
+        assert_eq!(monitor.transition(state2), Ok(state2));
+        assert_eq!(monitor.transition_by_index(state3), Ok(state3));
+        assert_eq!(monitor.current_state_index(), Ok(state3));
+        assert_eq!(monitor.reset_to(state2), Ok(state2));
+        assert_eq!(monitor.state(), Ok(state2));
+        assert_eq!(monitor.history().len(), 3);
+        assert!(monitor.invalid_transition().is_none());
+    }
+
+    #[test]
+    fn shared_logic_monitor_concurrent_transitions() {
+        const NUM_THREADS: usize = 4;
+        const NUM_TRANSITIONS: usize = 100;
+        let allocator = ProtectedMemoryAllocator::new();
+        let states: Vec<StateTag> = (0..NUM_THREADS)
+            .map(|index| StateTag::from(format!("state{index}")))
+            .collect();
+        let mut builder = LogicMonitorBuilder::new(states[0]).with_history_capacity(NUM_THREADS * NUM_TRANSITIONS);
+        for state in states.iter() {
+            builder = builder.add_state(*state, &states);
+        }
+        let monitor = Arc::new(
+            builder
+                .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+                .unwrap()
+                .into_shared(),
+        );
+
+        let threads: Vec<_> = states
+            .iter()
+            .map(|state| {
+                let (monitor, state) = (monitor.clone(), *state);
+                std::thread::spawn(move || {
+                    for _ in 0..NUM_TRANSITIONS {
+                        assert_eq!(monitor.transition(state), Ok(state));
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert!(states.contains(&monitor.state().unwrap()));
+        assert_eq!(monitor.history().len(), NUM_THREADS * NUM_TRANSITIONS);
+        monitor
+            .get_eval_handle()
+            .evaluate(Instant::now(), &mut |monitor_tag, error, _| {
+                panic!("error happened, tag: {monitor_tag:?}, error: {error:?}")
+            });
+    }
+
+    #[test]
+    fn shared_logic_monitor_racing_transitions() {
+        let monitor = create_fork_monitor();
+        let (left, right) = (StateTag::from("left"), StateTag::from("right"));
+
+        let (left_result, right_result) = std::thread::scope(|scope| {
+            let left_thread = scope.spawn(|| monitor.transition(left));
+            let right_thread = scope.spawn(|| monitor.transition(right));
+            (left_thread.join().unwrap(), right_thread.join().unwrap())
+        });
+
+        // Transition applied second is validated against the state entered by the first one.
+        let (winner, loser) = match (left_result, right_result) {
+            (Ok(_), Err(LogicEvaluationError::InvalidTransition)) => (left, right),
+            (Err(LogicEvaluationError::InvalidTransition), Ok(_)) => (right, left),
+            results => panic!("unexpected results: {results:?}"),
+        };
+        assert_eq!(monitor.invalid_transition(), Some((winner, loser)));
+    }
+}
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use crate::clock::Clock;
+    use crate::common::{Monitor, MonitorEvaluator};
+    use crate::logic::{LogicEvaluationError, LogicMonitorBuilder, SharedLogicMonitor};
+    use crate::protected_memory::ProtectedMemoryAllocator;
+    use crate::status::FailureOrigin;
+    use crate::tag::{MonitorTag, StateTag};
+    use loom::thread::spawn;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    fn create_fork_monitor() -> Arc<SharedLogicMonitor> {
+        let allocator = ProtectedMemoryAllocator::new();
+        let (start, left, right) = (StateTag::from("start"), StateTag::from("left"), StateTag::from("right"));
+        let monitor = LogicMonitorBuilder::new(start)
+            .add_state(start, &[left, right])
+            .add_state(left, &[])
+            .add_state(right, &[])
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap();
+        Arc::new(monitor.into_shared())
+    }
+
+    #[test]
+    fn shared_logic_monitor_racing_transitions() {
+        loom::model(|| {
+            let monitor = create_fork_monitor();
+            let (left, right) = (StateTag::from("left"), StateTag::from("right"));
+
+            // Perform transitions in separate threads.
+            let monitor_clone = monitor.clone();
+            let left_thread = spawn(move || monitor_clone.transition(left));
+            let monitor_clone = monitor.clone();
+            let right_thread = spawn(move || monitor_clone.transition(right));
+            let left_result = left_thread.join().unwrap();
+            let right_result = right_thread.join().unwrap();
+
+            // Exactly one transition succeeds, the other one is reported as invalid.
+            let (winner, loser) = match (left_result, right_result) {
+                (Ok(_), Err(LogicEvaluationError::InvalidTransition)) => (left, right),
+                (Err(LogicEvaluationError::InvalidTransition), Ok(_)) => (right, left),
+                results => panic!("unexpected results: {results:?}"),
+            };
+            assert_eq!(monitor.invalid_transition(), Some((winner, loser)));
+
+            // Evaluate.
+            let mut error_detected = false;
+            monitor
+                .get_eval_handle()
+                .evaluate(Instant::now(), &mut |_, error, origin| {
+                    assert_eq!(error, LogicEvaluationError::InvalidTransition.into());
+                    assert_eq!(origin, Some(FailureOrigin::Transition(winner, loser)));
+                    error_detected = true;
+                });
+            assert!(error_detected);
+        });
+    }
+
+    #[test]
+    fn shared_logic_monitor_transition_evaluate() {
+        loom::model(|| {
+            let monitor = create_fork_monitor();
+            let left = StateTag::from("left");
+
+            // Perform transition in a separate thread.
+            let monitor_clone = monitor.clone();
+            let transition_thread = spawn(move || monitor_clone.transition(left));
+
+            // Evaluate.
+            monitor
+                .get_eval_handle()
+                .evaluate(Instant::now(), &mut |monitor_tag, error, _| {
+                    panic!("error happened, tag: {monitor_tag:?}, error: {error:?}");
+                });
+
+            assert_eq!(transition_thread.join().unwrap(), Ok(left));
+        });
+    }
 }
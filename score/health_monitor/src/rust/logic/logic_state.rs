@@ -16,16 +16,27 @@ use crate::logic::logic_monitor::OK_STATE;
 use crate::logic::LogicEvaluationError;
 
 /// Snapshot of a logic state.
-/// Layout (u64) = | current state index: 56 bits | monitor status: u8 |
+/// Layout (u64) = | entry timestamp: 40 bits | current state index: 16 bits | monitor status: u8 |
+///
+/// Entry timestamp is a time point of entering the current state, truncated to 40 bits.
+/// Time spent in the state is computed with wrapping arithmetic, refer to [`Self::time_in_state`].
 #[derive(Clone, Copy)]
 pub struct LogicStateSnapshot(u64);
 
-const INDEX_MASK: u64 = 0xFFFFFFFF_FFFFFF00;
+const TIMESTAMP_MASK: u64 = 0xFFFFFFFF_FF000000;
+const TIMESTAMP_OFFSET: u32 = 24;
+const INDEX_MASK: u64 = 0x00FFFF00;
 const INDEX_OFFSET: u32 = u8::BITS;
 const STATUS_MASK: u64 = 0xFF;
 
+/// Max number of states, state index is 16-bit.
+pub const MAX_STATES: usize = 1 << 16;
+
+/// Max time spent in a state which can be measured, in ticks.
+pub const MAX_TIME_IN_STATE: u64 = TIMESTAMP_MASK >> TIMESTAMP_OFFSET;
+
 impl LogicStateSnapshot {
-    /// Create a new snapshot.
+    /// Create a new snapshot, state is entered at timestamp zero.
     pub fn new(initial_state_index: usize) -> Self {
         let mut snapshot = Self(0);
         snapshot.set_current_state_index(initial_state_index);
@@ -43,9 +54,9 @@ impl LogicStateSnapshot {
     }
 
     /// Set current state index.
-    /// Value is 56-bit, max accepted value is 0x00FFFFFF_FFFFFFFF.
+    /// Value is 16-bit, max accepted value is 0xFFFF.
     pub fn set_current_state_index(&mut self, value: usize) {
-        assert!(value < 1 << 56, "provided state index is out of range");
+        assert!(value < MAX_STATES, "provided state index is out of range");
         self.0 = ((value as u64) << INDEX_OFFSET) | (self.0 & !INDEX_MASK)
     }
 
@@ -65,6 +76,28 @@ impl LogicStateSnapshot {
     pub fn set_monitor_status(&mut self, value: LogicEvaluationError) {
         self.0 = (value as u64) | (self.0 & !STATUS_MASK);
     }
+
+    /// Reset monitor status to OK.
+    pub fn clear_monitor_status(&mut self) {
+        self.0 &= !STATUS_MASK;
+    }
+
+    /// Entry timestamp, truncated to 40 bits.
+    pub fn entry_timestamp(&self) -> u64 {
+        (self.0 & TIMESTAMP_MASK) >> TIMESTAMP_OFFSET
+    }
+
+    /// Set entry timestamp.
+    /// Value is truncated to 40 bits.
+    pub fn set_entry_timestamp(&mut self, value: u64) {
+        self.0 = (value << TIMESTAMP_OFFSET) | (self.0 & !TIMESTAMP_MASK)
+    }
+
+    /// Time spent in the current state until `now`.
+    /// Correct as long as it does not exceed [`MAX_TIME_IN_STATE`].
+    pub fn time_in_state(&self, now: u64) -> u64 {
+        now.wrapping_sub(self.entry_timestamp()) & MAX_TIME_IN_STATE
+    }
 }
 
 impl From<u64> for LogicStateSnapshot {
@@ -74,17 +107,17 @@ impl From<u64> for LogicStateSnapshot {
 }
 
 /// Atomic representation of [`LogicStateSnapshot`].
+/// State and time of entering it are changed together, so they are never observed mismatched.
 pub struct LogicState(AtomicU64);
 
 impl LogicState {
-    /// Create a new [`LogicState`].
+    /// Create a new [`LogicState`], initial state is entered at timestamp zero.
     pub fn new(initial_state_index: usize) -> Self {
         let snapshot = LogicStateSnapshot::new(initial_state_index);
         Self(AtomicU64::new(snapshot.as_u64()))
     }
 
     /// Return a snapshot of the current logic state.
-    #[allow(dead_code)]
     pub fn snapshot(&self) -> LogicStateSnapshot {
         LogicStateSnapshot::from(self.0.load(Ordering::Acquire))
     }
@@ -93,11 +126,35 @@ impl LogicState {
     pub fn swap(&self, new: LogicStateSnapshot) -> LogicStateSnapshot {
         self.0.swap(new.as_u64(), Ordering::AcqRel).into()
     }
+
+    /// Store `new` snapshot only if the current one equals `current`.
+    /// On failure, the current snapshot is returned.
+    pub fn compare_exchange(
+        &self,
+        current: LogicStateSnapshot,
+        new: LogicStateSnapshot,
+    ) -> Result<(), LogicStateSnapshot> {
+        self.0
+            .compare_exchange(current.as_u64(), new.as_u64(), Ordering::AcqRel, Ordering::Acquire)
+            .map(|_| ())
+            .map_err(LogicStateSnapshot::from)
+    }
+
+    /// Reset monitor status to OK and restart measuring time spent in the current state from `timestamp`.
+    /// Current state index is kept.
+    pub fn restart(&self, timestamp: u64) {
+        let _ = self.0.fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+            let mut snapshot = LogicStateSnapshot::from(current);
+            snapshot.clear_monitor_status();
+            snapshot.set_entry_timestamp(timestamp);
+            Some(snapshot.as_u64())
+        });
+    }
 }
 
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::logic::logic_state::{LogicState, LogicStateSnapshot};
+    use crate::logic::logic_state::{LogicState, LogicStateSnapshot, MAX_TIME_IN_STATE};
     use crate::logic::LogicEvaluationError;
     use core::sync::atomic::Ordering;
 
@@ -108,6 +165,7 @@ mod tests {
 
         assert_eq!(state.as_u64(), (initial_state_index as u64) << u8::BITS);
         assert_eq!(state.current_state_index(), initial_state_index);
+        assert_eq!(state.entry_timestamp(), 0);
         assert!(state.monitor_status().is_ok());
     }
 
@@ -117,6 +175,7 @@ mod tests {
 
         assert_eq!(state.as_u64(), 0x00);
         assert_eq!(state.current_state_index(), 0);
+        assert_eq!(state.entry_timestamp(), 0);
         assert!(state.monitor_status().is_ok());
     }
 
@@ -125,7 +184,8 @@ mod tests {
         let state = LogicStateSnapshot::from(0xDEADBEEF_DEADBE01);
 
         assert_eq!(state.as_u64(), 0xDEADBEEF_DEADBE01);
-        assert_eq!(state.current_state_index(), 0xDEADBEEF_DEADBE01 >> u8::BITS);
+        assert_eq!(state.entry_timestamp(), 0x00DE_ADBE_EFDE);
+        assert_eq!(state.current_state_index(), 0xADBE);
         assert!(state
             .monitor_status()
             .is_err_and(|e| e == LogicEvaluationError::InvalidState));
@@ -136,7 +196,8 @@ mod tests {
         let state = LogicStateSnapshot::from(u64::MAX);
 
         assert_eq!(state.as_u64(), u64::MAX);
-        assert_eq!(state.current_state_index(), (u64::MAX >> u8::BITS) as usize);
+        assert_eq!(state.entry_timestamp(), MAX_TIME_IN_STATE);
+        assert_eq!(state.current_state_index(), 0xFFFF);
         assert!(state
             .monitor_status()
             .is_err_and(|e| e == LogicEvaluationError::UnmappedError));
@@ -144,12 +205,13 @@ mod tests {
 
     #[test]
     fn snapshot_set_current_state_index_valid() {
-        let mut state = LogicStateSnapshot::from(0xDEADBEEF_DEADBE00);
-        state.set_current_state_index(0x00CAFEBA_DCAFEBAD);
+        let mut state = LogicStateSnapshot::from(0xDEADBEEF_DE000000);
+        state.set_current_state_index(0xCAFE);
 
-        assert_eq!(state.current_state_index(), 0x00CAFEBA_DCAFEBAD);
+        assert_eq!(state.current_state_index(), 0xCAFE);
 
         // Check other parameters unchanged.
+        assert_eq!(state.entry_timestamp(), 0x00DE_ADBE_EFDE);
         assert!(state.monitor_status().is_ok());
     }
 
@@ -157,7 +219,7 @@ mod tests {
     #[should_panic(expected = "provided state index is out of range")]
     fn snapshot_set_heartbeat_timestamp_out_of_range() {
         let mut state = LogicStateSnapshot::from(0xDEADBEEF_DEADBEEF);
-        state.set_current_state_index(0x01000000_00000000);
+        state.set_current_state_index(0x10000);
     }
 
     #[test]
@@ -170,7 +232,35 @@ mod tests {
             .is_err_and(|e| e == LogicEvaluationError::InvalidTransition));
 
         // Check other parameters unchanged.
-        assert_eq!(state.current_state_index(), 0xDEADBEEF_DEADBEEF >> u8::BITS);
+        assert_eq!(state.current_state_index(), 0xADBE);
+        assert_eq!(state.entry_timestamp(), 0x00DE_ADBE_EFDE);
+
+        state.clear_monitor_status();
+        assert!(state.monitor_status().is_ok());
+        assert_eq!(state.current_state_index(), 0xADBE);
+    }
+
+    #[test]
+    fn snapshot_set_entry_timestamp_truncated() {
+        let mut state = LogicStateSnapshot::new(3);
+        state.set_entry_timestamp(0x1234_5678_90AB);
+
+        assert_eq!(state.entry_timestamp(), 0x0034_5678_90AB);
+
+        // Check other parameters unchanged.
+        assert_eq!(state.current_state_index(), 3);
+        assert!(state.monitor_status().is_ok());
+    }
+
+    #[test]
+    fn snapshot_time_in_state() {
+        let mut state = LogicStateSnapshot::new(0);
+        state.set_entry_timestamp(1000);
+        assert_eq!(state.time_in_state(1500), 500);
+
+        // Time is measured across truncated timestamp overflow.
+        state.set_entry_timestamp(MAX_TIME_IN_STATE - 100);
+        assert_eq!(state.time_in_state(MAX_TIME_IN_STATE + 400), 500);
     }
 
     #[test]
@@ -201,9 +291,34 @@ mod tests {
 
         let curr_snapshot = state.snapshot();
         assert_eq!(curr_snapshot.as_u64(), 0xDEADBEEF_DEADBE02);
-        assert_eq!(curr_snapshot.current_state_index(), 0xDEADBEEF_DEADBE02 >> u8::BITS);
+        assert_eq!(curr_snapshot.current_state_index(), 0xADBE);
         assert!(curr_snapshot
             .monitor_status()
             .is_err_and(|e| e == LogicEvaluationError::InvalidTransition));
     }
+
+    #[test]
+    fn state_compare_exchange() {
+        let state = LogicState::new(0);
+        let current = state.snapshot();
+
+        assert!(state.compare_exchange(current, LogicStateSnapshot::new(1)).is_ok());
+        assert_eq!(state.snapshot().current_state_index(), 1);
+
+        let result = state.compare_exchange(current, LogicStateSnapshot::new(2));
+        assert!(result.is_err_and(|actual| actual.current_state_index() == 1));
+        assert_eq!(state.snapshot().current_state_index(), 1);
+    }
+
+    #[test]
+    fn state_restart() {
+        let state = LogicState::new(0);
+        let _ = state.swap(LogicStateSnapshot::from(0xDEADBEEF_DEADBE02));
+        state.restart(1234);
+
+        let curr_snapshot = state.snapshot();
+        assert_eq!(curr_snapshot.current_state_index(), 0xADBE);
+        assert_eq!(curr_snapshot.entry_timestamp(), 1234);
+        assert!(curr_snapshot.monitor_status().is_ok());
+    }
 }
@@ -13,10 +13,21 @@
 
 //! Implementation of a logic monitor and its builder.
 
+mod graph;
 mod logic_monitor;
 mod logic_state;
+mod transition_history;
+mod transition_table;
+mod typed;
 
-pub use logic_monitor::{LogicEvaluationError, LogicMonitor, LogicMonitorBuilder};
+pub use graph::GraphAnalysis;
+pub use logic_monitor::{
+    LogicEvaluationError, LogicMonitor, LogicMonitorBuilder, ResetPolicy, SharedLogicMonitor, StateIndex,
+};
+pub use transition_history::TransitionRecord;
+#[doc(hidden)]
+pub use typed::all_states_reachable;
+pub use typed::{LogicStates, TypedLogicMonitor, MAX_TYPED_STATES};
 
 // FFI bindings
 pub(super) mod ffi;
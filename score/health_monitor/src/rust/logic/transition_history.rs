@@ -0,0 +1,367 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::clock::Clock;
+use crate::common::{fence, AtomicPtr, AtomicU64, Ordering};
+use crate::logic::logic_monitor::OK_STATE;
+use crate::logic::LogicEvaluationError;
+use crate::tag::{EventTag, StateTag};
+use core::ptr;
+use std::time::Instant;
+
+/// Record of a requested state transition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransitionRecord {
+    /// State at the time of the request.
+    pub source: StateTag,
+    /// Requested target state.
+    /// For an unexpected event, the state in which it was reported.
+    pub target: StateTag,
+    /// Event which triggered the request, refer to [`crate::LogicMonitor::event`].
+    pub event: Option<EventTag>,
+    /// Time point of the request, rounded down to the clock resolution.
+    pub time: Instant,
+    /// Transition was forced using [`crate::LogicMonitor::reset_to`].
+    pub reset: bool,
+    /// Result of the request.
+    pub result: Result<(), LogicEvaluationError>,
+}
+
+/// Flag set in [`Slot::flags`] for transitions forced by a reset.
+const RESET_FLAG: u64 = 1 << u8::BITS;
+const RESULT_MASK: u64 = 0xFF;
+
+/// Tag string stored in atomic words, null data pointer stands for no tag.
+struct AtomicTag {
+    data: AtomicPtr<u8>,
+    length: AtomicU64,
+}
+
+impl AtomicTag {
+    fn new() -> Self {
+        Self {
+            data: AtomicPtr::new(ptr::null_mut()),
+            length: AtomicU64::new(0),
+        }
+    }
+
+    fn store(&self, value: Option<&'static str>) {
+        let (data, length) = match value {
+            Some(value) => (value.as_ptr().cast_mut(), value.len() as u64),
+            None => (ptr::null_mut(), 0),
+        };
+        self.data.store(data, Ordering::Relaxed);
+        self.length.store(length, Ordering::Relaxed);
+    }
+
+    /// Raw tag data, must be validated before use, refer to [`Slot::read`].
+    fn load(&self) -> (*mut u8, u64) {
+        (self.data.load(Ordering::Relaxed), self.length.load(Ordering::Relaxed))
+    }
+
+    /// Tag string from validated raw data.
+    ///
+    /// # Safety
+    ///
+    /// Data must be stored by [`Self::store`] as a whole.
+    unsafe fn as_str((data, length): (*mut u8, u64)) -> Option<&'static str> {
+        if data.is_null() {
+            return None;
+        }
+        // SAFETY: data and length were taken from a `&'static str`, as required by the caller.
+        unsafe {
+            let bytes = core::slice::from_raw_parts(data, length as usize);
+            Some(core::str::from_utf8_unchecked(bytes))
+        }
+    }
+}
+
+/// Single history entry, written and read without locking.
+///
+/// Sequence number of the record is `2 * (position + 1)`, odd value is stored while the record is written.
+/// Records modified during the read are skipped by readers.
+struct Slot {
+    sequence: AtomicU64,
+    source: AtomicTag,
+    target: AtomicTag,
+    event: AtomicTag,
+    /// Time point of the request in clock ticks.
+    time: AtomicU64,
+    /// Layout (u64) = | reset: 1 bit | result: u8 |
+    flags: AtomicU64,
+}
+
+impl Slot {
+    fn new() -> Self {
+        Self {
+            sequence: AtomicU64::new(0),
+            source: AtomicTag::new(),
+            target: AtomicTag::new(),
+            event: AtomicTag::new(),
+            time: AtomicU64::new(0),
+            flags: AtomicU64::new(0),
+        }
+    }
+
+    /// Write the record at `position`.
+    /// Record is dropped if a newer record or another writer already took the slot.
+    fn write(&self, position: u64, record: &RawRecord) {
+        let sequence = 2 * (position + 1);
+        let current = self.sequence.load(Ordering::Relaxed);
+        if !current.is_multiple_of(2) || current >= sequence {
+            return;
+        }
+        if self
+            .sequence
+            .compare_exchange(current, sequence - 1, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            return;
+        }
+        // Data is not written before the slot is marked as being written.
+        fence(Ordering::Release);
+
+        self.source.store(Some(record.source.as_str()));
+        self.target.store(Some(record.target.as_str()));
+        self.event.store(record.event.map(|event| event.as_str()));
+        self.time.store(record.time, Ordering::Relaxed);
+        let result = match record.result {
+            Ok(()) => OK_STATE,
+            Err(error) => error.into(),
+        };
+        let reset = if record.reset { RESET_FLAG } else { 0 };
+        self.flags.store(reset | u64::from(result), Ordering::Relaxed);
+
+        self.sequence.store(sequence, Ordering::Release);
+    }
+
+    /// Read the record at `position`.
+    /// [`None`] if the slot contains another record or is being written.
+    fn read(&self, position: u64) -> Option<RawRecord> {
+        let sequence = 2 * (position + 1);
+        if self.sequence.load(Ordering::Acquire) != sequence {
+            return None;
+        }
+
+        let source = self.source.load();
+        let target = self.target.load();
+        let event = self.event.load();
+        let time = self.time.load(Ordering::Relaxed);
+        let flags = self.flags.load(Ordering::Relaxed);
+
+        // Data is read before the sequence number is checked again.
+        fence(Ordering::Acquire);
+        if self.sequence.load(Ordering::Relaxed) != sequence {
+            return None;
+        }
+
+        // SAFETY: sequence number is unchanged, data was stored by a single completed write.
+        let (source, target, event) = unsafe {
+            (
+                AtomicTag::as_str(source)?,
+                AtomicTag::as_str(target)?,
+                AtomicTag::as_str(event),
+            )
+        };
+        let result = match (flags & RESULT_MASK) as u8 {
+            OK_STATE => Ok(()),
+            code => Err(LogicEvaluationError::try_from(code).unwrap_or(LogicEvaluationError::UnmappedError)),
+        };
+        Some(RawRecord {
+            source: StateTag::new(source),
+            target: StateTag::new(target),
+            event: event.map(EventTag::new),
+            time,
+            reset: flags & RESET_FLAG != 0,
+            result,
+        })
+    }
+}
+
+/// Transition record with time point in clock ticks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct RawRecord {
+    pub(super) source: StateTag,
+    pub(super) target: StateTag,
+    pub(super) event: Option<EventTag>,
+    pub(super) time: u64,
+    pub(super) reset: bool,
+    pub(super) result: Result<(), LogicEvaluationError>,
+}
+
+/// Fixed-size ring buffer of the last transitions.
+///
+/// Records are added and read without locking, so transitions never wait for a reader.
+/// Concurrent writers overtaking each other by the whole capacity drop their records.
+pub(super) struct TransitionHistory {
+    slots: Vec<Slot>,
+    /// Position of the next record.
+    next_position: AtomicU64,
+}
+
+impl TransitionHistory {
+    /// Create a new [`TransitionHistory`], memory is allocated upfront.
+    /// Zero `capacity` disables the history.
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            slots: (0..capacity).map(|_| Slot::new()).collect(),
+            next_position: AtomicU64::new(0),
+        }
+    }
+
+    /// History is enabled.
+    pub(super) fn is_enabled(&self) -> bool {
+        !self.slots.is_empty()
+    }
+
+    /// Add a record, the oldest record is overwritten if history is full.
+    pub(super) fn push(&self, record: RawRecord) {
+        if !self.is_enabled() {
+            return;
+        }
+        let position = self.next_position.fetch_add(1, Ordering::Relaxed);
+        self.slot(position).write(position, &record);
+    }
+
+    /// Records, starting from the oldest one.
+    /// Time points are converted using `clock`.
+    pub(super) fn records(&self, clock: &Clock) -> Vec<TransitionRecord> {
+        let end = self.next_position.load(Ordering::Relaxed);
+        let start = end.saturating_sub(self.slots.len() as u64);
+        (start..end)
+            .filter_map(|position| self.slot(position).read(position))
+            .map(|record| TransitionRecord {
+                source: record.source,
+                target: record.target,
+                event: record.event,
+                time: clock.instant_at(record.time),
+                reset: record.reset,
+                result: record.result,
+            })
+            .collect()
+    }
+
+    fn slot(&self, position: u64) -> &Slot {
+        &self.slots[(position % self.slots.len() as u64) as usize]
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::clock::Clock;
+    use crate::logic::transition_history::{RawRecord, TransitionHistory};
+    use crate::logic::LogicEvaluationError;
+    use crate::tag::{EventTag, StateTag};
+    use std::sync::Arc;
+    use std::thread;
+
+    fn record(target: &str) -> RawRecord {
+        RawRecord {
+            source: StateTag::from("source"),
+            target: StateTag::from(target),
+            event: None,
+            time: 0,
+            reset: false,
+            result: Ok(()),
+        }
+    }
+
+    fn targets(history: &TransitionHistory) -> Vec<StateTag> {
+        history
+            .records(&Clock::default())
+            .iter()
+            .map(|record| record.target)
+            .collect()
+    }
+
+    #[test]
+    fn history_push_within_capacity() {
+        let history = TransitionHistory::new(3);
+        history.push(record("a"));
+        history.push(record("b"));
+        assert_eq!(targets(&history), vec![StateTag::from("a"), StateTag::from("b")]);
+    }
+
+    #[test]
+    fn history_drops_oldest_record() {
+        let history = TransitionHistory::new(2);
+        history.push(record("a"));
+        history.push(record("b"));
+        history.push(RawRecord {
+            result: Err(LogicEvaluationError::InvalidTransition),
+            ..record("c")
+        });
+        assert_eq!(targets(&history), vec![StateTag::from("b"), StateTag::from("c")]);
+        assert_eq!(
+            history.records(&Clock::default())[1].result,
+            Err(LogicEvaluationError::InvalidTransition)
+        );
+    }
+
+    #[test]
+    fn history_record_fields() {
+        let clock = Clock::default();
+        let history = TransitionHistory::new(1);
+        history.push(RawRecord {
+            event: Some(EventTag::from("event")),
+            time: 1234,
+            reset: true,
+            result: Err(LogicEvaluationError::TooLate),
+            ..record("a")
+        });
+
+        let records = history.records(&clock);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].source, StateTag::from("source"));
+        assert_eq!(records[0].target, StateTag::from("a"));
+        assert_eq!(records[0].event, Some(EventTag::from("event")));
+        assert_eq!(records[0].time, clock.instant_at(1234));
+        assert!(records[0].reset);
+        assert_eq!(records[0].result, Err(LogicEvaluationError::TooLate));
+    }
+
+    #[test]
+    fn history_disabled() {
+        let history = TransitionHistory::new(0);
+        assert!(!history.is_enabled());
+        history.push(record("a"));
+        assert!(targets(&history).is_empty());
+    }
+
+    #[test]
+    fn history_concurrent_writers() {
+        let history = Arc::new(TransitionHistory::new(4));
+        let writers: Vec<_> = ["a", "b"]
+            .into_iter()
+            .map(|target| {
+                let history = history.clone();
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        history.push(record(target));
+                    }
+                })
+            })
+            .collect();
+        for _ in 0..1000 {
+            // Records are never torn.
+            for record in history.records(&Clock::default()) {
+                assert_eq!(record.source, StateTag::from("source"));
+                assert!(record.target == StateTag::from("a") || record.target == StateTag::from("b"));
+            }
+        }
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        assert!(targets(&history).len() <= 4);
+    }
+}
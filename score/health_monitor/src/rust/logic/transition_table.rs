@@ -0,0 +1,89 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+/// Number of bits in a single bitset word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Allowed transitions between states, stored as an adjacency bitset per source state.
+pub(super) struct TransitionTable {
+    /// Number of words used by a single source state.
+    words_per_state: usize,
+    /// Adjacency bitsets of all source states, one after another.
+    words: Vec<u64>,
+}
+
+impl TransitionTable {
+    /// Create a new [`TransitionTable`] for `num_states` states, with no transitions allowed.
+    pub(super) fn new(num_states: usize) -> Self {
+        let words_per_state = num_states.div_ceil(WORD_BITS);
+        Self {
+            words_per_state,
+            words: vec![0; words_per_state * num_states],
+        }
+    }
+
+    /// Allow transition from `source` to `target` state.
+    /// Both indices must be lower than number of states.
+    pub(super) fn allow(&mut self, source: usize, target: usize) {
+        let word = source * self.words_per_state + target / WORD_BITS;
+        self.words[word] |= 1 << (target % WORD_BITS);
+    }
+
+    /// Check whether transition from `source` to `target` state is allowed.
+    /// Out of range indices are never allowed.
+    pub(super) fn is_allowed(&self, source: usize, target: usize) -> bool {
+        if target / WORD_BITS >= self.words_per_state {
+            return false;
+        }
+        let word = source * self.words_per_state + target / WORD_BITS;
+        self.words
+            .get(word)
+            .is_some_and(|word| word & (1 << (target % WORD_BITS)) != 0)
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::logic::transition_table::TransitionTable;
+
+    #[test]
+    fn transition_table_allow() {
+        let mut table = TransitionTable::new(3);
+        table.allow(0, 1);
+        table.allow(2, 2);
+        assert!(table.is_allowed(0, 1));
+        assert!(table.is_allowed(2, 2));
+        assert!(!table.is_allowed(1, 0));
+        assert!(!table.is_allowed(0, 2));
+    }
+
+    #[test]
+    fn transition_table_multiple_words() {
+        let mut table = TransitionTable::new(130);
+        table.allow(129, 128);
+        table.allow(64, 0);
+        assert!(table.is_allowed(129, 128));
+        assert!(table.is_allowed(64, 0));
+        assert!(!table.is_allowed(128, 129));
+        assert!(!table.is_allowed(0, 64));
+    }
+
+    #[test]
+    fn transition_table_out_of_range() {
+        let mut table = TransitionTable::new(2);
+        table.allow(0, 1);
+        assert!(!table.is_allowed(0, 64));
+        assert!(!table.is_allowed(2, 1));
+        assert!(!TransitionTable::new(0).is_allowed(0, 0));
+    }
+}
@@ -0,0 +1,337 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::health_monitor::HealthMonitorError;
+use crate::logic::{LogicEvaluationError, LogicMonitor, StateIndex};
+use crate::tag::StateTag;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Maximum number of states defined using [`crate::logic_states`].
+pub const MAX_TYPED_STATES: usize = 64;
+
+/// Set of states of a [`TypedLogicMonitor`].
+///
+/// Implemented using [`crate::logic_states`] macro, which also validates the state graph at compile time.
+pub trait LogicStates: Copy + Eq + Debug + 'static {
+    /// All states, the first one is the initial state.
+    const STATES: &'static [Self];
+
+    /// Tag of the state.
+    fn tag(self) -> StateTag;
+
+    /// Position of the state in [`LogicStates::STATES`].
+    fn index(self) -> usize;
+
+    /// States to which transition from this state is allowed.
+    fn allowed_targets(self) -> &'static [Self];
+
+    /// Find state by its tag.
+    fn from_tag(tag: StateTag) -> Option<Self> {
+        Self::STATES.iter().copied().find(|state| state.tag() == tag)
+    }
+}
+
+/// Check that all states are reachable from the initial state.
+/// State graph is provided as allowed target indices of each state, the first state is the initial state.
+#[doc(hidden)]
+pub const fn all_states_reachable(state_graph: &[&[usize]]) -> bool {
+    if state_graph.is_empty() || state_graph.len() > MAX_TYPED_STATES {
+        return false;
+    }
+
+    // Set of reached states, starting with the initial state.
+    let mut reached: u64 = 1;
+    loop {
+        let mut next = reached;
+        let mut state = 0;
+        while state < state_graph.len() {
+            if reached & (1 << state) != 0 {
+                let allowed_targets = state_graph[state];
+                let mut i = 0;
+                while i < allowed_targets.len() {
+                    next |= 1 << allowed_targets[i];
+                    i += 1;
+                }
+            }
+            state += 1;
+        }
+
+        if next == reached {
+            break;
+        }
+        reached = next;
+    }
+
+    reached.count_ones() as usize == state_graph.len()
+}
+
+/// Define an enum implementing [`LogicStates`](crate::logic::LogicStates).
+///
+/// Each variant lists states to which transition is allowed, the first variant is the initial state.
+/// `Clone`, `Copy`, `Debug`, `PartialEq`, `Eq` and `Hash` are derived.
+/// State tags are created from variant names.
+///
+/// Graph is validated at compile time:
+/// - undefined target states are rejected,
+/// - states unreachable from the initial state are rejected.
+///
+/// ```
+/// use health_monitoring_lib::logic::LogicStates;
+/// use health_monitoring_lib::{logic_states, StateTag};
+///
+/// logic_states! {
+///     /// Engine states.
+///     pub enum Engine {
+///         Off => [Starting],
+///         Starting => [Running, Off],
+///         Running => [Off],
+///     }
+/// }
+///
+/// assert_eq!(Engine::STATES[0], Engine::Off);
+/// assert_eq!(Engine::Starting.tag(), StateTag::from("Starting"));
+/// assert_eq!(Engine::Starting.allowed_targets(), &[Engine::Running, Engine::Off]);
+/// ```
+///
+/// Unreachable states fail to compile:
+///
+/// ```compile_fail
+/// use health_monitoring_lib::logic_states;
+///
+/// logic_states! {
+///     enum Engine {
+///         Off => [Running],
+///         Running => [Off],
+///         Broken => [Off],
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! logic_states {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => [$($target:ident),* $(,)?]
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl $crate::logic::LogicStates for $name {
+            const STATES: &'static [Self] = &[$(Self::$variant),+];
+
+            fn tag(self) -> $crate::StateTag {
+                match self {
+                    $(Self::$variant => $crate::StateTag::new(stringify!($variant)),)+
+                }
+            }
+
+            fn index(self) -> usize {
+                self as usize
+            }
+
+            fn allowed_targets(self) -> &'static [Self] {
+                match self {
+                    $(Self::$variant => &[$(Self::$target),*],)+
+                }
+            }
+        }
+
+        const _: () = {
+            const STATE_GRAPH: &[&[usize]] = &[$(&[$($name::$target as usize),*]),+];
+            assert!(
+                STATE_GRAPH.len() <= $crate::logic::MAX_TYPED_STATES,
+                concat!("Too many states defined in ", stringify!($name))
+            );
+            assert!(
+                $crate::logic::all_states_reachable(STATE_GRAPH),
+                concat!("Unreachable state defined in ", stringify!($name))
+            );
+        };
+    };
+}
+
+/// Logic monitor with states defined by an enum.
+/// Only states defined by `E` can be requested, refer to [`crate::logic_states`].
+pub struct TypedLogicMonitor<E: LogicStates> {
+    monitor: LogicMonitor,
+    /// Handles of the states, ordered as [`LogicStates::STATES`].
+    indices: Vec<StateIndex>,
+    _states: PhantomData<E>,
+}
+
+impl<E: LogicStates> TypedLogicMonitor<E> {
+    /// Create a new [`TypedLogicMonitor`] instance.
+    /// Returns [`None`] if any of the states is not defined in the monitor.
+    pub(crate) fn new(monitor: LogicMonitor) -> Option<Self> {
+        let indices = E::STATES
+            .iter()
+            .map(|state| monitor.state_index(state.tag()))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            monitor,
+            indices,
+            _states: PhantomData,
+        })
+    }
+
+    /// Check all states are defined in the monitor.
+    pub(crate) fn matches(monitor: &LogicMonitor) -> bool {
+        E::STATES.iter().all(|state| monitor.is_defined(state.tag()))
+    }
+
+    /// Perform transition to a new state.
+    /// On success, current state is returned.
+    pub fn transition(&self, state: E) -> Result<E, LogicEvaluationError> {
+        self.monitor
+            .transition_by_index(self.indices[state.index()])
+            .map(|_| state)
+    }
+
+    /// Current monitor state.
+    pub fn state(&self) -> Result<E, LogicEvaluationError> {
+        let state = self.monitor.current_state_index()?;
+        self.indices
+            .iter()
+            .position(|index| *index == state)
+            .map(|position| E::STATES[position])
+            .ok_or(LogicEvaluationError::InvalidState)
+    }
+
+    /// Force the monitor into a given state, refer to [`LogicMonitor::reset_to`].
+    pub fn reset_to(&self, state: E) -> Result<E, HealthMonitorError> {
+        self.monitor.reset_to(state.tag()).map(|_| state)
+    }
+
+    /// Underlying [`LogicMonitor`], e.g., to access diagnostic data.
+    pub fn untyped(&self) -> &LogicMonitor {
+        &self.monitor
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::clock::Clock;
+    use crate::logic::typed::all_states_reachable;
+    use crate::logic::{LogicEvaluationError, LogicMonitorBuilder, LogicStates, ResetPolicy, TypedLogicMonitor};
+    use crate::protected_memory::ProtectedMemoryAllocator;
+    use crate::tag::{MonitorTag, StateTag};
+
+    crate::logic_states! {
+        /// Test states.
+        enum Engine {
+            /// Initial state.
+            Off => [Starting],
+            Starting => [Running, Off],
+            Running => [Off,],
+        }
+    }
+
+    crate::logic_states! {
+        enum Other {
+            Off => [Unknown],
+            Unknown => [],
+        }
+    }
+
+    fn create_monitor(builder: LogicMonitorBuilder) -> TypedLogicMonitor<Engine> {
+        let allocator = ProtectedMemoryAllocator::new();
+        let monitor = builder
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap();
+        TypedLogicMonitor::new(monitor).unwrap()
+    }
+
+    #[test]
+    fn logic_states_generated() {
+        assert_eq!(Engine::STATES, &[Engine::Off, Engine::Starting, Engine::Running]);
+        assert_eq!(Engine::Running.tag(), StateTag::from("Running"));
+        assert_eq!(Engine::Running.index(), 2);
+        assert_eq!(Engine::Starting.allowed_targets(), &[Engine::Running, Engine::Off]);
+        assert!(Other::Unknown.allowed_targets().is_empty());
+        assert_eq!(Engine::from_tag(StateTag::from("Starting")), Some(Engine::Starting));
+        assert_eq!(Engine::from_tag(StateTag::from("Unknown")), None);
+    }
+
+    #[test]
+    fn all_states_reachable_checks_graph() {
+        assert!(all_states_reachable(&[&[1], &[2, 0], &[0]]));
+        assert!(all_states_reachable(&[&[]]));
+        assert!(!all_states_reachable(&[]));
+        assert!(!all_states_reachable(&[&[1], &[0], &[0]]));
+        // Reachable only through another state.
+        assert!(all_states_reachable(&[&[2], &[0], &[1]]));
+    }
+
+    #[test]
+    fn typed_logic_monitor_transition_succeeds() {
+        let monitor = create_monitor(LogicMonitorBuilder::from_states::<Engine>());
+        assert_eq!(monitor.state(), Ok(Engine::Off));
+        assert_eq!(monitor.transition(Engine::Starting), Ok(Engine::Starting));
+        assert_eq!(monitor.transition(Engine::Running), Ok(Engine::Running));
+        assert_eq!(monitor.state(), Ok(Engine::Running));
+    }
+
+    #[test]
+    fn typed_logic_monitor_transition_invalid_transition() {
+        let monitor = create_monitor(LogicMonitorBuilder::from_states::<Engine>());
+        assert_eq!(
+            monitor.transition(Engine::Running),
+            Err(LogicEvaluationError::InvalidTransition)
+        );
+        assert_eq!(
+            monitor.untyped().invalid_transition(),
+            Some((Engine::Off.tag(), Engine::Running.tag()))
+        );
+    }
+
+    #[test]
+    fn typed_logic_monitor_reset_to() {
+        let monitor =
+            create_monitor(LogicMonitorBuilder::from_states::<Engine>().with_reset_policy(ResetPolicy::AnyState));
+        let _ = monitor.transition(Engine::Running);
+        assert_eq!(monitor.reset_to(Engine::Starting), Ok(Engine::Starting));
+        assert_eq!(monitor.state(), Ok(Engine::Starting));
+    }
+
+    #[test]
+    fn typed_logic_monitor_state_not_in_enum() {
+        // Monitor defines an additional state, not known to the enum.
+        let extra = StateTag::from("extra");
+        let builder = LogicMonitorBuilder::from_states::<Engine>()
+            .add_state(Engine::Off.tag(), &[Engine::Starting.tag(), extra])
+            .add_state(extra, &[]);
+        let monitor = create_monitor(builder);
+        let _ = monitor.untyped().transition(extra);
+        assert_eq!(monitor.state(), Err(LogicEvaluationError::InvalidState));
+    }
+
+    #[test]
+    fn typed_logic_monitor_new_missing_state() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let monitor = LogicMonitorBuilder::from_states::<Other>()
+            .build(MonitorTag::from("logic_monitor"), Clock::default(), &allocator)
+            .unwrap();
+        assert!(TypedLogicMonitor::<Engine>::new(monitor).is_none());
+    }
+}
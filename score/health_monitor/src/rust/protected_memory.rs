@@ -10,9 +10,386 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
-#![allow(dead_code)]
 
-///
+//! Protected memory for monitor states.
+//!
+//! Each allocation is placed in a dedicated memory mapping with following layout:
+//!
+//! ```text
+//! | guard page | head canary | data | tail canary | guard page |
+//! ```
+//!
+//! - Guard pages are mapped with `PROT_NONE` - any access results in a segmentation fault.
+//! - Canary words surround the data and are checked by the evaluator - stray writes next to the data are detected.
+//!
+//! Miri does not support `mmap` and `mprotect` - heap allocation with the same layout is used instead.
+//! Canaries are checked as usual, guard pages are not protected.
+
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+use core::ops::Deref;
+use core::ptr::{self, NonNull};
+
+/// Canary pattern, mixed with canary address to make each canary unique.
+const CANARY_PATTERN: u64 = 0x5AFE_C0DE_DEAD_BEEF;
+
 /// A memory allocator that provides protected memory regions for health monitoring data structures.
-/// TODO: Implement the protected memory allocation logic.
-pub struct ProtectedMemoryAllocator {}
+pub(crate) struct ProtectedMemoryAllocator {
+    page_size: usize,
+}
+
+impl ProtectedMemoryAllocator {
+    /// Create a new [`ProtectedMemoryAllocator`].
+    pub(crate) fn new() -> Self {
+        // SAFETY: `sysconf` has no preconditions.
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        let page_size = usize::try_from(page_size).expect("failed to determine page size");
+        Self { page_size }
+    }
+
+    /// Move provided value into protected memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory cannot be mapped or protected.
+    pub(crate) fn allocate<T>(&self, value: T) -> ProtectedBox<T> {
+        ProtectedBox(self.allocate_slice(vec![value]))
+    }
+
+    /// Move provided values into protected memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory cannot be mapped or protected.
+    pub(crate) fn allocate_slice<T>(&self, values: Vec<T>) -> ProtectedSlice<T> {
+        let layout = RegionLayout::new::<T>(values.len(), self.page_size);
+        let base = map_region(&layout).as_ptr();
+
+        // SAFETY:
+        // Offsets are within the data pages of the mapping.
+        // Data offset is aligned for `T`, canary offsets are aligned for `u64`.
+        unsafe {
+            let data = base.add(layout.data_offset).cast::<T>();
+            for (index, value) in values.into_iter().enumerate() {
+                data.add(index).write(value);
+            }
+
+            let head_canary = base.add(layout.head_canary_offset).cast::<u64>();
+            let tail_canary = base.add(layout.tail_canary_offset).cast::<u64>();
+            head_canary.write_volatile(canary_value(head_canary));
+            tail_canary.write_volatile(canary_value(tail_canary));
+        }
+
+        ProtectedSlice {
+            // SAFETY: `map_region` never returns null.
+            base: unsafe { NonNull::new_unchecked(base) },
+            layout,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Map memory for the given layout, with guard pages protected.
+#[cfg(not(miri))]
+fn map_region(layout: &RegionLayout) -> NonNull<u8> {
+    // SAFETY: anonymous private mapping, no file descriptor involved.
+    let base = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            layout.mapping_size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANON,
+            -1,
+            0,
+        )
+    };
+    assert!(base != libc::MAP_FAILED, "failed to map protected memory");
+    let base = base.cast::<u8>();
+
+    // SAFETY: guard pages are within the mapping created above.
+    unsafe {
+        let head_guard = base;
+        let tail_guard = base.add(layout.mapping_size - layout.page_size);
+        for guard in [head_guard, tail_guard] {
+            let result = libc::mprotect(guard.cast(), layout.page_size, libc::PROT_NONE);
+            assert!(result == 0, "failed to protect guard page");
+        }
+    }
+
+    // SAFETY: `mmap` result was checked above.
+    unsafe { NonNull::new_unchecked(base) }
+}
+
+/// Unmap memory mapped by [`map_region`].
+///
+/// # Safety
+///
+/// `base` must be returned by [`map_region`] for the same `layout`.
+#[cfg(not(miri))]
+unsafe fn unmap_region(base: NonNull<u8>, layout: &RegionLayout) {
+    // SAFETY: mapping was created by `map_region` with `mapping_size`.
+    let _ = unsafe { libc::munmap(base.as_ptr().cast(), layout.mapping_size) };
+}
+
+/// Allocate memory for the given layout on the heap, guard pages are not protected.
+#[cfg(miri)]
+fn map_region(layout: &RegionLayout) -> NonNull<u8> {
+    let heap_layout = layout.heap_layout();
+    // SAFETY: layout size is non-zero, it always contains guard pages.
+    let base = unsafe { std::alloc::alloc(heap_layout) };
+    NonNull::new(base).unwrap_or_else(|| std::alloc::handle_alloc_error(heap_layout))
+}
+
+/// Release memory allocated by [`map_region`].
+///
+/// # Safety
+///
+/// `base` must be returned by [`map_region`] for the same `layout`.
+#[cfg(miri)]
+unsafe fn unmap_region(base: NonNull<u8>, layout: &RegionLayout) {
+    // SAFETY: memory was allocated by `map_region` with the same heap layout.
+    unsafe { std::alloc::dealloc(base.as_ptr(), layout.heap_layout()) }
+}
+
+/// Expected canary value for a given canary address.
+fn canary_value(canary: *const u64) -> u64 {
+    CANARY_PATTERN ^ (canary as usize as u64)
+}
+
+/// Offsets and sizes within a protected memory mapping.
+struct RegionLayout {
+    len: usize,
+    page_size: usize,
+    mapping_size: usize,
+    head_canary_offset: usize,
+    data_offset: usize,
+    tail_canary_offset: usize,
+}
+
+impl RegionLayout {
+    fn new<T>(len: usize, page_size: usize) -> Self {
+        let canary_size = size_of::<u64>();
+        let data_align = align_of::<T>().max(align_of::<u64>());
+        assert!(data_align <= page_size, "alignment exceeds page size");
+
+        // Data pages start after the head guard page, head canary is placed right before the data.
+        let data_offset = page_size + canary_size.next_multiple_of(data_align);
+        let head_canary_offset = data_offset - canary_size;
+        let data_size = size_of::<T>().checked_mul(len).expect("protected memory size overflow");
+        let tail_canary_offset = (data_offset + data_size).next_multiple_of(align_of::<u64>());
+        let data_pages_end = (tail_canary_offset + canary_size).next_multiple_of(page_size);
+
+        Self {
+            len,
+            page_size,
+            mapping_size: data_pages_end + page_size,
+            head_canary_offset,
+            data_offset,
+            tail_canary_offset,
+        }
+    }
+
+    /// Heap layout of the whole mapping, aligned to page size.
+    #[cfg(miri)]
+    fn heap_layout(&self) -> core::alloc::Layout {
+        core::alloc::Layout::from_size_align(self.mapping_size, self.page_size)
+            .expect("invalid protected memory layout")
+    }
+}
+
+/// Values placed in protected memory.
+/// Values are immutable, interior mutability (e.g., atomics) must be used for modifiable state.
+pub(crate) struct ProtectedSlice<T> {
+    base: NonNull<u8>,
+    layout: RegionLayout,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: `ProtectedSlice` owns its values, same as `Box<[T]>`.
+unsafe impl<T: Send> Send for ProtectedSlice<T> {}
+// SAFETY: `ProtectedSlice` provides only shared access to its values, same as `Box<[T]>`.
+unsafe impl<T: Sync> Sync for ProtectedSlice<T> {}
+
+impl<T> ProtectedSlice<T> {
+    fn canary(&self, offset: usize) -> *mut u64 {
+        // SAFETY: canary offsets are within the mapping.
+        unsafe { self.base.as_ptr().add(offset).cast::<u64>() }
+    }
+
+    /// Check canary words surrounding the data are intact.
+    pub(crate) fn is_intact(&self) -> bool {
+        [self.layout.head_canary_offset, self.layout.tail_canary_offset]
+            .into_iter()
+            .all(|offset| {
+                let canary = self.canary(offset);
+                // SAFETY: canary is within the mapping, volatile read prevents optimizing the check out.
+                unsafe { canary.read_volatile() == canary_value(canary) }
+            })
+    }
+
+    /// Overwrite head canary, simulating a stray write.
+    #[cfg(all(test, not(loom)))]
+    pub(crate) fn corrupt_canary(&self) {
+        // SAFETY: canary is within the mapping.
+        unsafe { self.canary(self.layout.head_canary_offset).write_volatile(0) }
+    }
+}
+
+impl<T> Deref for ProtectedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `len` values were initialized at `data_offset` during allocation.
+        unsafe {
+            let data = self.base.as_ptr().add(self.layout.data_offset).cast::<T>();
+            core::slice::from_raw_parts(data, self.layout.len)
+        }
+    }
+}
+
+impl<T> Drop for ProtectedSlice<T> {
+    fn drop(&mut self) {
+        // SAFETY:
+        // Values were initialized during allocation and are not used afterwards.
+        // Mapping was created during allocation by `map_region` with the same layout.
+        unsafe {
+            let data = self.base.as_ptr().add(self.layout.data_offset).cast::<T>();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(data, self.layout.len));
+            unmap_region(self.base, &self.layout);
+        }
+    }
+}
+
+/// Single value placed in protected memory.
+pub(crate) struct ProtectedBox<T>(ProtectedSlice<T>);
+
+impl<T> ProtectedBox<T> {
+    /// Check canary words surrounding the value are intact.
+    pub(crate) fn is_intact(&self) -> bool {
+        self.0.is_intact()
+    }
+
+    /// Overwrite head canary, simulating a stray write.
+    #[cfg(all(test, not(loom)))]
+    pub(crate) fn corrupt_canary(&self) {
+        self.0.corrupt_canary()
+    }
+}
+
+impl<T> Deref for ProtectedBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0[0]
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::protected_memory::{ProtectedMemoryAllocator, RegionLayout};
+    use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn region_layout_single_page() {
+        let layout = RegionLayout::new::<u64>(4, 4096);
+        assert_eq!(layout.head_canary_offset, 4096);
+        assert_eq!(layout.data_offset, 4096 + 8);
+        assert_eq!(layout.tail_canary_offset, 4096 + 8 + 32);
+        assert_eq!(layout.mapping_size, 3 * 4096);
+    }
+
+    #[test]
+    fn region_layout_multiple_pages() {
+        let layout = RegionLayout::new::<[u8; 3]>(3000, 4096);
+        assert_eq!(layout.data_offset, 4096 + 8);
+        // Tail canary aligned to `u64`.
+        assert_eq!(layout.tail_canary_offset, 4096 + 9008);
+        assert_eq!(layout.mapping_size, 5 * 4096);
+    }
+
+    #[test]
+    fn region_layout_over_aligned() {
+        #[repr(align(64))]
+        struct OverAligned;
+
+        let layout = RegionLayout::new::<OverAligned>(1, 4096);
+        assert_eq!(layout.data_offset, 4096 + 64);
+        assert_eq!(layout.head_canary_offset, 4096 + 56);
+    }
+
+    #[test]
+    fn allocate_succeeds() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let value = allocator.allocate(AtomicU64::new(123));
+        assert_eq!(value.load(Ordering::Relaxed), 123);
+        value.store(456, Ordering::Relaxed);
+        assert_eq!(value.load(Ordering::Relaxed), 456);
+        assert!(value.is_intact());
+    }
+
+    #[test]
+    fn allocate_slice_succeeds() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let values = allocator.allocate_slice((0..1000u64).collect());
+        assert_eq!(values.len(), 1000);
+        assert!(values.iter().copied().eq(0..1000));
+        assert!(values.is_intact());
+    }
+
+    #[test]
+    fn allocate_slice_empty() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let values = allocator.allocate_slice(Vec::<u64>::new());
+        assert!(values.is_empty());
+        assert!(values.is_intact());
+    }
+
+    #[test]
+    fn data_is_not_in_guard_page() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let values = allocator.allocate_slice(vec![0u8; 10]);
+        let data = values.as_ptr() as usize;
+        let base = values.base.as_ptr() as usize;
+        assert!(data >= base + allocator.page_size);
+        assert!(data + values.len() <= base + values.layout.mapping_size - allocator.page_size);
+    }
+
+    #[test]
+    fn corrupted_canary_detected() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let value = allocator.allocate(AtomicU64::new(0));
+        value.corrupt_canary();
+        assert!(!value.is_intact());
+    }
+
+    #[test]
+    fn overflow_detected() {
+        let allocator = ProtectedMemoryAllocator::new();
+        let values = allocator.allocate_slice(vec![0u64; 2]);
+        // Write past the end of the data.
+        // SAFETY: tail canary follows the data directly and is within the mapping.
+        unsafe {
+            let data_end = values.base.as_ptr().add(values.layout.data_offset + 16);
+            data_end.cast::<u64>().write(0);
+        }
+        assert!(!values.is_intact());
+    }
+
+    #[test]
+    fn drop_releases_values() {
+        struct DropCounter(Arc<AtomicUsize>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let allocator = ProtectedMemoryAllocator::new();
+        let values = allocator.allocate_slice((0..3).map(|_| DropCounter(counter.clone())).collect());
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+        drop(values);
+        assert_eq!(counter.load(Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,344 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::common::MonitorEvaluationError;
+use crate::log::ScoreDebug;
+use crate::tag::{DeadlineTag, EventTag, MonitorTag, StateTag};
+use core::sync::atomic::{AtomicU8, Ordering};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Health monitor status.
+///
+/// Allowed transitions:
+/// - [`Status::Disabled`] -> [`Status::Running`] - monitoring started or resumed.
+/// - [`Status::Running`] -> [`Status::Disabled`] - monitoring paused.
+/// - [`Status::Running`] -> [`Status::Failed`] - monitor reported an error.
+/// - [`Status::Failed`] -> [`Status::Running`] - monitors re-armed.
+/// - any -> [`Status::Stopped`] - monitoring stopped, final state.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum Status {
+    /// Monitoring is running and all monitors are healthy.
+    Running,
+    /// Monitoring is not started yet or paused.
+    Disabled,
+    /// One or more monitors reported an error.
+    Failed,
+    /// Monitoring has been stopped.
+    Stopped,
+}
+
+impl From<Status> for u8 {
+    fn from(value: Status) -> Self {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for Status {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        const RUNNING: u8 = Status::Running as u8;
+        const DISABLED: u8 = Status::Disabled as u8;
+        const FAILED: u8 = Status::Failed as u8;
+        const STOPPED: u8 = Status::Stopped as u8;
+        match value {
+            RUNNING => Ok(Status::Running),
+            DISABLED => Ok(Status::Disabled),
+            FAILED => Ok(Status::Failed),
+            STOPPED => Ok(Status::Stopped),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Object within a monitor that caused the failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub enum FailureOrigin {
+    /// Failing deadline of a deadline monitor.
+    Deadline(DeadlineTag),
+    /// State of a logic monitor at the time of the failure.
+    State(StateTag),
+    /// Source and target state of an invalid logic monitor transition.
+    Transition(StateTag, StateTag),
+    /// State of a logic monitor in which an unexpected event was reported, and the event.
+    Event(StateTag, EventTag),
+}
+
+/// Failure reported by a monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonitorFailure {
+    /// Reported error.
+    pub error: MonitorEvaluationError,
+    /// Time point of evaluation in which the error was observed.
+    pub time: Instant,
+    /// Object within a monitor that caused the failure, if applicable.
+    pub origin: Option<FailureOrigin>,
+}
+
+/// Health of a single monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonitorHealth {
+    /// No errors reported.
+    Healthy,
+    /// Monitor reported an error within its [`crate::FailureTolerance`], last reported failure is provided.
+    Tolerated(MonitorFailure),
+    /// Monitor reported an error, last reported failure is provided.
+    Failed(MonitorFailure),
+}
+
+/// Internal status value - [`Status::Running`] with re-arm of monitors requested.
+/// Reported as [`Status::Running`].
+const RUNNING_REARM_REQUESTED: u8 = u8::MAX;
+
+/// Health state shared between the [`crate::HealthMonitor`] and the monitoring logic.
+pub(crate) struct HealthState {
+    status: AtomicU8,
+    monitors: Mutex<HashMap<MonitorTag, MonitorHealth>>,
+}
+
+impl HealthState {
+    /// Create a new [`HealthState`] with all provided monitors healthy.
+    pub(crate) fn new(monitor_tags: impl IntoIterator<Item = MonitorTag>) -> Self {
+        let monitors = monitor_tags
+            .into_iter()
+            .map(|monitor_tag| (monitor_tag, MonitorHealth::Healthy))
+            .collect();
+        Self {
+            status: AtomicU8::new(Status::Disabled.into()),
+            monitors: Mutex::new(monitors),
+        }
+    }
+
+    /// Current status.
+    pub(crate) fn status(&self) -> Status {
+        match self.status.load(Ordering::Acquire) {
+            RUNNING_REARM_REQUESTED => Status::Running,
+            value => Status::try_from(value).expect("Invalid status value stored"),
+        }
+    }
+
+    /// Set current status.
+    pub(crate) fn set_status(&self, status: Status) {
+        self.status.store(status.into(), Ordering::Release);
+    }
+
+    /// Change status from `from` to `to`.
+    /// Returns `false` if current status is not `from`.
+    pub(crate) fn transition(&self, from: Status, to: Status) -> bool {
+        self.status
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                let current_status = match current {
+                    RUNNING_REARM_REQUESTED => Status::Running,
+                    value => Status::try_from(value).ok()?,
+                };
+                (current_status == from).then_some(to.into())
+            })
+            .is_ok()
+    }
+
+    /// Change status to [`Status::Stopped`].
+    /// Returns `false` if already stopped.
+    pub(crate) fn stop(&self) -> bool {
+        let stopped = Status::Stopped.into();
+        self.status.swap(stopped, Ordering::AcqRel) != stopped
+    }
+
+    /// Change status from `from` to [`Status::Running`], requesting re-arm of all monitors.
+    /// Returns `false` if current status is not `from`.
+    pub(crate) fn request_rearm(&self, from: Status) -> bool {
+        self.status
+            .compare_exchange(
+                from.into(),
+                RUNNING_REARM_REQUESTED,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok()
+    }
+
+    /// Take pending re-arm request.
+    /// Returns `true` if re-arm was requested.
+    pub(crate) fn take_rearm_request(&self) -> bool {
+        self.status
+            .compare_exchange(
+                RUNNING_REARM_REQUESTED,
+                Status::Running.into(),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok()
+    }
+
+    /// Health of the monitor with given tag.
+    /// [`None`] if monitor is unknown.
+    pub(crate) fn monitor_health(&self, monitor_tag: MonitorTag) -> Option<MonitorHealth> {
+        let monitors = self.monitors.lock().unwrap_or_else(|e| e.into_inner());
+        monitors.get(&monitor_tag).copied()
+    }
+
+    /// Record a failure of the monitor with given tag.
+    pub(crate) fn report_failure(&self, monitor_tag: MonitorTag, failure: MonitorFailure) {
+        let mut monitors = self.monitors.lock().unwrap_or_else(|e| e.into_inner());
+        monitors.insert(monitor_tag, MonitorHealth::Failed(failure));
+    }
+
+    /// Record a tolerated failure of the monitor with given tag.
+    pub(crate) fn report_tolerated_failure(&self, monitor_tag: MonitorTag, failure: MonitorFailure) {
+        let mut monitors = self.monitors.lock().unwrap_or_else(|e| e.into_inner());
+        monitors.insert(monitor_tag, MonitorHealth::Tolerated(failure));
+    }
+
+    /// Mark the monitor with given tag as healthy.
+    pub(crate) fn report_recovered(&self, monitor_tag: MonitorTag) {
+        let mut monitors = self.monitors.lock().unwrap_or_else(|e| e.into_inner());
+        monitors.insert(monitor_tag, MonitorHealth::Healthy);
+    }
+
+    /// Mark all monitors as healthy.
+    pub(crate) fn reset_monitors(&self) {
+        let mut monitors = self.monitors.lock().unwrap_or_else(|e| e.into_inner());
+        for monitor_health in monitors.values_mut() {
+            *monitor_health = MonitorHealth::Healthy;
+        }
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::common::MonitorEvaluationError;
+    use crate::logic::LogicEvaluationError;
+    use crate::status::{FailureOrigin, HealthState, MonitorFailure, MonitorHealth, Status};
+    use crate::tag::{MonitorTag, StateTag};
+    use std::time::Instant;
+
+    #[test]
+    fn status_u8_conversion_roundtrip() {
+        for status in [Status::Running, Status::Disabled, Status::Failed, Status::Stopped] {
+            let value: u8 = status.into();
+            assert_eq!(Status::try_from(value), Ok(status));
+        }
+    }
+
+    #[test]
+    fn status_from_u8_invalid() {
+        assert_eq!(Status::try_from(u8::MAX), Err(()));
+    }
+
+    #[test]
+    fn health_state_new_succeeds() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let state = HealthState::new([monitor_tag]);
+        assert_eq!(state.status(), Status::Disabled);
+        assert_eq!(state.monitor_health(monitor_tag), Some(MonitorHealth::Healthy));
+    }
+
+    #[test]
+    fn health_state_set_status() {
+        let state = HealthState::new([]);
+        state.set_status(Status::Running);
+        assert_eq!(state.status(), Status::Running);
+        state.set_status(Status::Failed);
+        assert_eq!(state.status(), Status::Failed);
+    }
+
+    #[test]
+    fn health_state_monitor_health_unknown() {
+        let state = HealthState::new([MonitorTag::from("monitor")]);
+        assert!(state.monitor_health(MonitorTag::from("unknown")).is_none());
+    }
+
+    #[test]
+    fn health_state_report_failure() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let state = HealthState::new([monitor_tag]);
+        let failure = MonitorFailure {
+            error: MonitorEvaluationError::Logic(LogicEvaluationError::InvalidTransition),
+            time: Instant::now(),
+            origin: Some(FailureOrigin::State(StateTag::from("state"))),
+        };
+
+        state.report_failure(monitor_tag, failure);
+        assert_eq!(state.monitor_health(monitor_tag), Some(MonitorHealth::Failed(failure)));
+    }
+
+    #[test]
+    fn health_state_transition_succeeds() {
+        let state = HealthState::new([]);
+        assert!(state.transition(Status::Disabled, Status::Running));
+        assert_eq!(state.status(), Status::Running);
+        assert!(state.transition(Status::Running, Status::Disabled));
+        assert_eq!(state.status(), Status::Disabled);
+    }
+
+    #[test]
+    fn health_state_transition_wrong_state() {
+        let state = HealthState::new([]);
+        assert!(!state.transition(Status::Running, Status::Failed));
+        assert_eq!(state.status(), Status::Disabled);
+    }
+
+    #[test]
+    fn health_state_stop() {
+        let state = HealthState::new([]);
+        state.set_status(Status::Running);
+        assert!(state.stop());
+        assert_eq!(state.status(), Status::Stopped);
+        assert!(!state.stop());
+        assert!(!state.transition(Status::Running, Status::Disabled));
+        assert!(!state.request_rearm(Status::Failed));
+    }
+
+    #[test]
+    fn health_state_rearm_request() {
+        let state = HealthState::new([]);
+        state.set_status(Status::Failed);
+        assert!(!state.take_rearm_request());
+
+        assert!(state.request_rearm(Status::Failed));
+        assert_eq!(state.status(), Status::Running);
+        assert!(!state.request_rearm(Status::Failed));
+
+        assert!(state.take_rearm_request());
+        assert_eq!(state.status(), Status::Running);
+        assert!(!state.take_rearm_request());
+    }
+
+    #[test]
+    fn health_state_rearm_request_paused() {
+        let state = HealthState::new([]);
+        assert!(state.request_rearm(Status::Disabled));
+
+        // Pending re-arm request is dropped when paused.
+        assert!(state.transition(Status::Running, Status::Disabled));
+        assert_eq!(state.status(), Status::Disabled);
+        assert!(!state.take_rearm_request());
+    }
+
+    #[test]
+    fn health_state_reset_monitors() {
+        let monitor_tag = MonitorTag::from("monitor");
+        let state = HealthState::new([monitor_tag]);
+        let failure = MonitorFailure {
+            error: MonitorEvaluationError::Logic(LogicEvaluationError::InvalidTransition),
+            time: Instant::now(),
+            origin: None,
+        };
+
+        state.report_failure(monitor_tag, failure);
+        state.reset_monitors();
+        assert_eq!(state.monitor_health(monitor_tag), Some(MonitorHealth::Healthy));
+    }
+}
@@ -15,9 +15,14 @@
 //! Currently `ScoreSupervisorAPIClient` and `StubSupervisorAPIClient` are supported.
 //! The latter is meant for testing purposes.
 
+use crate::tag::MonitorTag;
+
 /// An abstraction over the API used to notify the supervisor about process liveness.
 pub trait SupervisorAPIClient {
     fn notify_alive(&self);
+
+    /// Actively report a failure of the monitor with the given tag to the supervisor.
+    fn report_failure(&self, monitor_tag: &MonitorTag);
 }
 
 // NOTE: various implementations are not mutually exclusive.
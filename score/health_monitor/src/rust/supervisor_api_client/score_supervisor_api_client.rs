@@ -13,8 +13,9 @@
 
 #![allow(dead_code)]
 
-use crate::log::debug;
+use crate::log::{debug, error};
 use crate::supervisor_api_client::SupervisorAPIClient;
+use crate::tag::MonitorTag;
 
 pub struct ScoreSupervisorAPIClient {
     supervisor_link: alive_rs::Alive,
@@ -36,4 +37,10 @@ impl SupervisorAPIClient for ScoreSupervisorAPIClient {
     fn notify_alive(&self) {
         self.supervisor_link.report_alive();
     }
+
+    fn report_failure(&self, monitor_tag: &MonitorTag) {
+        // Supervisor is not able to receive the monitor tag, log it instead.
+        error!("ScoreSupervisorAPIClient: reporting failure of {:?}", monitor_tag);
+        self.supervisor_link.report_failure();
+    }
 }
@@ -15,8 +15,9 @@
 
 use crate::log::warn;
 use crate::supervisor_api_client::SupervisorAPIClient;
+use crate::tag::MonitorTag;
 
-/// A stub implementation of the SupervisorAPIClient that logs alive notifications.
+/// A stub implementation of the SupervisorAPIClient that logs alive notifications and failure reports.
 pub struct StubSupervisorAPIClient;
 
 impl StubSupervisorAPIClient {
@@ -29,4 +30,8 @@ impl SupervisorAPIClient for StubSupervisorAPIClient {
     fn notify_alive(&self) {
         warn!("StubSupervisorAPIClient: notify_alive called");
     }
+
+    fn report_failure(&self, monitor_tag: &MonitorTag) {
+        warn!("StubSupervisorAPIClient: report_failure called for {:?}", monitor_tag);
+    }
 }
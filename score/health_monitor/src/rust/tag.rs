@@ -14,109 +14,190 @@
 use crate::log;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+/// Registry of strings used by tags created at runtime.
+/// Each unique string is allocated once and kept for the lifetime of the process.
+static TAG_REGISTRY: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Get interned copy of the provided string.
+fn intern(value: &str) -> &'static str {
+    let mut registry = TAG_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(interned) = registry.get(value) {
+        return interned;
+    }
+
+    let interned: &'static str = Box::leak(Box::from(value));
+    registry.insert(interned);
+    interned
+}
+
+/// FNV-1a hash of the provided string, used as a tag identifier.
+const fn tag_id(value: &str) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let bytes = value.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
 
 /// Common string-based tag.
+/// Tags are compared and hashed using an identifier computed from the string.
+/// Tags created at runtime are interned - equal ones share the string and are compared by pointer.
+/// Tags created in const context are not interned - equal ones are additionally compared by string.
 #[derive(Clone, Copy, Eq)]
-#[repr(C)]
 struct Tag {
-    data: *const u8,
-    length: usize,
+    value: &'static str,
+    id: u64,
 }
 
 impl Tag {
-    const fn new(value: &str) -> Self {
+    const fn new(value: &'static str) -> Self {
         Self {
-            data: value.as_ptr(),
-            length: value.len(),
+            value,
+            id: tag_id(value),
         }
     }
 }
 
-unsafe impl Send for Tag {}
-unsafe impl Sync for Tag {}
-
 impl fmt::Debug for Tag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.data, self.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        write!(f, "Tag({})", s)
+        write!(f, "Tag({})", self.value)
     }
 }
 
 impl log::ScoreDebug for Tag {
     fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.data, self.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        log::score_write!(f, "Tag({})", s)
+        log::score_write!(f, "Tag({})", self.value)
     }
 }
 
 impl Hash for Tag {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.data, self.length) };
-        bytes.hash(state);
+        state.write_u64(self.id);
     }
 }
 
 impl PartialEq for Tag {
     fn eq(&self, other: &Self) -> bool {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let self_bytes = unsafe { core::slice::from_raw_parts(self.data, self.length) };
-        let other_bytes = unsafe { core::slice::from_raw_parts(other.data, other.length) };
-        self_bytes == other_bytes
+        // Interned strings match by pointer.
+        // Otherwise strings are compared only on identifier match, to rule out hash collisions.
+        core::ptr::eq(self.value, other.value) || (self.id == other.id && self.value == other.value)
     }
 }
 
 impl From<String> for Tag {
     fn from(value: String) -> Self {
-        let leaked = value.leak();
-        Self {
-            data: leaked.as_ptr(),
-            length: leaked.len(),
-        }
+        Self::from(value.as_str())
     }
 }
 
 impl From<&str> for Tag {
     fn from(value: &str) -> Self {
-        let leaked = value.to_string().leak();
+        Self::new(intern(value))
+    }
+}
+
+/// Tag representation shared with C++.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub(crate) struct FFITag {
+    data: *const u8,
+    length: usize,
+}
+
+impl FFITag {
+    /// Get tag string.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to `length` bytes valid for the duration of the returned borrow.
+    /// Returns [`None`] if data is not a valid UTF-8.
+    unsafe fn as_str(&self) -> Option<&str> {
+        let bytes = unsafe { core::slice::from_raw_parts(self.data, self.length) };
+        core::str::from_utf8(bytes).ok()
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+impl FFITag {
+    /// Create a tag from raw bytes, not necessarily a valid UTF-8.
+    pub(crate) fn from_bytes(bytes: &'static [u8]) -> Self {
+        Self {
+            data: bytes.as_ptr(),
+            length: bytes.len(),
+        }
+    }
+}
+
+impl From<Tag> for FFITag {
+    fn from(value: Tag) -> Self {
         Self {
-            data: leaked.as_ptr(),
-            length: leaked.len(),
+            data: value.value.as_ptr(),
+            length: value.value.len(),
         }
     }
 }
 
+/// Create a tag of the provided type at compile time.
+///
+/// ```
+/// use health_monitoring_lib::{tag, MonitorTag};
+///
+/// let monitor_tag = tag!(MonitorTag, "monitor");
+/// assert_eq!(monitor_tag, MonitorTag::from("monitor"));
+/// ```
+#[macro_export]
+macro_rules! tag {
+    ($tag_type:ty, $value:literal) => {{
+        const TAG: $tag_type = <$tag_type>::new($value);
+        TAG
+    }};
+}
+
 /// Monitor tag.
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
-#[repr(C)]
 pub struct MonitorTag(Tag);
 
 impl MonitorTag {
     /// Create a new monitor tag using provided string.
-    pub const fn new(value: &str) -> Self {
+    pub const fn new(value: &'static str) -> Self {
         MonitorTag(Tag::new(value))
     }
+
+    /// Tag string.
+    pub fn as_str(&self) -> &'static str {
+        self.0.value
+    }
+
+    /// Create a new monitor tag from FFI representation.
+    ///
+    /// # Safety
+    ///
+    /// Refer to [`FFITag::as_str`].
+    /// Returns [`None`] if tag is not a valid UTF-8.
+    pub(crate) unsafe fn from_ffi(tag: &FFITag) -> Option<Self> {
+        unsafe { tag.as_str() }.map(Self::from)
+    }
 }
 
 impl fmt::Debug for MonitorTag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        write!(f, "MonitorTag({})", s)
+        write!(f, "MonitorTag({})", self.0.value)
     }
 }
 
 impl log::ScoreDebug for MonitorTag {
     fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        log::score_write!(f, "MonitorTag({})", s)
+        log::score_write!(f, "MonitorTag({})", self.0.value)
     }
 }
 
@@ -132,33 +213,47 @@ impl From<&str> for MonitorTag {
     }
 }
 
+impl From<MonitorTag> for FFITag {
+    fn from(value: MonitorTag) -> Self {
+        Self::from(value.0)
+    }
+}
+
 /// Deadline tag.
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
-#[repr(C)]
 pub struct DeadlineTag(Tag);
 
 impl DeadlineTag {
     /// Create a new deadline tag using provided string.
-    pub const fn new(value: &str) -> Self {
+    pub const fn new(value: &'static str) -> Self {
         DeadlineTag(Tag::new(value))
     }
+
+    /// Tag string.
+    pub fn as_str(&self) -> &'static str {
+        self.0.value
+    }
+
+    /// Create a new deadline tag from FFI representation.
+    ///
+    /// # Safety
+    ///
+    /// Refer to [`FFITag::as_str`].
+    /// Returns [`None`] if tag is not a valid UTF-8.
+    pub(crate) unsafe fn from_ffi(tag: &FFITag) -> Option<Self> {
+        unsafe { tag.as_str() }.map(Self::from)
+    }
 }
 
 impl fmt::Debug for DeadlineTag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        write!(f, "DeadlineTag({})", s)
+        write!(f, "DeadlineTag({})", self.0.value)
     }
 }
 
 impl log::ScoreDebug for DeadlineTag {
     fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        log::score_write!(f, "DeadlineTag({})", s)
+        log::score_write!(f, "DeadlineTag({})", self.0.value)
     }
 }
 
@@ -174,33 +269,47 @@ impl From<&str> for DeadlineTag {
     }
 }
 
+impl From<DeadlineTag> for FFITag {
+    fn from(value: DeadlineTag) -> Self {
+        Self::from(value.0)
+    }
+}
+
 /// State tag.
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
-#[repr(C)]
 pub struct StateTag(Tag);
 
 impl StateTag {
     /// Create a new state tag using provided string.
-    pub const fn new(value: &str) -> Self {
+    pub const fn new(value: &'static str) -> Self {
         StateTag(Tag::new(value))
     }
+
+    /// Tag string.
+    pub fn as_str(&self) -> &'static str {
+        self.0.value
+    }
+
+    /// Create a new state tag from FFI representation.
+    ///
+    /// # Safety
+    ///
+    /// Refer to [`FFITag::as_str`].
+    /// Returns [`None`] if tag is not a valid UTF-8.
+    pub(crate) unsafe fn from_ffi(tag: &FFITag) -> Option<Self> {
+        unsafe { tag.as_str() }.map(Self::from)
+    }
 }
 
 impl fmt::Debug for StateTag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        write!(f, "StateTag({})", s)
+        write!(f, "StateTag({})", self.0.value)
     }
 }
 
 impl log::ScoreDebug for StateTag {
     fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
-        // SAFETY: the underlying data was created from a valid `&str`.
-        let bytes = unsafe { core::slice::from_raw_parts(self.0.data, self.0.length) };
-        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
-        log::score_write!(f, "StateTag({})", s)
+        log::score_write!(f, "StateTag({})", self.0.value)
     }
 }
 
@@ -216,10 +325,72 @@ impl From<&str> for StateTag {
     }
 }
 
+impl From<StateTag> for FFITag {
+    fn from(value: StateTag) -> Self {
+        Self::from(value.0)
+    }
+}
+
+/// Event tag.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct EventTag(Tag);
+
+impl EventTag {
+    /// Create a new event tag using provided string.
+    pub const fn new(value: &'static str) -> Self {
+        EventTag(Tag::new(value))
+    }
+
+    /// Tag string.
+    pub fn as_str(&self) -> &'static str {
+        self.0.value
+    }
+
+    /// Create a new event tag from FFI representation.
+    ///
+    /// # Safety
+    ///
+    /// Refer to [`FFITag::as_str`].
+    /// Returns [`None`] if tag is not a valid UTF-8.
+    pub(crate) unsafe fn from_ffi(tag: &FFITag) -> Option<Self> {
+        unsafe { tag.as_str() }.map(Self::from)
+    }
+}
+
+impl fmt::Debug for EventTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EventTag({})", self.0.value)
+    }
+}
+
+impl log::ScoreDebug for EventTag {
+    fn fmt(&self, f: log::Writer, _spec: &log::FormatSpec) -> Result<(), log::Error> {
+        log::score_write!(f, "EventTag({})", self.0.value)
+    }
+}
+
+impl From<String> for EventTag {
+    fn from(value: String) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
+impl From<&str> for EventTag {
+    fn from(value: &str) -> Self {
+        Self(Tag::from(value))
+    }
+}
+
+impl From<EventTag> for FFITag {
+    fn from(value: EventTag) -> Self {
+        Self::from(value.0)
+    }
+}
+
 #[cfg(all(test, not(loom)))]
 mod tests {
     use crate::log::score_write;
-    use crate::tag::{DeadlineTag, MonitorTag, StateTag, Tag};
+    use crate::tag::{tag_id, DeadlineTag, EventTag, FFITag, MonitorTag, StateTag, Tag};
     use core::fmt::Write;
     use core::hash::{Hash, Hasher};
     use score_log::fmt::{Error, FormatSpec, Result as FmtResult, ScoreWrite};
@@ -290,9 +461,14 @@ mod tests {
     }
 
     fn compare_tag(tag: Tag, expected: &str) {
-        let tag_as_bytes = unsafe { core::slice::from_raw_parts(tag.data, tag.length) };
-        let tag_as_str = unsafe { core::str::from_utf8_unchecked(tag_as_bytes) };
-        assert_eq!(tag_as_str, expected);
+        assert_eq!(tag.value, expected);
+        assert_eq!(tag.id, tag_id(expected));
+    }
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
     }
 
     #[test]
@@ -494,4 +670,109 @@ mod tests {
         let tag = StateTag::from(example_str);
         compare_tag(tag.0, example_str);
     }
+
+    #[test]
+    fn event_tag_new() {
+        const EXAMPLE_STR: &str = "EXAMPLE";
+        const TAG: EventTag = EventTag::new(EXAMPLE_STR);
+        compare_tag(TAG.0, EXAMPLE_STR);
+    }
+
+    #[test]
+    fn event_tag_debug() {
+        let example_str = "EXAMPLE";
+        let tag = EventTag::from(example_str.to_string());
+        assert_eq!(format!("{:?}", tag), "EventTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn event_tag_score_debug() {
+        let example_str = "EXAMPLE";
+        let tag = EventTag::from(example_str.to_string());
+        let mut writer = StringWriter::new();
+        assert!(score_write!(&mut writer, "{:?}", tag).is_ok());
+        assert_eq!(writer.get(), "EventTag(EXAMPLE)");
+    }
+
+    #[test]
+    fn event_tag_from_string() {
+        let example_str = "EXAMPLE";
+        let tag = EventTag::from(example_str.to_string());
+        compare_tag(tag.0, example_str);
+    }
+
+    #[test]
+    fn event_tag_from_str() {
+        let example_str = "EXAMPLE";
+        let tag = EventTag::from(example_str);
+        compare_tag(tag.0, example_str);
+    }
+
+    #[test]
+    fn tag_from_is_interned() {
+        let tag1 = Tag::from("interned".to_string());
+        let tag2 = Tag::from("interned");
+        assert!(core::ptr::eq(tag1.value, tag2.value));
+    }
+
+    #[test]
+    fn tag_new_eq_from() {
+        const TAG: Tag = Tag::new("EXAMPLE");
+        let tag = Tag::from("EXAMPLE");
+        assert_eq!(TAG, tag);
+        assert_eq!(hash_of(&TAG), hash_of(&tag));
+    }
+
+    #[test]
+    fn tag_id_collision_is_ne() {
+        // Same identifier with different strings must not be equal.
+        let tag1 = Tag::new("first");
+        let tag2 = Tag {
+            value: "second",
+            id: tag1.id,
+        };
+        assert_ne!(tag1, tag2);
+    }
+
+    #[test]
+    fn tag_macro() {
+        let tag = crate::tag!(MonitorTag, "EXAMPLE");
+        assert_eq!(tag, MonitorTag::from("EXAMPLE"));
+        assert_eq!(tag.as_str(), "EXAMPLE");
+        let tag = crate::tag!(DeadlineTag, "EXAMPLE");
+        assert_eq!(tag, DeadlineTag::from("EXAMPLE"));
+        let tag = crate::tag!(StateTag, "EXAMPLE");
+        assert_eq!(tag, StateTag::from("EXAMPLE"));
+        let tag = crate::tag!(EventTag, "EXAMPLE");
+        assert_eq!(tag, EventTag::from("EXAMPLE"));
+    }
+
+    #[test]
+    fn tag_from_ffi() {
+        let data = String::from("EXAMPLE");
+        let ffi_tag = FFITag {
+            data: data.as_ptr(),
+            length: data.len(),
+        };
+        let tag = unsafe { MonitorTag::from_ffi(&ffi_tag) }.unwrap();
+        drop(data);
+        // Tag does not depend on the FFI data.
+        assert_eq!(tag.as_str(), "EXAMPLE");
+    }
+
+    #[test]
+    fn tag_from_ffi_invalid_utf8() {
+        let ffi_tag = FFITag::from_bytes(b"fo\xffo");
+        assert!(unsafe { MonitorTag::from_ffi(&ffi_tag) }.is_none());
+        assert!(unsafe { DeadlineTag::from_ffi(&ffi_tag) }.is_none());
+        assert!(unsafe { StateTag::from_ffi(&ffi_tag) }.is_none());
+        assert!(unsafe { EventTag::from_ffi(&ffi_tag) }.is_none());
+    }
+
+    #[test]
+    fn tag_to_ffi() {
+        let tag = StateTag::from("EXAMPLE");
+        let ffi_tag = FFITag::from(tag);
+        assert_eq!(unsafe { StateTag::from_ffi(&ffi_tag) }, Some(tag));
+    }
 }
@@ -0,0 +1,67 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Helpers shared by unit tests of multiple modules.
+
+use crate::custom::{CustomEvaluationError, CustomMonitor};
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll};
+use std::time::Instant;
+
+/// Future pending on the first poll and returning its value on the next one.
+pub(crate) struct YieldOnce<T: Unpin + Copy> {
+    value: T,
+    polled: bool,
+}
+
+impl<T: Unpin + Copy> YieldOnce<T> {
+    /// Create a new future returning `value` on the second poll.
+    pub(crate) fn new(value: T) -> Self {
+        Self { value, polled: false }
+    }
+}
+
+impl<T: Unpin + Copy> Future for YieldOnce<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.polled {
+            Poll::Ready(self.value)
+        } else {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Custom monitor failing with code 7 until rearmed.
+pub(crate) struct FlagMonitor {
+    pub(crate) failed: AtomicBool,
+}
+
+impl CustomMonitor for FlagMonitor {
+    fn evaluate(&self, _hmon_starting_point: Instant) -> Result<(), CustomEvaluationError> {
+        if self.failed.load(Ordering::Relaxed) {
+            Err(CustomEvaluationError::new(7))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn rearm(&self) {
+        self.failed.store(false, Ordering::Relaxed);
+    }
+}
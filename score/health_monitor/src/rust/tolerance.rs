@@ -0,0 +1,280 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::health_monitor::HealthMonitorError;
+use crate::log::ScoreDebug;
+use crate::status::MonitorFailure;
+
+/// Failure tolerance of a single monitor.
+///
+/// Monitor is considered failed (expired) only after `failures` failed evaluation cycles out of the last `window` cycles.
+/// Until then, failures are tolerated - supervisor is still notified about liveness.
+/// Tolerated monitor is considered healthy again after `recovery_cycles` consecutive cycles without errors.
+///
+/// By default, monitors have no tolerance - first failed evaluation cycle is considered a failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ScoreDebug)]
+pub struct FailureTolerance {
+    failures: u32,
+    window: u32,
+    recovery_cycles: u32,
+}
+
+impl FailureTolerance {
+    /// Max number of evaluation cycles in a window.
+    pub const MAX_WINDOW: u32 = u64::BITS;
+
+    /// Create a new [`FailureTolerance`].
+    ///
+    /// - `failures` - number of failed evaluation cycles considered a failure, must be greater than zero.
+    /// - `window` - number of last evaluation cycles taken into account, must not be lower than `failures`
+    ///   and must not exceed [`Self::MAX_WINDOW`].
+    /// - `recovery_cycles` - number of consecutive cycles without errors after which tolerated monitor is healthy again,
+    ///   must be greater than zero.
+    ///
+    /// Values are validated when the [`crate::HealthMonitor`] is built.
+    pub fn new(failures: u32, window: u32, recovery_cycles: u32) -> Self {
+        Self {
+            failures,
+            window,
+            recovery_cycles,
+        }
+    }
+
+    /// Number of failed evaluation cycles considered a failure.
+    pub fn failures(&self) -> u32 {
+        self.failures
+    }
+
+    /// Number of last evaluation cycles taken into account.
+    pub fn window(&self) -> u32 {
+        self.window
+    }
+
+    /// Number of consecutive cycles without errors after which tolerated monitor is healthy again.
+    pub fn recovery_cycles(&self) -> u32 {
+        self.recovery_cycles
+    }
+
+    /// Check values are in allowed ranges.
+    pub(crate) fn validate(&self) -> Result<(), HealthMonitorError> {
+        if self.failures > 0
+            && self.failures <= self.window
+            && self.window <= Self::MAX_WINDOW
+            && self.recovery_cycles > 0
+        {
+            Ok(())
+        } else {
+            Err(HealthMonitorError::InvalidArgument)
+        }
+    }
+}
+
+/// Outcome of a single evaluation cycle of a debounced monitor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DebounceOutcome {
+    /// Monitor state is not changed.
+    Unchanged,
+    /// Monitor failed, but the failure is tolerated.
+    Tolerated(MonitorFailure),
+    /// Monitor failed and tolerance is exceeded.
+    Expired(MonitorFailure),
+    /// Tolerated monitor is healthy again.
+    Recovered,
+}
+
+/// Debouncing of failures reported by a single monitor.
+pub(crate) struct Debouncer {
+    tolerance: FailureTolerance,
+    /// Failed cycles, most recent cycle in the least significant bit.
+    history: u64,
+    /// Number of consecutive cycles without errors.
+    ok_cycles: u32,
+    /// Failures are currently tolerated.
+    tolerated: bool,
+    /// Last failure reported in the current cycle.
+    failure: Option<MonitorFailure>,
+}
+
+impl Debouncer {
+    /// Create a new [`Debouncer`], tolerance is expected to be valid.
+    pub(crate) fn new(tolerance: FailureTolerance) -> Self {
+        Self {
+            tolerance,
+            history: 0,
+            ok_cycles: 0,
+            tolerated: false,
+            failure: None,
+        }
+    }
+
+    /// Record a failure reported in the current cycle.
+    pub(crate) fn record_failure(&mut self, failure: MonitorFailure) {
+        self.failure = Some(failure);
+    }
+
+    /// Finish the current cycle and determine its outcome.
+    pub(crate) fn finish_cycle(&mut self) -> DebounceOutcome {
+        let window_mask = u64::MAX >> (u64::BITS - self.tolerance.window);
+        self.history = (self.history << 1) & window_mask;
+
+        match self.failure.take() {
+            Some(failure) => {
+                self.history |= 1;
+                self.ok_cycles = 0;
+                if self.history.count_ones() >= self.tolerance.failures {
+                    DebounceOutcome::Expired(failure)
+                } else {
+                    self.tolerated = true;
+                    DebounceOutcome::Tolerated(failure)
+                }
+            },
+            None if self.tolerated => {
+                self.ok_cycles += 1;
+                if self.ok_cycles >= self.tolerance.recovery_cycles {
+                    self.reset();
+                    DebounceOutcome::Recovered
+                } else {
+                    DebounceOutcome::Unchanged
+                }
+            },
+            None => DebounceOutcome::Unchanged,
+        }
+    }
+
+    /// Discard failures observed so far.
+    pub(crate) fn reset(&mut self) {
+        self.history = 0;
+        self.ok_cycles = 0;
+        self.tolerated = false;
+        self.failure = None;
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::deadline::DeadlineEvaluationError;
+    use crate::health_monitor::HealthMonitorError;
+    use crate::status::MonitorFailure;
+    use crate::tolerance::{DebounceOutcome, Debouncer, FailureTolerance};
+    use std::time::Instant;
+
+    fn failure() -> MonitorFailure {
+        MonitorFailure {
+            error: DeadlineEvaluationError::TooLate.into(),
+            time: Instant::now(),
+            origin: None,
+        }
+    }
+
+    /// Run cycles, `true` represents a failed cycle.
+    fn run_cycles(debouncer: &mut Debouncer, cycles: &[bool]) -> Vec<DebounceOutcome> {
+        cycles
+            .iter()
+            .map(|failed| {
+                if *failed {
+                    debouncer.record_failure(failure());
+                }
+                debouncer.finish_cycle()
+            })
+            .collect()
+    }
+
+    fn is_expired(outcome: &DebounceOutcome) -> bool {
+        matches!(outcome, DebounceOutcome::Expired(_))
+    }
+
+    #[test]
+    fn failure_tolerance_validation() {
+        assert!(FailureTolerance::new(1, 1, 1).validate().is_ok());
+        assert!(FailureTolerance::new(3, 64, 10).validate().is_ok());
+        for tolerance in [
+            FailureTolerance::new(0, 5, 1),
+            FailureTolerance::new(6, 5, 1),
+            FailureTolerance::new(3, 65, 1),
+            FailureTolerance::new(3, 5, 0),
+        ] {
+            assert_eq!(tolerance.validate(), Err(HealthMonitorError::InvalidArgument));
+        }
+    }
+
+    #[test]
+    fn debouncer_no_tolerance_expires_immediately() {
+        let mut debouncer = Debouncer::new(FailureTolerance::new(1, 1, 1));
+        let outcomes = run_cycles(&mut debouncer, &[false, true]);
+        assert_eq!(outcomes[0], DebounceOutcome::Unchanged);
+        assert!(is_expired(&outcomes[1]));
+    }
+
+    #[test]
+    fn debouncer_expires_after_failures_in_window() {
+        let mut debouncer = Debouncer::new(FailureTolerance::new(3, 5, 10));
+        let outcomes = run_cycles(&mut debouncer, &[true, false, true, false, true]);
+        assert!(matches!(outcomes[0], DebounceOutcome::Tolerated(_)));
+        assert_eq!(outcomes[1], DebounceOutcome::Unchanged);
+        assert!(matches!(outcomes[2], DebounceOutcome::Tolerated(_)));
+        assert_eq!(outcomes[3], DebounceOutcome::Unchanged);
+        assert!(is_expired(&outcomes[4]));
+    }
+
+    #[test]
+    fn debouncer_failures_slide_out_of_window() {
+        let mut debouncer = Debouncer::new(FailureTolerance::new(3, 5, 10));
+        let outcomes = run_cycles(&mut debouncer, &[true, true, false, false, false, true, false]);
+        assert!(!outcomes.iter().any(is_expired));
+    }
+
+    #[test]
+    fn debouncer_recovers_after_ok_cycles() {
+        let mut debouncer = Debouncer::new(FailureTolerance::new(2, 10, 3));
+        let outcomes = run_cycles(&mut debouncer, &[true, false, false, false, true]);
+        assert!(matches!(outcomes[0], DebounceOutcome::Tolerated(_)));
+        assert_eq!(outcomes[1], DebounceOutcome::Unchanged);
+        assert_eq!(outcomes[2], DebounceOutcome::Unchanged);
+        assert_eq!(outcomes[3], DebounceOutcome::Recovered);
+        // Failures observed before recovery are discarded.
+        assert!(matches!(outcomes[4], DebounceOutcome::Tolerated(_)));
+    }
+
+    #[test]
+    fn debouncer_recovery_interrupted_by_failure() {
+        let mut debouncer = Debouncer::new(FailureTolerance::new(3, 10, 3));
+        let outcomes = run_cycles(&mut debouncer, &[true, false, false, true, false, false]);
+        assert!(!outcomes.contains(&DebounceOutcome::Recovered));
+        assert!(!outcomes.iter().any(is_expired));
+    }
+
+    #[test]
+    fn debouncer_max_window() {
+        let mut debouncer = Debouncer::new(FailureTolerance::new(2, FailureTolerance::MAX_WINDOW, 100));
+        let mut cycles = vec![false; 62];
+        cycles.insert(0, true);
+        cycles.push(true);
+        let outcomes = run_cycles(&mut debouncer, &cycles);
+        assert!(is_expired(outcomes.last().unwrap()));
+
+        // First failure slid out of the window.
+        let mut debouncer = Debouncer::new(FailureTolerance::new(2, FailureTolerance::MAX_WINDOW, 100));
+        cycles.insert(1, false);
+        let outcomes = run_cycles(&mut debouncer, &cycles);
+        assert!(!outcomes.iter().any(is_expired));
+    }
+
+    #[test]
+    fn debouncer_reset() {
+        let mut debouncer = Debouncer::new(FailureTolerance::new(2, 5, 3));
+        let _ = run_cycles(&mut debouncer, &[true]);
+        debouncer.reset();
+        let outcomes = run_cycles(&mut debouncer, &[true]);
+        assert!(matches!(outcomes[0], DebounceOutcome::Tolerated(_)));
+    }
+}
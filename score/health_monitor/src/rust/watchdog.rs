@@ -0,0 +1,258 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::clock::Clock;
+use crate::log::error;
+use crate::status::{HealthState, Status};
+use crate::worker::Checks;
+use core::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use core::time::Duration;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Checkpoint value used before the watchdog is armed.
+const NOT_ARMED: u64 = u64::MAX;
+
+/// Checkpoints reported by the monitoring logic.
+struct WatchdogState {
+    /// Reference time point for stored checkpoint times.
+    starting_point: Instant,
+    /// Time of the last checkpoint in nanoseconds since `starting_point`.
+    last_checkpoint_ns: AtomicU64,
+    /// Last reported checkpoint.
+    last_check: AtomicU32,
+    /// Stall detected and not yet reported to the supervisor.
+    stall_detected: AtomicBool,
+    /// Time without checkpoint after which the monitoring logic is considered stalled.
+    stall_timeout: Duration,
+}
+
+/// Watchdog supervising the health monitoring logic itself.
+///
+/// Monitoring logic reports a checkpoint on each cycle.
+/// [`WorkerWatchdog::check`] shall be called periodically from a thread other than the monitoring thread,
+/// e.g., the application main loop or a dedicated lightweight thread.
+///
+/// When no checkpoint was reported within the stall timeout:
+/// - error is logged,
+/// - status is changed to [`Status::Failed`], alive notifications are no longer sent to the supervisor,
+/// - failure is reported to the supervisor as soon as the monitoring logic resumes.
+///
+/// Watchdog does not report to the supervisor itself, supervisor client is owned by the monitoring logic.
+/// Monitoring logic which never resumes is detected by the supervisor only through missing alive notifications.
+///
+/// Created using [`crate::HealthMonitor::worker_watchdog`] or [`crate::HealthMonitorDriver::worker_watchdog`].
+#[derive(Clone)]
+pub struct WorkerWatchdog {
+    state: Arc<WatchdogState>,
+    health_state: Arc<HealthState>,
+    clock: Clock,
+}
+
+impl WorkerWatchdog {
+    /// Create a new [`WorkerWatchdog`].
+    ///
+    /// - `stall_timeout` - time without checkpoint after which the monitoring logic is considered stalled.
+    /// - `health_state` - shared health state, failed on stall.
+    /// - `clock` - clock providing current time point for [`WorkerWatchdog::check`].
+    pub(crate) fn new(stall_timeout: Duration, health_state: Arc<HealthState>, clock: Clock) -> Self {
+        Self {
+            state: Arc::new(WatchdogState {
+                starting_point: clock.instant(),
+                last_checkpoint_ns: AtomicU64::new(NOT_ARMED),
+                last_check: AtomicU32::new(0),
+                stall_detected: AtomicBool::new(false),
+                stall_timeout,
+            }),
+            health_state,
+            clock,
+        }
+    }
+
+    fn elapsed_ns(&self, now: Instant) -> u64 {
+        let elapsed = now.saturating_duration_since(self.state.starting_point);
+        // Stay below `NOT_ARMED`.
+        u64::try_from(elapsed.as_nanos())
+            .unwrap_or(NOT_ARMED - 1)
+            .min(NOT_ARMED - 1)
+    }
+
+    /// Arm the watchdog, monitoring logic is expected to report checkpoints from now on.
+    pub(crate) fn arm(&self, now: Instant) {
+        self.state
+            .last_checkpoint_ns
+            .store(self.elapsed_ns(now), Ordering::Release);
+    }
+
+    /// Report a checkpoint of the monitoring logic.
+    pub(crate) fn checkpoint(&self, check: Checks, now: Instant) {
+        self.state.last_check.store(check.into(), Ordering::Relaxed);
+        self.state
+            .last_checkpoint_ns
+            .fetch_max(self.elapsed_ns(now), Ordering::AcqRel);
+    }
+
+    /// Take stall detected since the last call.
+    /// Returns `true` if stall shall be reported to the supervisor.
+    pub(crate) fn take_stall(&self) -> bool {
+        self.state.stall_detected.swap(false, Ordering::AcqRel)
+    }
+
+    /// Time without checkpoint after which the monitoring logic is considered stalled.
+    pub fn stall_timeout(&self) -> Duration {
+        self.state.stall_timeout
+    }
+
+    /// Check whether the monitoring logic reported a checkpoint within the stall timeout.
+    ///
+    /// Stall is only observed - status is changed to [`Status::Failed`], nothing is reported to the supervisor.
+    /// Failure report is sent by the monitoring logic once it resumes, refer to [`WorkerWatchdog`].
+    ///
+    /// Returns `false` if the monitoring logic is stalled.
+    /// Returns `true` if monitoring is not started or already stopped.
+    pub fn check(&self) -> bool {
+        self.check_at(self.clock.instant())
+    }
+
+    pub(crate) fn check_at(&self, now: Instant) -> bool {
+        let last_checkpoint_ns = self.state.last_checkpoint_ns.load(Ordering::Acquire);
+        if last_checkpoint_ns == NOT_ARMED || self.health_state.status() == Status::Stopped {
+            return true;
+        }
+
+        let since_checkpoint = Duration::from_nanos(self.elapsed_ns(now).saturating_sub(last_checkpoint_ns));
+        if since_checkpoint <= self.state.stall_timeout {
+            return true;
+        }
+
+        // Status is changed and stall is reported only once.
+        if self.health_state.transition(Status::Running, Status::Failed)
+            || self.health_state.transition(Status::Disabled, Status::Failed)
+        {
+            error!(
+                "Monitoring logic stalled, last checkpoint {} reported {} ms ago.",
+                self.state.last_check.load(Ordering::Relaxed),
+                since_checkpoint.as_millis() as u64
+            );
+            self.state.stall_detected.store(true, Ordering::Release);
+        }
+        false
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use crate::clock::{Clock, TimeSource, VirtualClock, DEFAULT_CLOCK_RESOLUTION};
+    use crate::status::{HealthState, Status};
+    use crate::watchdog::WorkerWatchdog;
+    use crate::worker::Checks;
+    use core::time::Duration;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    const STALL_TIMEOUT: Duration = Duration::from_millis(100);
+
+    fn running_watchdog() -> (WorkerWatchdog, Arc<HealthState>) {
+        let health_state = Arc::new(HealthState::new([]));
+        health_state.set_status(Status::Running);
+        (
+            WorkerWatchdog::new(STALL_TIMEOUT, health_state.clone(), Clock::default()),
+            health_state,
+        )
+    }
+
+    #[test]
+    fn worker_watchdog_not_armed() {
+        let (watchdog, health_state) = running_watchdog();
+        assert!(watchdog.check_at(Instant::now() + 10 * STALL_TIMEOUT));
+        assert_eq!(health_state.status(), Status::Running);
+    }
+
+    #[test]
+    fn worker_watchdog_checkpoint_in_time() {
+        let (watchdog, health_state) = running_watchdog();
+        let now = Instant::now();
+        watchdog.arm(now);
+
+        assert!(watchdog.check_at(now + STALL_TIMEOUT));
+        watchdog.checkpoint(Checks::WorkerCheckpoint, now + STALL_TIMEOUT);
+        assert!(watchdog.check_at(now + 2 * STALL_TIMEOUT));
+        assert_eq!(health_state.status(), Status::Running);
+        assert!(!watchdog.take_stall());
+    }
+
+    #[test]
+    fn worker_watchdog_stall_detected() {
+        let (watchdog, health_state) = running_watchdog();
+        let now = Instant::now();
+        watchdog.arm(now);
+
+        assert!(!watchdog.check_at(now + STALL_TIMEOUT + Duration::from_millis(1)));
+        assert_eq!(health_state.status(), Status::Failed);
+        // Stall is reported once.
+        assert!(!watchdog.check_at(now + 2 * STALL_TIMEOUT));
+        assert!(watchdog.take_stall());
+        assert!(!watchdog.take_stall());
+    }
+
+    #[test]
+    fn worker_watchdog_stall_detected_when_paused() {
+        let (watchdog, health_state) = running_watchdog();
+        health_state.set_status(Status::Disabled);
+        let now = Instant::now();
+        watchdog.arm(now);
+
+        assert!(!watchdog.check_at(now + 2 * STALL_TIMEOUT));
+        assert_eq!(health_state.status(), Status::Failed);
+        assert!(watchdog.take_stall());
+    }
+
+    #[test]
+    fn worker_watchdog_stopped() {
+        let (watchdog, health_state) = running_watchdog();
+        let now = Instant::now();
+        watchdog.arm(now);
+        assert!(health_state.stop());
+
+        assert!(watchdog.check_at(now + 2 * STALL_TIMEOUT));
+        assert_eq!(health_state.status(), Status::Stopped);
+    }
+
+    #[test]
+    fn worker_watchdog_checkpoint_not_moved_back() {
+        let (watchdog, _health_state) = running_watchdog();
+        let now = Instant::now();
+        watchdog.arm(now + STALL_TIMEOUT);
+
+        // Checkpoint with older time point does not override newer one.
+        watchdog.checkpoint(Checks::WorkerCheckpoint, now);
+        assert!(watchdog.check_at(now + 2 * STALL_TIMEOUT));
+    }
+
+    #[test]
+    fn worker_watchdog_check_uses_clock() {
+        let health_state = Arc::new(HealthState::new([]));
+        health_state.set_status(Status::Running);
+        let time_source = VirtualClock::new();
+        let clock = Clock::new(DEFAULT_CLOCK_RESOLUTION, Arc::new(time_source.clone()));
+        let watchdog = WorkerWatchdog::new(STALL_TIMEOUT, health_state.clone(), clock);
+        watchdog.arm(time_source.now());
+
+        // Stall is detected exactly after the timeout.
+        time_source.advance(STALL_TIMEOUT);
+        assert!(watchdog.check());
+        time_source.advance(Duration::from_nanos(1));
+        assert!(!watchdog.check());
+        assert_eq!(health_state.status(), Status::Failed);
+    }
+}
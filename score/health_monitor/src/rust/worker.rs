@@ -10,21 +10,43 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
+use crate::clock::Clock;
 use crate::common::{MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator};
-use crate::log::{info, warn};
+use crate::cycle_timing::CycleTiming;
+use crate::log::{error, info, warn};
+use crate::status::{HealthState, MonitorFailure, Status};
 use crate::supervisor_api_client::SupervisorAPIClient;
+use crate::tag::MonitorTag;
+use crate::tolerance::{DebounceOutcome, Debouncer, FailureTolerance};
+use crate::watchdog::WorkerWatchdog;
 use containers::fixed_capacity::FixedCapacityVec;
 use core::sync::atomic::{AtomicBool, Ordering};
 use core::time::Duration;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 use thread::{spawn, JoinHandle, ThreadParameters};
 
+/// Tag used to report failures of the monitoring logic itself to the supervisor.
+pub(crate) const WORKER_MONITOR_TAG: MonitorTag = MonitorTag::new("health_monitor_worker");
+
+/// Handler called on each error reported by a monitor.
+pub(crate) type ErrorHandler = Box<dyn FnMut(&MonitorTag, MonitorEvaluationError) + Send>;
+
 pub(super) struct MonitoringLogic<T: SupervisorAPIClient> {
     monitors: FixedCapacityVec<MonitorEvalHandle>,
     client: T,
     last_notification: Instant,
     supervisor_api_cycle: Duration,
+    health_state: Arc<HealthState>,
+    cycle_timing: Arc<CycleTiming>,
+    watchdog: WorkerWatchdog,
+    clock: Clock,
+    error_handler: Option<ErrorHandler>,
+    debouncers: HashMap<MonitorTag, Debouncer>,
+    /// Failure was reported to the supervisor.
+    /// Supervisor expires the process supervision on the report, re-arming monitors does not revoke it.
+    failure_reported: bool,
 }
 
 impl<T: SupervisorAPIClient> MonitoringLogic<T> {
@@ -33,25 +55,114 @@ impl<T: SupervisorAPIClient> MonitoringLogic<T> {
     /// * `monitors` - A vector of monitor evaluation handles.
     /// * `supervisor_api_cycle` - Duration between alive notifications to the supervisor.
     /// * `client` - An implementation of the SupervisorAPIClient trait.
+    /// * `health_state` - Shared health state, updated with failures reported by monitors.
+    /// * `cycle_timing` - Shared cycle timing, updated on each cycle.
+    /// * `watchdog` - Watchdog receiving a checkpoint on each cycle.
+    /// * `clock` - Clock used to measure cycle evaluation time.
+    /// * `error_handler` - Optional handler called on each error reported by a monitor.
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         monitors: FixedCapacityVec<MonitorEvalHandle>,
         supervisor_api_cycle: Duration,
         client: T,
+        health_state: Arc<HealthState>,
+        cycle_timing: Arc<CycleTiming>,
+        watchdog: WorkerWatchdog,
+        clock: Clock,
+        error_handler: Option<ErrorHandler>,
     ) -> Self {
         Self {
             monitors,
             client,
             supervisor_api_cycle,
-            last_notification: Instant::now(),
+            last_notification: clock.instant(),
+            health_state,
+            cycle_timing,
+            watchdog,
+            clock,
+            error_handler,
+            debouncers: HashMap::new(),
+            failure_reported: false,
         }
     }
 
-    fn run(&mut self, hmon_starting_point: Instant) -> bool {
+    /// Debounce failures of monitors with provided tolerances.
+    /// Monitors without a tolerance fail on the first reported error.
+    pub(super) fn with_failure_tolerances(mut self, tolerances: &HashMap<MonitorTag, FailureTolerance>) -> Self {
+        self.debouncers = tolerances
+            .iter()
+            .map(|(monitor_tag, tolerance)| (*monitor_tag, Debouncer::new(*tolerance)))
+            .collect();
+        self
+    }
+
+    /// Run a single monitoring cycle scheduled at `scheduled` and record its timing.
+    ///
+    /// - `hmon_starting_point` - starting point of all monitors.
+    /// - `scheduled` - time point at which the cycle was scheduled to start.
+    /// - `now` - time point at which the cycle actually started.
+    ///
+    /// Returns result of [`Self::run_at`] and the time point at which the next cycle is scheduled.
+    /// Result is `false` also if the number of consecutive cycle overruns reached the configured limit.
+    pub(super) fn run_cycle(
+        &mut self,
+        hmon_starting_point: Instant,
+        scheduled: Instant,
+        now: Instant,
+    ) -> (bool, Instant) {
+        self.watchdog.checkpoint(Checks::WorkerCheckpoint, now);
+        // Stall observed by the watchdog is reported once the stalled cycle resumes.
+        if self.watchdog.take_stall() {
+            self.report_worker_failure();
+        }
+
+        let evaluation_start = self.clock.instant();
+        let mut ok = self.run_at(hmon_starting_point, now);
+        let evaluation_time = self.clock.instant().saturating_duration_since(evaluation_start);
+        let record = self.cycle_timing.record(scheduled, now, evaluation_time);
+
+        if record.missed_cycles > 0 {
+            warn!("Monitoring cycle overrun, {} cycle(s) missed.", record.missed_cycles);
+        }
+
+        if record.overrun_limit_exceeded && self.health_state.transition(Status::Running, Status::Failed) {
+            error!("Limit of consecutive monitoring cycle overruns reached.");
+            self.report_worker_failure();
+            ok = false;
+        }
+
+        (ok, record.next_cycle)
+    }
+
+    /// Run a single monitoring cycle using `now` as the current time point for supervisor notifications.
+    ///
+    /// Monitors are evaluated only when [`Status::Running`].
+    /// Supervisor is still notified when monitoring is paused ([`Status::Disabled`]).
+    ///
+    /// Returns `false` if any of the monitors reported an error or monitoring is failed or stopped.
+    pub(super) fn run_at(&mut self, hmon_starting_point: Instant, now: Instant) -> bool {
+        match self.health_state.status() {
+            Status::Running => {},
+            Status::Disabled => {
+                self.notify_alive(now);
+                return true;
+            },
+            Status::Failed | Status::Stopped => return false,
+        }
+
+        if self.health_state.take_rearm_request() {
+            self.rearm();
+        }
+
         let mut has_any_error = false;
 
         for monitor in self.monitors.iter() {
-            monitor.evaluate(hmon_starting_point, &mut |monitor_tag, error| {
-                has_any_error = true;
+            monitor.evaluate(hmon_starting_point, &mut |monitor_tag, error, origin| {
+                let failure = MonitorFailure {
+                    error,
+                    time: now,
+                    origin,
+                };
 
                 match error {
                     MonitorEvaluationError::Deadline(deadline_evaluation_error) => {
@@ -72,22 +183,100 @@ impl<T: SupervisorAPIClient> MonitoringLogic<T> {
                             monitor_tag, logic_evaluation_error
                         )
                     },
+                    MonitorEvaluationError::Custom(custom_evaluation_error) => {
+                        warn!(
+                            "Custom monitor with tag {:?} reported error: {:?}.",
+                            monitor_tag, custom_evaluation_error
+                        )
+                    },
+                }
+
+                if let Some(error_handler) = self.error_handler.as_mut() {
+                    error_handler(monitor_tag, error);
+                }
+
+                // Debounced failures are handled once all monitors are evaluated.
+                if let Some(debouncer) = self.debouncers.get_mut(monitor_tag) {
+                    debouncer.record_failure(failure);
+                    return;
+                }
+
+                has_any_error = true;
+                self.health_state.report_failure(*monitor_tag, failure);
+
+                // Only the first error is actively reported to the supervisor.
+                if !self.failure_reported {
+                    self.failure_reported = true;
+                    self.client.report_failure(monitor_tag);
                 }
             });
         }
 
-        if !has_any_error {
-            if self.last_notification.elapsed() > self.supervisor_api_cycle {
-                self.last_notification = Instant::now();
-                self.client.notify_alive();
+        for (monitor_tag, debouncer) in self.debouncers.iter_mut() {
+            match debouncer.finish_cycle() {
+                DebounceOutcome::Unchanged => {},
+                DebounceOutcome::Tolerated(failure) => {
+                    warn!("Failure of monitor with tag {:?} is tolerated.", monitor_tag);
+                    self.health_state.report_tolerated_failure(*monitor_tag, failure);
+                },
+                DebounceOutcome::Expired(failure) => {
+                    error!("Failure tolerance of monitor with tag {:?} exceeded.", monitor_tag);
+                    has_any_error = true;
+                    self.health_state.report_failure(*monitor_tag, failure);
+
+                    // Only the first error is actively reported to the supervisor.
+                    if !self.failure_reported {
+                        self.failure_reported = true;
+                        self.client.report_failure(monitor_tag);
+                    }
+                },
+                DebounceOutcome::Recovered => {
+                    info!("Monitor with tag {:?} recovered.", monitor_tag);
+                    self.health_state.report_recovered(*monitor_tag);
+                },
             }
+        }
+
+        if !has_any_error {
+            self.notify_alive(now);
         } else {
             warn!("One or more monitors reported errors, skipping AliveAPI notification.");
+            // Status is not changed if monitoring was paused or stopped in the meantime.
+            let _ = self.health_state.transition(Status::Running, Status::Failed);
             return false;
         }
 
         true
     }
+
+    /// Report failure of the monitoring logic itself to the supervisor, unless a failure was already reported.
+    fn report_worker_failure(&mut self) {
+        if !self.failure_reported {
+            self.failure_reported = true;
+            self.client.report_failure(&WORKER_MONITOR_TAG);
+        }
+    }
+
+    fn notify_alive(&mut self, now: Instant) {
+        if now.saturating_duration_since(self.last_notification) > self.supervisor_api_cycle {
+            self.last_notification = now;
+            self.client.notify_alive();
+        }
+    }
+
+    /// Re-arm all monitors, discarding errors observed so far.
+    fn rearm(&mut self) {
+        info!("Re-arming monitors.");
+        for monitor in self.monitors.iter() {
+            monitor.rearm();
+        }
+        for debouncer in self.debouncers.values_mut() {
+            debouncer.reset();
+        }
+        self.health_state.reset_monitors();
+        self.cycle_timing.reset_overruns();
+        // `failure_reported` is kept - supervisor-side expiry is final.
+    }
 }
 
 /// A struct that manages a unique thread for running monitoring logic periodically.
@@ -96,15 +285,17 @@ pub struct UniqueThreadRunner {
     should_stop: Arc<AtomicBool>,
     internal_duration_cycle: Duration,
     thread_parameters: ThreadParameters,
+    clock: Clock,
 }
 
 impl UniqueThreadRunner {
-    pub(super) fn new(internal_duration_cycle: Duration, thread_parameters: ThreadParameters) -> Self {
+    pub(super) fn new(internal_duration_cycle: Duration, thread_parameters: ThreadParameters, clock: Clock) -> Self {
         Self {
             handle: None,
             should_stop: Arc::new(AtomicBool::new(false)),
             internal_duration_cycle,
             thread_parameters,
+            clock,
         }
     }
 
@@ -115,25 +306,29 @@ impl UniqueThreadRunner {
         self.handle = Some({
             let should_stop = self.should_stop.clone();
             let interval = self.internal_duration_cycle;
+            let clock = self.clock.clone();
 
             spawn(
                 move || {
                     info!("Monitoring thread started.");
-                    let hmon_starting_point = Instant::now();
-                    let mut next_sleep_time = interval;
+                    let hmon_starting_point = clock.instant();
+                    let mut next_cycle = hmon_starting_point + interval;
+                    let mut failed = false;
 
-                    // TODO Add some checks and log if cyclicly here is not met.
                     while !should_stop.load(Ordering::Relaxed) {
-                        std::thread::sleep(next_sleep_time);
-
-                        let now = Instant::now();
+                        // Sleep is limited to a single interval, time source might not follow the real time.
+                        let until_next_cycle = next_cycle.saturating_duration_since(clock.instant());
+                        std::thread::sleep(until_next_cycle.min(interval));
 
-                        if !monitoring_logic.run(hmon_starting_point) {
-                            info!("Monitoring logic failed, stopping thread.");
-                            break;
+                        // Thread is kept running after failure to allow re-arming the monitors.
+                        // Cycles are scheduled in relation to the previous schedule, overruns skip missed cycles.
+                        let (ok, scheduled_cycle) =
+                            monitoring_logic.run_cycle(hmon_starting_point, next_cycle, clock.instant());
+                        if !ok && !failed {
+                            info!("Monitoring logic failed, waiting for re-arm.");
                         }
-
-                        next_sleep_time = interval - now.elapsed();
+                        failed = !ok;
+                        next_cycle = scheduled_cycle;
                     }
 
                     info!("Monitoring thread exiting.");
@@ -143,6 +338,11 @@ impl UniqueThreadRunner {
         });
     }
 
+    /// Check if monitoring thread was started.
+    pub(super) fn is_started(&self) -> bool {
+        self.handle.is_some()
+    }
+
     pub fn join(&mut self) {
         self.should_stop.store(true, Ordering::Relaxed);
         if let Some(handle) = self.handle.take() {
@@ -157,9 +357,10 @@ impl Drop for UniqueThreadRunner {
     }
 }
 
-#[allow(dead_code)]
+/// Checkpoints reported by the monitoring logic to the [`WorkerWatchdog`].
 #[derive(Copy, Clone)]
 pub(crate) enum Checks {
+    /// Monitoring cycle started.
     WorkerCheckpoint,
 }
 
@@ -174,44 +375,77 @@ impl From<Checks> for u32 {
 #[score_testing_macros::test_mod_with_log]
 #[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::common::{Monitor, TimeRange};
+    use crate::clock::{Clock, VirtualClock, DEFAULT_CLOCK_RESOLUTION};
+    use crate::common::{
+        Monitor, MonitorEvalHandle, MonitorEvaluationError, MonitorEvaluator, MonitorKind, OnErrorFn, TimeRange,
+    };
+    use crate::cycle_timing::CycleTiming;
+    use crate::deadline::DeadlineEvaluationError;
     use crate::deadline::{DeadlineMonitor, DeadlineMonitorBuilder};
     use crate::protected_memory::ProtectedMemoryAllocator;
+    use crate::status::{FailureOrigin, HealthState, MonitorHealth, Status};
     use crate::supervisor_api_client::SupervisorAPIClient;
     use crate::tag::{DeadlineTag, MonitorTag};
-    use crate::worker::{MonitoringLogic, UniqueThreadRunner};
+    use crate::tolerance::FailureTolerance;
+    use crate::watchdog::WorkerWatchdog;
+    use crate::worker::{MonitoringLogic, UniqueThreadRunner, WORKER_MONITOR_TAG};
     use containers::fixed_capacity::FixedCapacityVec;
-    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use core::time::Duration;
-    use std::sync::Arc;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
     use std::time::Instant;
     use thread::ThreadParameters;
 
     #[derive(Clone)]
     struct MockSupervisorAPIClient {
         pub notify_called: Arc<AtomicUsize>,
+        pub failures_reported: Arc<Mutex<Vec<MonitorTag>>>,
     }
 
     impl MockSupervisorAPIClient {
         pub fn new() -> Self {
             Self {
                 notify_called: Arc::new(AtomicUsize::new(0)),
+                failures_reported: Arc::new(Mutex::new(Vec::new())),
             }
         }
 
         fn get_notify_count(&self) -> usize {
             self.notify_called.load(Ordering::Acquire)
         }
+
+        fn get_failures_reported(&self) -> Vec<MonitorTag> {
+            self.failures_reported.lock().unwrap().clone()
+        }
     }
 
     impl SupervisorAPIClient for MockSupervisorAPIClient {
         fn notify_alive(&self) {
             self.notify_called.fetch_add(1, Ordering::AcqRel);
         }
+
+        fn report_failure(&self, monitor_tag: &MonitorTag) {
+            self.failures_reported.lock().unwrap().push(*monitor_tag);
+        }
+    }
+
+    fn running_health_state(monitor_tags: impl IntoIterator<Item = MonitorTag>) -> Arc<HealthState> {
+        let health_state = Arc::new(HealthState::new(monitor_tags));
+        health_state.set_status(Status::Running);
+        health_state
+    }
+
+    fn def_cycle_timing() -> Arc<CycleTiming> {
+        Arc::new(CycleTiming::new(Duration::from_millis(10), None))
+    }
+
+    fn def_watchdog() -> WorkerWatchdog {
+        WorkerWatchdog::new(Duration::from_secs(1), running_health_state([]), Clock::default())
     }
 
     fn create_monitor_with_deadlines() -> DeadlineMonitor {
-        let allocator = ProtectedMemoryAllocator {};
+        let allocator = ProtectedMemoryAllocator::new();
         let monitor_tag = MonitorTag::from("deadline_monitor");
         DeadlineMonitorBuilder::new()
             .add_deadline(
@@ -222,7 +456,8 @@ mod tests {
                 DeadlineTag::from("deadline_fast"),
                 TimeRange::new(Duration::from_millis(0), Duration::from_millis(50)),
             )
-            .build(monitor_tag, &allocator)
+            .build(monitor_tag, Clock::default(), &allocator)
+            .unwrap()
     }
 
     #[test]
@@ -239,6 +474,11 @@ mod tests {
             },
             Duration::from_secs(1),
             alive_mock.clone(),
+            running_health_state([]),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
         );
 
         let mut deadline = deadline_monitor
@@ -248,10 +488,143 @@ mod tests {
 
         drop(handle);
 
-        assert!(!logic.run(hmon_starting_point));
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
         assert_eq!(alive_mock.get_notify_count(), 0);
     }
 
+    #[test]
+    fn monitoring_logic_report_failure_once() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let alive_mock = MockSupervisorAPIClient::new();
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            alive_mock.clone(),
+            running_health_state([]),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        let handle = deadline.start().unwrap();
+
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(alive_mock.get_failures_reported().is_empty());
+
+        drop(handle);
+
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(
+            alive_mock.get_failures_reported(),
+            vec![MonitorTag::from("deadline_monitor")]
+        );
+    }
+
+    #[test]
+    fn monitoring_logic_updates_health_state_when_deadline_failed() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let health_state = running_health_state([monitor_tag]);
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            health_state.clone(),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        let handle = deadline.start().unwrap();
+
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(health_state.monitor_health(monitor_tag), Some(MonitorHealth::Healthy));
+
+        drop(handle);
+
+        let now = Instant::now();
+        assert!(!logic.run_at(hmon_starting_point, now));
+        assert_eq!(health_state.status(), Status::Failed);
+        match health_state.monitor_health(monitor_tag) {
+            Some(MonitorHealth::Failed(failure)) => {
+                assert_eq!(
+                    failure.error,
+                    MonitorEvaluationError::Deadline(DeadlineEvaluationError::TooEarly)
+                );
+                assert_eq!(failure.time, now);
+                assert_eq!(
+                    failure.origin,
+                    Some(FailureOrigin::Deadline(DeadlineTag::from("deadline_long")))
+                );
+            },
+            other => panic!("unexpected monitor health: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn monitoring_logic_calls_error_handler_when_deadline_failed() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let reported_errors = Arc::new(Mutex::new(Vec::new()));
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            MockSupervisorAPIClient::new(),
+            running_health_state([]),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            Some(Box::new({
+                let reported_errors = reported_errors.clone();
+                move |monitor_tag: &MonitorTag, error| reported_errors.lock().unwrap().push((*monitor_tag, error))
+            })),
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        let handle = deadline.start().unwrap();
+
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(reported_errors.lock().unwrap().is_empty());
+
+        drop(handle);
+
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+        let reported_errors = reported_errors.lock().unwrap();
+        assert_eq!(reported_errors.len(), 1);
+        let (monitor_tag, error) = reported_errors[0];
+        assert_eq!(monitor_tag, MonitorTag::from("deadline_monitor"));
+        assert_eq!(error.kind(), MonitorKind::Deadline);
+        assert_eq!(error, DeadlineEvaluationError::TooEarly.into());
+    }
+
     #[test]
     fn monitoring_logic_report_alive_on_each_call_when_no_error() {
         let deadline_monitor = create_monitor_with_deadlines();
@@ -266,6 +639,11 @@ mod tests {
             },
             Duration::from_nanos(0), // Make sure each call notifies alive
             alive_mock.clone(),
+            running_health_state([]),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
         );
 
         let mut deadline = deadline_monitor
@@ -273,11 +651,11 @@ mod tests {
             .unwrap();
         let _handle = deadline.start().unwrap();
 
-        assert!(logic.run(hmon_starting_point));
-        assert!(logic.run(hmon_starting_point));
-        assert!(logic.run(hmon_starting_point));
-        assert!(logic.run(hmon_starting_point));
-        assert!(logic.run(hmon_starting_point));
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
 
         assert_eq!(alive_mock.get_notify_count(), 5);
     }
@@ -286,7 +664,9 @@ mod tests {
     fn monitoring_logic_report_alive_respect_cycle() {
         let deadline_monitor = create_monitor_with_deadlines();
         let alive_mock = MockSupervisorAPIClient::new();
-        let hmon_starting_point = Instant::now();
+        let time_source = VirtualClock::new();
+        let clock = Clock::new(DEFAULT_CLOCK_RESOLUTION, Arc::new(time_source.clone()));
+        let hmon_starting_point = clock.instant();
 
         let mut logic = MonitoringLogic::new(
             {
@@ -296,6 +676,11 @@ mod tests {
             },
             Duration::from_millis(30),
             alive_mock.clone(),
+            running_health_state([]),
+            def_cycle_timing(),
+            def_watchdog(),
+            clock.clone(),
+            None,
         );
 
         let mut deadline = deadline_monitor
@@ -303,22 +688,402 @@ mod tests {
             .unwrap();
         let _handle = deadline.start().unwrap();
 
-        std::thread::sleep(Duration::from_millis(30));
-        assert!(logic.run(hmon_starting_point));
+        // Notification is sent only after the cycle has fully elapsed.
+        time_source.advance(Duration::from_millis(30));
+        assert!(logic.run_at(hmon_starting_point, clock.instant()));
+        assert_eq!(alive_mock.get_notify_count(), 0);
+
+        for _ in 0..5 {
+            time_source.advance(Duration::from_millis(31));
+            assert!(logic.run_at(hmon_starting_point, clock.instant()));
+        }
+
+        assert_eq!(alive_mock.get_notify_count(), 5);
+    }
+
+    #[test]
+    fn monitoring_logic_run_at_respects_provided_time_point() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let alive_mock = MockSupervisorAPIClient::new();
+        let hmon_starting_point = Instant::now();
 
-        std::thread::sleep(Duration::from_millis(30));
-        assert!(logic.run(hmon_starting_point));
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_millis(100),
+            alive_mock.clone(),
+            running_health_state([]),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
 
-        std::thread::sleep(Duration::from_millis(30));
-        assert!(logic.run(hmon_starting_point));
+        let now = Instant::now();
+        assert!(logic.run_at(hmon_starting_point, now + Duration::from_millis(150)));
+        assert_eq!(alive_mock.get_notify_count(), 1);
 
-        std::thread::sleep(Duration::from_millis(30));
-        assert!(logic.run(hmon_starting_point));
+        // Supervisor API cycle not elapsed since last notification.
+        assert!(logic.run_at(hmon_starting_point, now + Duration::from_millis(200)));
+        assert_eq!(alive_mock.get_notify_count(), 1);
 
-        std::thread::sleep(Duration::from_millis(30));
-        assert!(logic.run(hmon_starting_point));
+        assert!(logic.run_at(hmon_starting_point, now + Duration::from_millis(300)));
+        assert_eq!(alive_mock.get_notify_count(), 2);
+    }
 
-        assert_eq!(alive_mock.get_notify_count(), 5);
+    #[test]
+    fn monitoring_logic_paused_notifies_without_evaluation() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let alive_mock = MockSupervisorAPIClient::new();
+        let health_state = running_health_state([]);
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            alive_mock.clone(),
+            health_state.clone(),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        let handle = deadline.start().unwrap();
+
+        assert!(health_state.transition(Status::Running, Status::Disabled));
+        drop(handle);
+
+        // Deadline finished too early, but monitors are not evaluated while paused.
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(health_state.status(), Status::Disabled);
+        assert_eq!(alive_mock.get_notify_count(), 1);
+        assert!(alive_mock.get_failures_reported().is_empty());
+    }
+
+    #[test]
+    fn monitoring_logic_resume_rearms_monitors() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let alive_mock = MockSupervisorAPIClient::new();
+        let health_state = running_health_state([]);
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            alive_mock.clone(),
+            health_state.clone(),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        let handle = deadline.start().unwrap();
+
+        assert!(health_state.transition(Status::Running, Status::Disabled));
+        drop(handle);
+        assert!(health_state.request_rearm(Status::Disabled));
+
+        // Error observed while paused is discarded.
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(health_state.status(), Status::Running);
+        assert!(alive_mock.get_failures_reported().is_empty());
+    }
+
+    #[test]
+    fn monitoring_logic_failed_skips_evaluation() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let alive_mock = MockSupervisorAPIClient::new();
+        let health_state = running_health_state([]);
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            alive_mock.clone(),
+            health_state.clone(),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
+
+        health_state.set_status(Status::Failed);
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+        health_state.set_status(Status::Stopped);
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(alive_mock.get_notify_count(), 0);
+    }
+
+    #[test]
+    fn monitoring_logic_rearm_after_failure() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let monitor_tag = MonitorTag::from("deadline_monitor");
+        let alive_mock = MockSupervisorAPIClient::new();
+        let health_state = running_health_state([monitor_tag]);
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            alive_mock.clone(),
+            health_state.clone(),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
+
+        let mut deadline = deadline_monitor
+            .get_deadline(DeadlineTag::from("deadline_long"))
+            .unwrap();
+        drop(deadline.start().unwrap());
+
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(health_state.status(), Status::Failed);
+        assert!(matches!(
+            health_state.monitor_health(monitor_tag),
+            Some(MonitorHealth::Failed(_))
+        ));
+
+        // Re-arm - monitor is healthy again and deadline can be restarted.
+        assert!(health_state.request_rearm(Status::Failed));
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(health_state.status(), Status::Running);
+        assert_eq!(health_state.monitor_health(monitor_tag), Some(MonitorHealth::Healthy));
+        assert_eq!(alive_mock.get_notify_count(), 1);
+
+        // Failure is not reported to the supervisor again.
+        drop(deadline.start().unwrap());
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(alive_mock.get_failures_reported(), vec![monitor_tag]);
+    }
+
+    #[test]
+    fn monitoring_logic_cycle_overrun_limit_reported() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let alive_mock = MockSupervisorAPIClient::new();
+        let health_state = running_health_state([]);
+        let hmon_starting_point = Instant::now();
+        let interval = Duration::from_millis(10);
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            alive_mock.clone(),
+            health_state.clone(),
+            Arc::new(CycleTiming::new(interval, Some(2))),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        );
+
+        // Each cycle starts one interval late.
+        let scheduled = Instant::now();
+        let (ok, next_cycle) = logic.run_cycle(hmon_starting_point, scheduled, scheduled + interval);
+        assert!(ok);
+        assert_eq!(next_cycle, scheduled + 2 * interval);
+        assert_eq!(health_state.status(), Status::Running);
+
+        let (ok, _) = logic.run_cycle(hmon_starting_point, next_cycle, next_cycle + interval);
+        assert!(!ok);
+        assert_eq!(health_state.status(), Status::Failed);
+        assert_eq!(alive_mock.get_failures_reported(), vec![WORKER_MONITOR_TAG]);
+
+        // Re-arm resets consecutive overruns.
+        assert!(health_state.request_rearm(Status::Failed));
+        let scheduled = Instant::now();
+        let (ok, _) = logic.run_cycle(hmon_starting_point, scheduled, scheduled + interval);
+        assert!(ok);
+        assert_eq!(health_state.status(), Status::Running);
+    }
+
+    #[test]
+    fn monitoring_logic_worker_stall_reported() {
+        let deadline_monitor = create_monitor_with_deadlines();
+        let alive_mock = MockSupervisorAPIClient::new();
+        let health_state = running_health_state([]);
+        let watchdog = WorkerWatchdog::new(Duration::from_millis(100), health_state.clone(), Clock::default());
+        let hmon_starting_point = Instant::now();
+
+        let mut logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(2);
+                vec.push(deadline_monitor.get_eval_handle()).unwrap();
+                vec
+            },
+            Duration::from_secs(1),
+            alive_mock.clone(),
+            health_state.clone(),
+            def_cycle_timing(),
+            watchdog.clone(),
+            Clock::default(),
+            None,
+        );
+
+        let now = Instant::now();
+        watchdog.arm(now);
+        let (ok, _) = logic.run_cycle(hmon_starting_point, now, now);
+        assert!(ok);
+
+        // No checkpoint within stall timeout.
+        let now = now + Duration::from_millis(200);
+        assert!(!watchdog.check_at(now));
+        assert_eq!(health_state.status(), Status::Failed);
+        assert!(alive_mock.get_failures_reported().is_empty());
+
+        // Stall is reported to the supervisor once monitoring logic resumes.
+        let (ok, _) = logic.run_cycle(hmon_starting_point, now, now);
+        assert!(!ok);
+        assert_eq!(alive_mock.get_failures_reported(), vec![WORKER_MONITOR_TAG]);
+        assert!(watchdog.check_at(now));
+    }
+
+    /// Monitor reporting an error on each evaluation while `failing` is set.
+    struct FlakyMonitor {
+        monitor_tag: MonitorTag,
+        failing: AtomicBool,
+    }
+
+    impl MonitorEvaluator for FlakyMonitor {
+        fn evaluate(&self, _hmon_starting_point: Instant, on_error: &mut OnErrorFn<'_>) {
+            if self.failing.load(Ordering::Acquire) {
+                on_error(&self.monitor_tag, DeadlineEvaluationError::TooLate.into(), None);
+            }
+        }
+
+        fn rearm(&self) {}
+    }
+
+    fn create_tolerant_logic(
+        failures: u32,
+        window: u32,
+        recovery_cycles: u32,
+    ) -> (
+        Arc<FlakyMonitor>,
+        MonitoringLogic<MockSupervisorAPIClient>,
+        MockSupervisorAPIClient,
+        Arc<HealthState>,
+    ) {
+        let monitor_tag = MonitorTag::from("flaky_monitor");
+        let monitor = Arc::new(FlakyMonitor {
+            monitor_tag,
+            failing: AtomicBool::new(false),
+        });
+        let alive_mock = MockSupervisorAPIClient::new();
+        let health_state = running_health_state([monitor_tag]);
+        let logic = MonitoringLogic::new(
+            {
+                let mut vec = FixedCapacityVec::new(1);
+                vec.push(MonitorEvalHandle::new(monitor.clone())).unwrap();
+                vec
+            },
+            Duration::from_nanos(0), // Make sure each call notifies alive
+            alive_mock.clone(),
+            health_state.clone(),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
+        )
+        .with_failure_tolerances(&HashMap::from([(
+            monitor_tag,
+            FailureTolerance::new(failures, window, recovery_cycles),
+        )]));
+        (monitor, logic, alive_mock, health_state)
+    }
+
+    #[test]
+    fn monitoring_logic_tolerated_failure_notifies_alive() {
+        let (monitor, mut logic, alive_mock, health_state) = create_tolerant_logic(2, 5, 3);
+        let hmon_starting_point = Instant::now();
+
+        monitor.failing.store(true, Ordering::Release);
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(alive_mock.get_notify_count(), 1);
+        assert!(alive_mock.get_failures_reported().is_empty());
+        assert_eq!(health_state.status(), Status::Running);
+        assert!(matches!(
+            health_state.monitor_health(MonitorTag::from("flaky_monitor")),
+            Some(MonitorHealth::Tolerated(_))
+        ));
+    }
+
+    #[test]
+    fn monitoring_logic_tolerance_exceeded() {
+        let (monitor, mut logic, alive_mock, health_state) = create_tolerant_logic(2, 3, 3);
+        let hmon_starting_point = Instant::now();
+
+        monitor.failing.store(true, Ordering::Release);
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        monitor.failing.store(false, Ordering::Release);
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        monitor.failing.store(true, Ordering::Release);
+        assert!(!logic.run_at(hmon_starting_point, Instant::now()));
+
+        assert_eq!(alive_mock.get_notify_count(), 2);
+        assert_eq!(
+            alive_mock.get_failures_reported(),
+            vec![MonitorTag::from("flaky_monitor")]
+        );
+        assert_eq!(health_state.status(), Status::Failed);
+        assert!(matches!(
+            health_state.monitor_health(MonitorTag::from("flaky_monitor")),
+            Some(MonitorHealth::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn monitoring_logic_tolerated_failure_recovers() {
+        let (monitor, mut logic, alive_mock, health_state) = create_tolerant_logic(2, 10, 2);
+        let hmon_starting_point = Instant::now();
+        let monitor_tag = MonitorTag::from("flaky_monitor");
+
+        monitor.failing.store(true, Ordering::Release);
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        monitor.failing.store(false, Ordering::Release);
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(matches!(
+            health_state.monitor_health(monitor_tag),
+            Some(MonitorHealth::Tolerated(_))
+        ));
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert_eq!(health_state.monitor_health(monitor_tag), Some(MonitorHealth::Healthy));
+
+        // Failures observed before recovery are discarded.
+        monitor.failing.store(true, Ordering::Release);
+        assert!(logic.run_at(hmon_starting_point, Instant::now()));
+        assert!(alive_mock.get_failures_reported().is_empty());
     }
 
     #[test]
@@ -337,10 +1102,15 @@ mod tests {
             },
             Duration::from_nanos(0), // Make sure each call notifies alive
             alive_mock.clone(),
+            running_health_state([]),
+            def_cycle_timing(),
+            def_watchdog(),
+            Clock::default(),
+            None,
         );
 
         let thread_parameters = ThreadParameters::default();
-        let mut worker = UniqueThreadRunner::new(Duration::from_millis(10), thread_parameters);
+        let mut worker = UniqueThreadRunner::new(Duration::from_millis(10), thread_parameters, Clock::default());
         worker.start(logic);
 
         let mut deadline = deadline_monitor
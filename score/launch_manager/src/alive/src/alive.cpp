@@ -44,7 +44,10 @@ void Alive::ReportAlive() const noexcept
 
 void Alive::ReportFailure() const noexcept
 {
-    SCORE_LANGUAGE_FUTURECPP_PRECONDITION_PRD_MESSAGE(false, "Alive::ReportFailure() is not yet implemented");
+    if (aliveImplPtr.get() != nullptr)
+    {
+        aliveImplPtr->ReportCheckpoint(score::mw::lifecycle::internal::saf::ifappl::k_failureCheckpointId);
+    }
 }
 
 }  // namespace score::mw::lifecycle
@@ -64,8 +64,8 @@ class Alive final
     void ReportAlive() const noexcept;
 
     /// @brief Report a direct failure
+    /// @details Expires the alive supervision of the process and triggers the configured recovery action.
     /// @remark Thread safety: This method is NOT thread safe.
-    /// @note Not Implemented. This method currently does nothing.
     void ReportFailure() const noexcept;
 
   private:
@@ -95,7 +95,6 @@ void score_lcm_alive_report_alive(void* instance);
 
 /// @brief Report a direct failure.
 /// @param [in] instance  Opaque pointer previously returned by score_lcm_alive_initialize (must not be NULL)
-/// @note Not Implemented. This method currently does nothing.
 void score_lcm_alive_report_failure(void* instance);
 
 #ifdef __cplusplus
@@ -25,6 +25,7 @@ Checkpoint::Checkpoint(
       k_checkpointId(f_checkpointId),
       processState(f_processState_p),
       isDataLossEvent(false),
+      isFailureEvent(false),
       timestamp(0U)
 {
     static_cast<void>(0U);
@@ -63,6 +64,16 @@ bool Checkpoint::getDataLossEvent(void) const noexcept(true)
     return isDataLossEvent;
 }
 
+void Checkpoint::setFailureEvent(const bool f_isFailureEvent) noexcept(true)
+{
+    isFailureEvent = f_isFailureEvent;
+}
+
+bool Checkpoint::getFailureEvent(void) const noexcept(true)
+{
+    return isFailureEvent;
+}
+
 std::string_view Checkpoint::getConfigName(void) const noexcept(true)
 {
     return k_configName;
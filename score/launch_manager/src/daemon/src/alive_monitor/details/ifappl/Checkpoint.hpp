@@ -94,6 +94,15 @@ class Checkpoint : public saf::common::Observable<Checkpoint>
     /// @return     Data loss event occurred (true)
     bool getDataLossEvent(void) const noexcept(true);
 
+    /// @brief Set failure event
+    /// @details Set failure event in the checkpoint observer, reported directly by the application
+    /// @param [in] f_isFailureEvent   set failure event marker
+    void setFailureEvent(const bool f_isFailureEvent) noexcept(true);
+
+    /// @brief Is failure event
+    /// @return     Failure was reported by the application (true)
+    bool getFailureEvent(void) const noexcept(true);
+
     /// @brief Get the configuration name of the corresponding SupervisionCheckpoint
     /// @return     Name of the corresponding SupervisionCheckpoint (configuration element)
     std::string_view getConfigName(void) const noexcept(true);
@@ -115,6 +124,9 @@ class Checkpoint : public saf::common::Observable<Checkpoint>
     /// @brief Data loss event marker
     bool isDataLossEvent;
 
+    /// @brief Failure event marker
+    bool isFailureEvent;
+
     /// @brief Timestamp value in [nano seconds]
     score::mw::lifecycle::internal::saf::timers::NanoSecondType timestamp;
 };
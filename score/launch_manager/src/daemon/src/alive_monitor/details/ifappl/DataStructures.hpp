@@ -35,6 +35,11 @@ namespace ifappl
 // coverity[autosar_cpp14_a0_1_1_violation] value is referenced in multiple files, but depending on build package.
 constexpr uint16_t k_maxCheckpointBufferElements{512U};
 
+/// Reserved checkpoint ID used by an Alive instance to report a direct failure.
+/// It is never assigned to a configured supervision checkpoint.
+// coverity[autosar_cpp14_a0_1_1_violation] value is referenced in multiple files, but depending on build package.
+constexpr uint32_t k_failureCheckpointId{UINT32_MAX};
+
 /// Variable data exchange buffer: For every report of checkpoint,
 /// one new instance of the below structure is created and stored
 /// in the shared memory
@@ -120,6 +120,19 @@ void MonitorIfDaemon::handleOverflow()
 
 void MonitorIfDaemon::pushCheckpointToObservers(CheckpointBufferElement& f_elem_r)
 {
+    if (f_elem_r.checkpointId == k_failureCheckpointId)
+    {
+        // Failure reported directly by the application concerns all supervisions of this interface
+        LM_LOG_WARN() << "MonitorInterface: Failure reported by application. Instance:" << k_interfaceName;
+        for (auto& observer : checkpointObservers)
+        {
+            observer->setFailureEvent(true);
+            observer->pushData(f_elem_r.timestamp);
+            observer->setFailureEvent(false);
+        }
+        return;
+    }
+
     for (auto& observer : checkpointObservers)
     {
         if (f_elem_r.checkpointId == observer->getId())
@@ -342,6 +342,38 @@ TEST_F(MonitorIfDaemonTest, Active_TwoAttachedCheckpoints_RoutedByCheckpointId)
     fix.monitor.checkForNewData(mockClock());
 }
 
+TEST_F(MonitorIfDaemonTest, Active_FailureCheckpointId_ForwardedToAllCheckpoints)
+{
+    RecordProperty(
+        "Description",
+        "An IPC element with the reserved failure checkpoint ID must be forwarded "
+        "to all attached Checkpoints as a failure event.");
+
+    constexpr uint32_t kCheckpointId2 = 2U;
+
+    MonitorIfDaemonFixture fix;
+    EXPECT_CALL(fix.checkpointMock, updateData).WillOnce([](const ifappl::Checkpoint& checkpoint) {
+        EXPECT_TRUE(checkpoint.getFailureEvent());
+    });
+    fix.initIpc();
+
+    ifappl::Checkpoint checkpoint2("test_cp2", kCheckpointId2, &fix.processState);
+    CheckpointMock mock2;
+    EXPECT_CALL(mock2, updateData).WillOnce([](const ifappl::Checkpoint& checkpoint) {
+        EXPECT_TRUE(checkpoint.getFailureEvent());
+    });
+    checkpoint2.attachObserver(mock2);
+    fix.monitor.attachCheckpoint(checkpoint2);
+
+    fix.activateProcess(mockClock());
+    fix.sendCheckpoint(ifappl::k_failureCheckpointId, mockClock());
+    fix.monitor.checkForNewData(mockClock());
+
+    // Failure marker is only set while the failure is forwarded.
+    EXPECT_FALSE(fix.checkpoint.getFailureEvent());
+    EXPECT_FALSE(checkpoint2.getFailureEvent());
+}
+
 TEST_F(MonitorIfDaemonTest, Active_OverflowDetected_TransitionsToInactiveOverflow)
 {
     RecordProperty(
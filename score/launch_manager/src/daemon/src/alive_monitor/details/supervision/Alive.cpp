@@ -58,6 +58,11 @@ void Alive::updateData(const score::mw::lifecycle::internal::saf::ifappl::Checkp
         // If clock error is detected, last syncTimestamp is used as event timestamp.
         eventTimestamp = ((timestamp == 0U) ? lastSyncTimestamp : timestamp);
     }
+    else if (f_observable_r.getFailureEvent())
+    {
+        // Failure is handled after all update events of this cycle were processed.
+        isFailureReported = true;
+    }
     else
     {
         CheckpointSnapshot checkpointSnapshot{&f_observable_r, timestamp};
@@ -169,6 +174,13 @@ void Alive::evaluate(const timers::NanoSecondType f_syncTimestamp)
     }
 
     timeSortingUpdateEventBuffer.clear();
+
+    if (isFailureReported)
+    {
+        eventTimestamp = f_syncTimestamp;
+        handleReportedFailureReaction();
+    }
+
     lastSyncTimestamp = f_syncTimestamp;
 }
 
@@ -193,6 +205,22 @@ void Alive::handleDataLossReaction(void) noexcept(true)
     }
     timeSortingUpdateEventBuffer.clear();
     dataLossReason = EDataLossReason::kNoDataLoss;
+    // Supervision already expired due to data loss.
+    isFailureReported = false;
+}
+
+void Alive::handleReportedFailureReaction(void) noexcept(true)
+{
+    if ((aliveStatus == EStatus::kOk) || (aliveStatus == EStatus::kFailed))
+    {
+        switchToExpired(EReason::kReportedFailure);
+    }
+    else
+    {
+        LM_LOG_WARN() << "Alive Supervision (" << getConfigName()
+                      << ") ignored failure reported by application, supervision is not active.";
+    }
+    isFailureReported = false;
 }
 
 bool Alive::detectEvaluationEvent(
@@ -498,6 +526,12 @@ void Alive::switchToExpired(Alive::EReason reason) noexcept(true)
             LM_LOG_ERROR() << "Alive Supervision (" << getConfigName() << ") switched to EXPIRED, due to overflow.";
             break;
         }
+        case EReason::kReportedFailure:
+        {
+            LM_LOG_ERROR() << "Alive Supervision (" << getConfigName()
+                           << ") switched to EXPIRED, due to failure reported by application.";
+            break;
+        }
         default:
             LM_LOG_WARN() << "Alive Supervision (" << getConfigName() << ") switched to EXPIRED";
             break;
@@ -230,6 +230,10 @@ class Alive : public ISupervision,
     /// @brief Handle data loss reaction
     void handleDataLossReaction(void) noexcept(true);
 
+    /// @brief Handle failure reported directly by the application
+    /// @details Active supervision is switched to Expired, otherwise the failure is ignored.
+    void handleReportedFailureReaction(void) noexcept(true);
+
     /// @brief Switch to state Deactivate
     void switchToDeactivated(void) noexcept(true);
 
@@ -245,7 +249,8 @@ class Alive : public ISupervision,
         kDataLoss,                 ///< Checkpoint data was lost, e.g. due to full buffer
         kFailedToleranceExceeded,  ///< More failed cycles than the configured tolerance
         kDataCorruption,           ///< Checkpoint data was corrupted
-        kOverflow                  ///< Overflow appeared, e.g. failed cycle counter or timestamp overflowed
+        kOverflow,                 ///< Overflow appeared, e.g. failed cycle counter or timestamp overflowed
+        kReportedFailure           ///< Failure was reported directly by the application
     };
 
     /// @brief Reasons for Data loss
@@ -316,6 +321,9 @@ class Alive : public ISupervision,
     /// @brief Data loss reason
     EDataLossReason dataLossReason{EDataLossReason::kNoDataLoss};
 
+    /// @brief Set when the application reported a failure since the last evaluation
+    bool isFailureReported{false};
+
     /// @brief status of Alive supervision
     EStatus aliveStatus{EStatus::kDeactivated};
 
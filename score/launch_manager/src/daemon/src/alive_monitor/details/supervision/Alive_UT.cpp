@@ -135,6 +135,14 @@ struct AliveFixture
     {
         checkpoint.pushData(timestamp);
     }
+
+    /// Report a failure directly at the given timestamp.
+    void reportFailure(score::mw::lifecycle::internal::saf::timers::NanoSecondType timestamp)
+    {
+        checkpoint.setFailureEvent(true);
+        checkpoint.pushData(timestamp);
+        checkpoint.setFailureEvent(false);
+    }
 };
 
 }  // namespace
@@ -296,3 +304,43 @@ TEST_F(AliveSupervisionTest, MaxIndicationViolationExpires)
     fix.alive->evaluate(1011U);
     EXPECT_EQ(fix.alive->getStatus(), EStatus::kExpired);
 }
+
+TEST_F(AliveSupervisionTest, ReportedFailureExpiresActiveSupervision)
+{
+    RecordProperty(
+        "Description",
+        "Verify that a failure reported directly by the application switches active Alive to expired "
+        "without waiting for the end of the reference cycle.");
+    AliveFixture fix = AliveFixture::Builder{}.withFailedCyclesTolerance(2U).build();
+
+    EXPECT_CALL(*fix.mockClient, sendRecoveryRequest(fix.kProcessIdentifier)).Times(1).WillOnce(Return(true));
+
+    fix.activateProcess(10U);
+    fix.alive->evaluate(11U);
+    EXPECT_EQ(fix.alive->getStatus(), EStatus::kOk);
+
+    // Failure reported within the reference cycle [10, 1010].
+    fix.reportHeartbeat(100U);
+    fix.reportFailure(200U);
+    fix.alive->evaluate(300U);
+    EXPECT_EQ(fix.alive->getStatus(), EStatus::kExpired);
+}
+
+TEST_F(AliveSupervisionTest, ReportedFailureIgnoredWhenDeactivated)
+{
+    RecordProperty(
+        "Description", "Verify that a failure reported while the supervision is deactivated is ignored.");
+    AliveFixture fix = AliveFixture::Builder{}.build();
+
+    EXPECT_CALL(*fix.mockClient, sendRecoveryRequest(_)).Times(0);
+
+    fix.reportFailure(5U);
+    fix.alive->evaluate(6U);
+    EXPECT_EQ(fix.alive->getStatus(), EStatus::kDeactivated);
+
+    // Failure is not carried over to the next activation.
+    fix.activateProcess(10U);
+    fix.reportHeartbeat(500U);
+    fix.alive->evaluate(1011U);
+    EXPECT_EQ(fix.alive->getStatus(), EStatus::kOk);
+}